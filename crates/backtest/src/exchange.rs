@@ -163,6 +163,9 @@ impl SimulatedExchange {
     }
 
     pub fn set_latency_model(&mut self, latency_model: LatencyModel) {
+        for matching_engine in self.matching_engines.values_mut() {
+            matching_engine.set_latency_model(latency_model.clone());
+        }
         self.latency_model = latency_model;
         log::info!("Setting latency model to {}", self.latency_model);
     }
@@ -199,7 +202,7 @@ impl SimulatedExchange {
             self.use_reduce_only,
         );
         let instrument_id = instrument.id();
-        let matching_engine = OrderMatchingEngine::new(
+        let mut matching_engine = OrderMatchingEngine::new(
             instrument,
             self.instruments.len() as u32,
             self.fill_model.clone(),
@@ -212,6 +215,7 @@ impl SimulatedExchange {
             Rc::clone(&self.cache),
             matching_engine_config,
         );
+        matching_engine.set_latency_model(self.latency_model.clone());
         self.matching_engines.insert(instrument_id, matching_engine);
 
         log::info!("Added instrument {instrument_id} and created matching engine");
@@ -682,7 +686,7 @@ mod tests {
             &ATOMIC_TIME,
             FillModel::default(),
             FeeModelAny::MakerTaker(MakerTakerFeeModel),
-            LatencyModel,
+            LatencyModel::default(),
             book_type,
             None,
             None,
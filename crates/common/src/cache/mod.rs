@@ -1393,6 +1393,10 @@ impl Cache {
             //     database.snapshot_order_state(order)?;
             // }
         }
+
+        // Update the position in the cache
+        self.positions.insert(position.id, position.clone());
+
         Ok(())
     }
 
@@ -292,6 +292,7 @@ mod tests {
             None,
             None,
             None,
+            None,
         );
         order.apply(filled).unwrap();
         cache.update_order(&order).unwrap();
@@ -392,6 +393,7 @@ mod tests {
             None,
             None,
             None,
+            None,
         );
         let position = Position::new(&audusd_sim, fill.into());
         cache
@@ -417,6 +419,65 @@ mod tests {
         assert_eq!(cache.positions_closed_count(None, None, None, None), 0);
     }
 
+    #[rstest]
+    fn test_update_position_stores_position(mut cache: Cache, audusd_sim: CurrencyPair) {
+        let audusd_sim = InstrumentAny::CurrencyPair(audusd_sim);
+        let buy_order = OrderTestBuilder::new(OrderType::Market)
+            .instrument_id(audusd_sim.id())
+            .side(OrderSide::Buy)
+            .quantity(Quantity::from(100_000))
+            .build();
+        let sell_order = OrderTestBuilder::new(OrderType::Market)
+            .instrument_id(audusd_sim.id())
+            .client_order_id(ClientOrderId::new("O-2"))
+            .side(OrderSide::Sell)
+            .quantity(Quantity::from(100_000))
+            .build();
+        let position_id = PositionId::new("P-123456");
+        let buy_fill = TestOrderEventStubs::order_filled(
+            &buy_order,
+            &audusd_sim,
+            None,
+            Some(position_id),
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+        );
+        let sell_fill = TestOrderEventStubs::order_filled(
+            &sell_order,
+            &audusd_sim,
+            None,
+            Some(position_id),
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+        );
+        let mut position = Position::new(&audusd_sim, buy_fill.into());
+        cache
+            .add_position(position.clone(), OmsType::Netting)
+            .unwrap();
+
+        position.apply(&sell_fill.into());
+        cache.update_position(&position).unwrap();
+
+        let result = cache.position(&position_id).unwrap();
+        assert_eq!(result, &position);
+        assert!(result.is_closed());
+        assert_eq!(
+            cache.positions_closed(None, None, None, None),
+            vec![&position]
+        );
+        assert_eq!(cache.positions_open_count(None, None, None, None), 0);
+    }
+
     // -- DATA ------------------------------------------------------------------------------------
 
     #[rstest]
@@ -25,6 +25,18 @@ pub fn precision_from_str(s: &str) -> u8 {
         return s.split("e-").last().unwrap().parse::<u8>().unwrap();
     }
 
+    // Check for scientific notation with a positive exponent, which shifts the mantissa decimals
+    if let Some((mantissa, exponent)) = s.split_once('e') {
+        let mantissa_precision = mantissa
+            .split_once('.')
+            .map_or(0, |(_, decimal_part)| decimal_part.len());
+        let exponent = exponent
+            .trim_start_matches('+')
+            .parse::<usize>()
+            .unwrap_or(0);
+        return mantissa_precision.saturating_sub(exponent) as u8;
+    }
+
     // Check for decimal precision
     if let Some((_, decimal_part)) = s.split_once('.') {
         decimal_part.len() as u8
@@ -97,6 +109,9 @@ mod tests {
     #[case("1e-2", 2)]
     #[case("1e-3", 3)]
     #[case("1e8", 0)]
+    #[case("1.5e3", 0)]
+    #[case("1.25e1", 1)]
+    #[case("1.5E+3", 0)]
     #[case("-1.23", 2)]
     #[case("-1e-2", 2)]
     #[case("1E-2", 2)]
@@ -89,6 +89,34 @@ impl Visitor<'_> for BoolVisitor {
     }
 }
 
+struct U8AsBoolVisitor;
+
+impl Visitor<'_> for U8AsBoolVisitor {
+    type Value = bool;
+
+    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        formatter.write_str("a boolean, or a u8 of 0 or 1")
+    }
+
+    fn visit_bool<E>(self, value: bool) -> Result<Self::Value, E>
+    where
+        E: serde::de::Error,
+    {
+        Ok(value)
+    }
+
+    fn visit_u64<E>(self, value: u64) -> Result<Self::Value, E>
+    where
+        E: serde::de::Error,
+    {
+        match value {
+            0 => Ok(false),
+            1 => Ok(true),
+            _ => Err(E::invalid_value(Unexpected::Unsigned(value), &self)),
+        }
+    }
+}
+
 /// Deserialize the boolean value as a `u8`.
 ///
 /// # Errors
@@ -101,6 +129,18 @@ where
     deserializer.deserialize_any(BoolVisitor)
 }
 
+/// Deserialize the boolean value, also accepting a `u8` of `0` or `1` (as previously serialized).
+///
+/// # Errors
+///
+/// Returns serialization errors.
+pub fn from_u8_as_bool<'de, D>(deserializer: D) -> Result<bool, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    deserializer.deserialize_any(U8AsBoolVisitor)
+}
+
 ////////////////////////////////////////////////////////////////////////////////
 // Tests
 ////////////////////////////////////////////////////////////////////////////////
@@ -109,7 +149,7 @@ mod tests {
     use rstest::*;
     use serde::Deserialize;
 
-    use super::from_bool_as_u8;
+    use super::{from_bool_as_u8, from_u8_as_bool};
 
     #[derive(Deserialize)]
     pub struct TestStruct {
@@ -117,6 +157,12 @@ mod tests {
         pub value: u8,
     }
 
+    #[derive(Deserialize)]
+    pub struct TestBoolStruct {
+        #[serde(deserialize_with = "from_u8_as_bool")]
+        pub value: bool,
+    }
+
     #[rstest]
     #[case(r#"{"value": true}"#, 1)]
     #[case(r#"{"value": false}"#, 0)]
@@ -132,4 +178,20 @@ mod tests {
         let test_struct: TestStruct = serde_json::from_str(json_str).unwrap();
         assert_eq!(test_struct.value, expected);
     }
+
+    #[rstest]
+    #[case(r#"{"value": true}"#, true)]
+    #[case(r#"{"value": false}"#, false)]
+    #[case(r#"{"value": 1}"#, true)]
+    #[case(r#"{"value": 0}"#, false)]
+    fn test_deserialize_u8_as_bool(#[case] json_str: &str, #[case] expected: bool) {
+        let test_struct: TestBoolStruct = serde_json::from_str(json_str).unwrap();
+        assert_eq!(test_struct.value, expected);
+    }
+
+    #[rstest]
+    fn test_deserialize_u8_as_bool_with_invalid_u8() {
+        let result: Result<TestBoolStruct, _> = serde_json::from_str(r#"{"value": 2}"#);
+        assert!(result.is_err());
+    }
 }
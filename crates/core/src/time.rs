@@ -258,6 +258,36 @@ impl AtomicTime {
     }
 }
 
+/// A source of the current time, allowing components to be driven by an injected clock
+/// (such as a non-global static mode [`AtomicTime`] which tests can advance deterministically).
+pub trait TimeSource {
+    /// Returns the current time as nanoseconds since the UNIX epoch.
+    fn get_time_ns(&self) -> UnixNanos;
+
+    /// Sets the current time (only meaningful for controllable clocks).
+    fn set_time(&self, time: UnixNanos);
+}
+
+impl TimeSource for AtomicTime {
+    fn get_time_ns(&self) -> UnixNanos {
+        Self::get_time_ns(self)
+    }
+
+    fn set_time(&self, time: UnixNanos) {
+        Self::set_time(self, time);
+    }
+}
+
+impl<T: TimeSource + ?Sized> TimeSource for &T {
+    fn get_time_ns(&self) -> UnixNanos {
+        (**self).get_time_ns()
+    }
+
+    fn set_time(&self, time: UnixNanos) {
+        (**self).set_time(time);
+    }
+}
+
 ////////////////////////////////////////////////////////////////////////////////
 // Tests
 ////////////////////////////////////////////////////////////////////////////////
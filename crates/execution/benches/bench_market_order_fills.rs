@@ -0,0 +1,82 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2025 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+use criterion::{Criterion, black_box, criterion_group};
+use nautilus_model::{
+    data::{BookOrder, OrderBookDelta},
+    enums::{BookAction, BookType, OrderSide},
+    identifiers::InstrumentId,
+    orderbook::OrderBook,
+    types::{Price, Quantity, fixed::FIXED_PRECISION},
+};
+
+const LEVELS: u64 = 10;
+
+fn l2_book() -> OrderBook {
+    let instrument_id = InstrumentId::from("ETHUSDT-PERP.BINANCE");
+    let mut book = OrderBook::new(instrument_id, BookType::L2_MBP);
+    for i in 0..LEVELS {
+        let order = BookOrder::new(
+            OrderSide::Sell,
+            Price::new(1500.0 + i as f64, 2),
+            Quantity::from("1.000"),
+            i,
+        );
+        book.apply_delta(&OrderBookDelta::new(
+            instrument_id,
+            BookAction::Add,
+            order,
+            0,
+            i,
+            0.into(),
+            0.into(),
+        ));
+    }
+    book
+}
+
+fn market_order(size: &str) -> BookOrder {
+    BookOrder::new(
+        OrderSide::Buy,
+        Price::max(FIXED_PRECISION),
+        Quantity::from(size),
+        0,
+    )
+}
+
+pub fn bench_simulate_fills(c: &mut Criterion) {
+    let book = l2_book();
+    let order = market_order("9.500");
+
+    c.bench_function("simulate_fills_alloc_sweep_10_levels", |b| {
+        b.iter(|| black_box(book.simulate_fills(black_box(&order))));
+    });
+}
+
+pub fn bench_simulate_fills_into(c: &mut Criterion) {
+    let book = l2_book();
+    let order = market_order("9.500");
+    let mut fills = Vec::new();
+
+    c.bench_function("simulate_fills_reuse_sweep_10_levels", |b| {
+        b.iter(|| {
+            book.simulate_fills_into(black_box(&order), &mut fills);
+            black_box(&fills);
+        });
+    });
+}
+
+criterion_group!(benches, bench_simulate_fills, bench_simulate_fills_into);
+criterion::criterion_main!(benches);
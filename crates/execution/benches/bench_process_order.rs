@@ -0,0 +1,110 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2025 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+use std::{cell::RefCell, rc::Rc, sync::LazyLock};
+
+use criterion::{BatchSize, Criterion, criterion_group};
+use nautilus_common::{cache::Cache, msgbus::MessageBus};
+use nautilus_core::{AtomicTime, UnixNanos};
+use nautilus_execution::{
+    matching_engine::{config::OrderMatchingEngineConfig, engine::OrderMatchingEngine},
+    models::{fee::FeeModelAny, fill::FillModel},
+};
+use nautilus_model::{
+    data::{BookOrder, OrderBookDelta},
+    enums::{AccountType, BookAction, BookType, OmsType, OrderSide, OrderType},
+    identifiers::{AccountId, ClientOrderId},
+    instruments::{InstrumentAny, stubs::crypto_perpetual_ethusdt},
+    orders::{OrderAny, OrderTestBuilder},
+    types::{Price, Quantity},
+};
+
+static ATOMIC_TIME: LazyLock<AtomicTime> =
+    LazyLock::new(|| AtomicTime::new(true, UnixNanos::default()));
+
+fn engine_with_liquidity(instrument: &InstrumentAny) -> OrderMatchingEngine {
+    let mut engine = OrderMatchingEngine::new(
+        instrument.clone(),
+        1,
+        FillModel::default(),
+        FeeModelAny::default(),
+        BookType::L2_MBP,
+        OmsType::Netting,
+        AccountType::Cash,
+        &ATOMIC_TIME,
+        Rc::new(RefCell::new(MessageBus::default())),
+        Rc::new(RefCell::new(Cache::default())),
+        OrderMatchingEngineConfig::default(),
+    );
+    let order = BookOrder::new(
+        OrderSide::Sell,
+        Price::from("1500.00"),
+        Quantity::from("1000000.000"),
+        1,
+    );
+    engine.process_order_book_delta(&OrderBookDelta::new(
+        instrument.id(),
+        BookAction::Add,
+        order,
+        0,
+        1,
+        0.into(),
+        0.into(),
+    ));
+    engine
+}
+
+fn market_order(instrument: &InstrumentAny, count: &mut u64) -> OrderAny {
+    *count += 1;
+    OrderTestBuilder::new(OrderType::Market)
+        .instrument_id(instrument.id())
+        .client_order_id(ClientOrderId::new(format!("O-{count}")))
+        .side(OrderSide::Buy)
+        .quantity(Quantity::from("1.000"))
+        .build()
+}
+
+pub fn bench_process_order(c: &mut Criterion) {
+    let instrument = InstrumentAny::CryptoPerpetual(crypto_perpetual_ethusdt());
+    let account_id = AccountId::from("BINANCE-001");
+    let mut engine = engine_with_liquidity(&instrument);
+    let mut count = 0;
+
+    c.bench_function("process_order_market_fill", |b| {
+        b.iter_batched(
+            || market_order(&instrument, &mut count),
+            |mut order| engine.process_order(&mut order, account_id),
+            BatchSize::SmallInput,
+        );
+    });
+}
+
+pub fn bench_process_order_unchecked(c: &mut Criterion) {
+    let instrument = InstrumentAny::CryptoPerpetual(crypto_perpetual_ethusdt());
+    let account_id = AccountId::from("BINANCE-001");
+    let mut engine = engine_with_liquidity(&instrument);
+    let mut count = 0;
+
+    c.bench_function("process_order_unchecked_market_fill", |b| {
+        b.iter_batched(
+            || market_order(&instrument, &mut count),
+            |mut order| engine.process_order_unchecked(&mut order, account_id),
+            BatchSize::SmallInput,
+        );
+    });
+}
+
+criterion_group!(benches, bench_process_order, bench_process_order_unchecked);
+criterion::criterion_main!(benches);
@@ -184,6 +184,10 @@ impl ExecutionClient {
             ts_event,
             self.clock.get_time_ns(),
             false,
+            self.cache
+                .borrow()
+                .venue_order_id(&client_order_id)
+                .copied(),
         );
         self.send_order_event(OrderEventAny::Rejected(event));
     }
@@ -232,6 +236,7 @@ impl ExecutionClient {
             false,
             Some(venue_order_id),
             Some(self.account_id),
+            None,
         );
         self.send_order_event(OrderEventAny::ModifyRejected(event));
     }
@@ -327,6 +332,7 @@ impl ExecutionClient {
             false,
             Some(venue_order_id),
             Some(self.account_id),
+            None,
         );
 
         self.send_order_event(OrderEventAny::Canceled(event));
@@ -351,6 +357,8 @@ impl ExecutionClient {
             false,
             Some(venue_order_id),
             Some(self.account_id),
+            None,
+            None,
         );
 
         self.send_order_event(OrderEventAny::Triggered(event));
@@ -418,6 +426,7 @@ impl ExecutionClient {
             false,
             Some(venue_position_id),
             Some(commission),
+            None,
         );
 
         self.send_order_event(OrderEventAny::Filled(event));
@@ -33,6 +33,10 @@ pub struct ExecutionEngineConfig {
     /// If None then no additional snapshots will be taken.
     #[serde(default)]
     pub snapshot_positions_interval_secs: Option<f64>,
+    /// If position events (opened, changed, closed) are published to the message bus
+    /// when fills are applied to positions.
+    #[serde(default)]
+    pub publish_position_events: bool,
     /// If debug mode is active (will provide extra debug logging).
     #[serde(default)]
     pub debug: bool,
@@ -49,6 +53,7 @@ impl Default for ExecutionEngineConfig {
             snapshot_orders: false,
             snapshot_positions: false,
             snapshot_positions_interval_secs: None,
+            publish_position_events: false,
             debug: false,
         }
     }
@@ -42,9 +42,9 @@ use nautilus_model::{
     enums::{ContingencyType, OmsType, OrderSide, PositionSide},
     events::{
         OrderDenied, OrderEvent, OrderEventAny, OrderFilled, PositionChanged, PositionClosed,
-        PositionOpened,
+        PositionEvent, PositionOpened,
     },
-    identifiers::{ClientId, InstrumentId, PositionId, StrategyId, Venue},
+    identifiers::{ClientId, ClientOrderId, InstrumentId, PositionId, StrategyId, TradeId, Venue},
     instruments::InstrumentAny,
     orders::{OrderAny, OrderError},
     position::Position,
@@ -69,6 +69,7 @@ pub struct ExecutionEngine {
     oms_overrides: HashMap<StrategyId, OmsType>,
     external_order_claims: HashMap<InstrumentId, StrategyId>,
     pos_id_generator: PositionIdGenerator,
+    applied_fills: HashSet<(TradeId, ClientOrderId)>,
     config: ExecutionEngineConfig,
 }
 
@@ -90,6 +91,7 @@ impl ExecutionEngine {
             oms_overrides: HashMap::new(),
             external_order_claims: HashMap::new(),
             pos_id_generator: PositionIdGenerator::new(trader_id, clock),
+            applied_fills: HashSet::new(),
             config: config.unwrap_or_default(),
         }
     }
@@ -521,6 +523,15 @@ impl ExecutionEngine {
         drop(cache);
         match event {
             OrderEventAny::Filled(order_filled) => {
+                // Keys are evicted once the order closes, after which the order's own
+                // trade IDs are checked instead
+                if (order.is_closed() && order.trade_ids().contains(&&order_filled.trade_id))
+                    || !self.applied_fills.insert(order_filled.dedup_key())
+                {
+                    log::warn!("Ignoring duplicate fill {order_filled}");
+                    return;
+                }
+
                 let oms_type = self.determine_oms_type(order_filled);
                 let position_id = self.determine_position_id(*order_filled, oms_type);
 
@@ -537,6 +548,17 @@ impl ExecutionEngine {
                 self.apply_event_to_order(&mut order, event.clone());
             }
         }
+
+        if order.is_closed() {
+            self.evict_applied_fills(&order);
+        }
+    }
+
+    fn evict_applied_fills(&mut self, order: &OrderAny) {
+        let client_order_id = order.client_order_id();
+        for trade_id in order.trade_ids() {
+            self.applied_fills.remove(&(*trade_id, client_order_id));
+        }
     }
 
     fn determine_oms_type(&self, fill: &OrderFilled) -> OmsType {
@@ -668,19 +690,21 @@ impl ExecutionEngine {
             return;
         };
 
-        let mut position = match self.cache.borrow().position(&position_id) {
-            Some(pos) if !pos.is_closed() => pos.clone(),
+        let position = self.cache.borrow().position(&position_id).cloned();
+        let position = match position {
+            Some(mut pos) if !pos.is_closed() => {
+                if self.will_flip_position(&pos, fill) {
+                    self.flip_position(instrument, &mut pos, fill, oms_type);
+                } else {
+                    self.update_position(&mut pos, fill);
+                }
+                pos
+            }
             _ => self
-                .open_position(instrument.clone(), None, fill, oms_type)
+                .open_position(instrument, None, fill, oms_type)
                 .unwrap(),
         };
 
-        if self.will_flip_position(&position, fill) {
-            self.flip_position(instrument, &mut position, fill, oms_type);
-        } else {
-            self.update_position(&mut position, fill);
-        }
-
         if matches!(order.contingency_type(), Some(ContingencyType::Oto)) && position.is_open() {
             for client_order_id in order.linked_order_ids().unwrap_or_default() {
                 let mut cache = self.cache.borrow_mut();
@@ -728,13 +752,15 @@ impl ExecutionEngine {
             position
         };
 
-        let ts_init = self.clock.borrow().timestamp_ns();
-        let event = PositionOpened::create(&position, &fill, UUID4::new(), ts_init);
-        let mut msgbus = self.msgbus.borrow_mut();
-        let topic = msgbus
-            .switchboard
-            .get_event_positions_topic(event.strategy_id);
-        msgbus.publish(&topic, &event);
+        if self.config.publish_position_events {
+            let ts_init = self.clock.borrow().timestamp_ns();
+            let event = PositionOpened::create(&position, &fill, UUID4::new(), ts_init);
+            let mut msgbus = self.msgbus.borrow_mut();
+            let topic = msgbus
+                .switchboard
+                .get_event_positions_topic(event.strategy_id);
+            msgbus.publish(&topic, &PositionEvent::PositionOpened(event));
+        }
 
         Ok(position)
     }
@@ -751,6 +777,10 @@ impl ExecutionEngine {
             self.create_position_state_snapshot(position);
         }
 
+        if !self.config.publish_position_events {
+            return;
+        }
+
         let mut msgbus = self.msgbus.borrow_mut();
         let topic = msgbus
             .switchboard
@@ -759,10 +789,10 @@ impl ExecutionEngine {
 
         if position.is_closed() {
             let event = PositionClosed::create(position, &fill, UUID4::new(), ts_init);
-            msgbus.publish(&topic, &event);
+            msgbus.publish(&topic, &PositionEvent::PositionClosed(event));
         } else {
             let event = PositionChanged::create(position, &fill, UUID4::new(), ts_init);
-            msgbus.publish(&topic, &event);
+            msgbus.publish(&topic, &PositionEvent::PositionChanged(event));
         }
     }
 
@@ -823,6 +853,7 @@ impl ExecutionEngine {
                 fill.reconciliation,
                 fill.position_id,
                 commission1,
+                fill.slippage,
             ));
 
             self.update_position(position, fill_split1.unwrap());
@@ -871,6 +902,7 @@ impl ExecutionEngine {
             fill.reconciliation,
             position_id_flip,
             commission2,
+            fill.slippage,
         );
 
         if oms_type == OmsType::Hedging {
@@ -1040,8 +1072,25 @@ impl ExecutionEngine {
 mod tests {
     use std::{cell::RefCell, rc::Rc};
 
-    use nautilus_common::{cache::Cache, clock::TestClock, msgbus::MessageBus};
-    use rstest::fixture;
+    use nautilus_common::{
+        cache::Cache,
+        clock::TestClock,
+        msgbus::{
+            MessageBus,
+            stubs::{get_message_saving_handler, get_saved_messages},
+        },
+    };
+    use nautilus_model::{
+        accounts::{AccountAny, stubs::cash_account},
+        enums::OrderType,
+        events::account::stubs::cash_account_state_million_usd,
+        instruments::{CurrencyPair, stubs::audusd_sim},
+        orders::{
+            OrderTestBuilder,
+            stubs::{TestOrderEventStubs, TestOrderStubs},
+        },
+    };
+    use rstest::{fixture, rstest};
 
     use super::*;
 
@@ -1070,5 +1119,157 @@ mod tests {
         ExecutionEngine::new(clock, cache, msgbus, config)
     }
 
-    // TODO: After Implementing ExecutionClient & Strategy
+    fn add_instrument_and_account(cache: &Rc<RefCell<Cache>>, instrument: &InstrumentAny) {
+        let mut cache = cache.borrow_mut();
+        cache.add_instrument(instrument.clone()).unwrap();
+        cache
+            .add_account(AccountAny::Cash(cash_account(
+                cash_account_state_million_usd("1000000 USD", "0 USD", "1000000 USD"),
+            )))
+            .unwrap();
+    }
+
+    fn add_accepted_market_order(
+        cache: &Rc<RefCell<Cache>>,
+        instrument: &InstrumentAny,
+        client_order_id: &str,
+        side: OrderSide,
+    ) -> OrderAny {
+        let order = OrderTestBuilder::new(OrderType::Market)
+            .instrument_id(instrument.id())
+            .client_order_id(ClientOrderId::new(client_order_id))
+            .side(side)
+            .quantity(Quantity::from(100_000))
+            .build();
+        let order = TestOrderStubs::make_accepted_order(&order);
+        cache
+            .borrow_mut()
+            .add_order(order.clone(), None, None, false)
+            .unwrap();
+        order
+    }
+
+    fn fill_event(order: &OrderAny, instrument: &InstrumentAny) -> OrderEventAny {
+        TestOrderEventStubs::order_filled(
+            order, instrument, None, None, None, None, None, None, None, None, None,
+        )
+    }
+
+    #[rstest]
+    fn test_duplicate_fill_is_applied_once(
+        msgbus: MessageBus,
+        simple_cache: Cache,
+        clock: TestClock,
+        audusd_sim: CurrencyPair,
+    ) {
+        let instrument = InstrumentAny::CurrencyPair(audusd_sim);
+        let cache = Rc::new(RefCell::new(simple_cache));
+        add_instrument_and_account(&cache, &instrument);
+        let order = add_accepted_market_order(&cache, &instrument, "O-001", OrderSide::Buy);
+
+        let mut engine = _get_exec_engine(
+            Rc::new(RefCell::new(msgbus)),
+            cache.clone(),
+            Rc::new(RefCell::new(clock)),
+            None,
+        );
+
+        let fill = fill_event(&order, &instrument);
+        engine.process(&fill);
+        engine.process(&fill);
+
+        let cache = cache.borrow();
+        let position = cache.position(&PositionId::new("1")).unwrap();
+        assert_eq!(position.quantity, Quantity::from(100_000));
+        assert_eq!(position.trade_ids.len(), 1);
+        let order = cache.order(&order.client_order_id()).unwrap();
+        assert_eq!(order.filled_qty(), Quantity::from(100_000));
+    }
+
+    #[rstest]
+    fn test_applied_fills_evicted_when_order_closes(
+        msgbus: MessageBus,
+        simple_cache: Cache,
+        clock: TestClock,
+        audusd_sim: CurrencyPair,
+    ) {
+        let instrument = InstrumentAny::CurrencyPair(audusd_sim);
+        let cache = Rc::new(RefCell::new(simple_cache));
+        add_instrument_and_account(&cache, &instrument);
+        let order = add_accepted_market_order(&cache, &instrument, "O-001", OrderSide::Buy);
+
+        let mut engine = _get_exec_engine(
+            Rc::new(RefCell::new(msgbus)),
+            cache.clone(),
+            Rc::new(RefCell::new(clock)),
+            None,
+        );
+
+        let fill = fill_event(&order, &instrument);
+        engine.process(&fill);
+        assert!(engine.applied_fills.is_empty());
+
+        // Replayed fill for the closed order is still ignored
+        engine.process(&fill);
+
+        let cache = cache.borrow();
+        let position = cache.position(&PositionId::new("1")).unwrap();
+        assert_eq!(position.quantity, Quantity::from(100_000));
+        assert_eq!(position.trade_ids.len(), 1);
+    }
+
+    #[rstest]
+    #[case(true)]
+    #[case(false)]
+    fn test_position_events_published_on_open_and_close(
+        msgbus: MessageBus,
+        simple_cache: Cache,
+        clock: TestClock,
+        audusd_sim: CurrencyPair,
+        #[case] publish_position_events: bool,
+    ) {
+        let instrument = InstrumentAny::CurrencyPair(audusd_sim);
+        let cache = Rc::new(RefCell::new(simple_cache));
+        add_instrument_and_account(&cache, &instrument);
+        let buy_order = add_accepted_market_order(&cache, &instrument, "O-001", OrderSide::Buy);
+        let sell_order = add_accepted_market_order(&cache, &instrument, "O-002", OrderSide::Sell);
+
+        let msgbus = Rc::new(RefCell::new(msgbus));
+        let handler = get_message_saving_handler::<PositionEvent>(None);
+        let topic = msgbus
+            .borrow_mut()
+            .switchboard
+            .get_event_positions_topic(buy_order.strategy_id());
+        msgbus.borrow_mut().subscribe(topic, handler.clone(), None);
+
+        let config = ExecutionEngineConfig {
+            publish_position_events,
+            ..Default::default()
+        };
+        let mut engine = _get_exec_engine(
+            msgbus,
+            cache.clone(),
+            Rc::new(RefCell::new(clock)),
+            Some(config),
+        );
+
+        engine.process(&fill_event(&buy_order, &instrument));
+        engine.process(&fill_event(&sell_order, &instrument));
+
+        let position = cache
+            .borrow()
+            .position(&PositionId::new("1"))
+            .unwrap()
+            .clone();
+        assert!(position.is_closed());
+
+        let events = get_saved_messages::<PositionEvent>(handler);
+        if publish_position_events {
+            assert_eq!(events.len(), 2);
+            assert!(matches!(events[0], PositionEvent::PositionOpened(_)));
+            assert!(matches!(events[1], PositionEvent::PositionClosed(_)));
+        } else {
+            assert!(events.is_empty());
+        }
+    }
 }
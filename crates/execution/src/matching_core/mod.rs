@@ -25,7 +25,7 @@ use nautilus_model::{
     enums::OrderSideSpecified,
     identifiers::{ClientOrderId, InstrumentId},
     orders::{LimitOrderAny, OrderAny, OrderError, PassiveOrderAny, StopOrderAny},
-    types::Price,
+    types::{Price, price::PriceRaw},
 };
 
 use crate::matching_core::handlers::{
@@ -167,17 +167,41 @@ impl OrderMatchingCore {
         self.orders_ask.clear();
     }
 
+    /// Adds the given `order` to the core in matching priority.
+    ///
+    /// Orders are matched in time priority, except that fully visible orders rank ahead of
+    /// orders with a hidden (non-displayed) quantity at the same price.
     pub fn add_order(&mut self, order: PassiveOrderAny) -> Result<(), OrderError> {
-        match order.order_side_specified() {
-            OrderSideSpecified::Buy => {
-                self.orders_bid.push(order);
-                Ok(())
-            }
-            OrderSideSpecified::Sell => {
-                self.orders_ask.push(order);
-                Ok(())
-            }
-        }
+        let orders = match order.order_side_specified() {
+            OrderSideSpecified::Buy => &mut self.orders_bid,
+            OrderSideSpecified::Sell => &mut self.orders_ask,
+        };
+
+        let index = if has_hidden_qty(&order) {
+            orders.len()
+        } else {
+            let price = order.to_any().price();
+            orders
+                .iter()
+                .position(|o| has_hidden_qty(o) && o.to_any().price() == price)
+                .unwrap_or(orders.len())
+        };
+        orders.insert(index, order);
+        Ok(())
+    }
+
+    /// Replaces the resting order with the same client order ID, keeping its queue position.
+    pub fn update_order(&mut self, order: PassiveOrderAny) -> Result<(), OrderError> {
+        let orders = match order.order_side_specified() {
+            OrderSideSpecified::Buy => &mut self.orders_bid,
+            OrderSideSpecified::Sell => &mut self.orders_ask,
+        };
+        let resting = orders
+            .iter_mut()
+            .find(|o| o.client_order_id() == order.client_order_id())
+            .ok_or(OrderError::NotFound(order.client_order_id()))?;
+        *resting = order;
+        Ok(())
     }
 
     pub fn delete_order(&mut self, order: &PassiveOrderAny) -> Result<(), OrderError> {
@@ -265,21 +289,60 @@ impl OrderMatchingCore {
         }
     }
 
+    /// Returns whether a stop order with the given trigger `price` is in the market.
+    ///
+    /// A trigger exactly at the best ask (BUY) or best bid (SELL) is considered matched,
+    /// with prices compared at the instrument tick size.
     #[must_use]
     pub fn is_stop_matched(&self, side: OrderSideSpecified, price: Price) -> bool {
+        let trigger = self.tick_raw(price);
         match side {
-            OrderSideSpecified::Buy => self.ask.is_some_and(|a| a >= price),
-            OrderSideSpecified::Sell => self.bid.is_some_and(|b| b <= price),
+            OrderSideSpecified::Buy => self.ask.is_some_and(|a| self.tick_raw(a) >= trigger),
+            OrderSideSpecified::Sell => self.bid.is_some_and(|b| self.tick_raw(b) <= trigger),
         }
     }
 
+    /// Returns whether a touched order with the given `trigger_price` is in the market.
+    ///
+    /// A trigger exactly at the best ask (BUY) or best bid (SELL) is considered triggered,
+    /// with prices compared at the instrument tick size.
     #[must_use]
     pub fn is_touch_triggered(&self, side: OrderSideSpecified, trigger_price: Price) -> bool {
+        let trigger = self.tick_raw(trigger_price);
         match side {
-            OrderSideSpecified::Buy => self.ask.is_some_and(|a| a <= trigger_price),
-            OrderSideSpecified::Sell => self.bid.is_some_and(|b| b >= trigger_price),
+            OrderSideSpecified::Buy => self.ask.is_some_and(|a| self.tick_raw(a) <= trigger),
+            OrderSideSpecified::Sell => self.bid.is_some_and(|b| self.tick_raw(b) >= trigger),
         }
     }
+
+    /// Returns whether the best ask (BUY) or best bid (SELL) is exactly at the given
+    /// `trigger_price`, compared at the instrument tick size.
+    #[must_use]
+    pub fn is_at_trigger_price(&self, side: OrderSideSpecified, trigger_price: Price) -> bool {
+        let trigger = self.tick_raw(trigger_price);
+        match side {
+            OrderSideSpecified::Buy => self.ask.is_some_and(|a| self.tick_raw(a) == trigger),
+            OrderSideSpecified::Sell => self.bid.is_some_and(|b| self.tick_raw(b) == trigger),
+        }
+    }
+
+    /// Returns the raw value of `price` rounded to the nearest price increment.
+    fn tick_raw(&self, price: Price) -> PriceRaw {
+        let increment = self.price_increment.raw;
+        if increment <= 0 {
+            return price.raw;
+        }
+        (price.raw + increment / 2).div_euclid(increment) * increment
+    }
+}
+
+/// Returns whether the given priced `order` has a hidden (non-displayed) quantity.
+fn has_hidden_qty(order: &PassiveOrderAny) -> bool {
+    let order = order.to_any();
+    order.price().is_some()
+        && order
+            .display_qty()
+            .is_some_and(|display_qty| display_qty < order.quantity())
 }
 
 ////////////////////////////////////////////////////////////////////////////////
@@ -325,6 +388,40 @@ mod tests {
         assert!(matching_core.order_exists(passive_order.client_order_id()));
     }
 
+    #[rstest]
+    fn test_add_order_visible_ranks_ahead_of_hidden_at_same_price() {
+        let instrument_id = InstrumentId::from("AAPL.XNAS");
+        let mut matching_core = create_matching_core(instrument_id, Price::from("0.01"));
+
+        let limit_order = |client_order_id: &str, price: &str, display_qty: Option<&str>| {
+            let mut builder = OrderTestBuilder::new(OrderType::Limit);
+            builder
+                .instrument_id(instrument_id)
+                .client_order_id(ClientOrderId::from(client_order_id))
+                .side(OrderSide::Buy)
+                .price(Price::from(price))
+                .quantity(Quantity::from("100"));
+            if let Some(display_qty) = display_qty {
+                builder.display_qty(Quantity::from(display_qty));
+            }
+            PassiveOrderAny::from(builder.build())
+        };
+        let iceberg = limit_order("O-1", "100.00", Some("10"));
+        let visible_other_price = limit_order("O-2", "99.00", None);
+        let visible = limit_order("O-3", "100.00", None);
+
+        matching_core.add_order(iceberg.clone()).unwrap();
+        matching_core
+            .add_order(visible_other_price.clone())
+            .unwrap();
+        matching_core.add_order(visible.clone()).unwrap();
+
+        assert_eq!(
+            matching_core.get_orders_bid(),
+            &[visible, iceberg, visible_other_price]
+        );
+    }
+
     #[rstest]
     fn test_add_order_ask_side() {
         let instrument_id = InstrumentId::from("AAPL.XNAS");
@@ -550,4 +647,62 @@ mod tests {
             .is_stop_matched(order.order_side_specified(), order.trigger_price().unwrap());
         assert_eq!(result, expected);
     }
+
+    #[rstest]
+    #[case(OrderSide::Buy, 0)] // Trigger exactly at ask
+    #[case(OrderSide::Buy, 1)] // Residue above ask, within tick
+    #[case(OrderSide::Buy, -1)] // Residue below ask, within tick
+    #[case(OrderSide::Sell, 0)] // Trigger exactly at bid
+    #[case(OrderSide::Sell, 1)] // Residue above bid, within tick
+    #[case(OrderSide::Sell, -1)] // Residue below bid, within tick
+    fn test_trigger_at_market_compared_at_tick_size(
+        #[case] order_side: OrderSide,
+        #[case] raw_offset: PriceRaw,
+    ) {
+        let instrument_id = InstrumentId::from("AAPL.XNAS");
+        let mut matching_core = create_matching_core(instrument_id, Price::from("0.01"));
+        let bid = Price::from("100.00");
+        let ask = Price::from("101.00");
+        matching_core.set_bid_raw(bid);
+        matching_core.set_ask_raw(ask);
+
+        let market = match order_side {
+            OrderSide::Buy => ask,
+            _ => bid,
+        };
+        let trigger_price = Price::from_raw(market.raw + raw_offset, market.precision);
+        let side = order_side.as_specified();
+
+        assert!(matching_core.is_stop_matched(side, trigger_price));
+        assert!(matching_core.is_touch_triggered(side, trigger_price));
+        assert!(matching_core.is_at_trigger_price(side, trigger_price));
+    }
+
+    #[rstest]
+    #[case(OrderSide::Buy, Price::from("101.01"), false, true)] // One tick above ask
+    #[case(OrderSide::Buy, Price::from("100.99"), true, false)] // One tick below ask
+    #[case(OrderSide::Sell, Price::from("100.01"), true, false)] // One tick above bid
+    #[case(OrderSide::Sell, Price::from("99.99"), false, true)] // One tick below bid
+    fn test_trigger_one_tick_from_market(
+        #[case] order_side: OrderSide,
+        #[case] trigger_price: Price,
+        #[case] expected_stop_matched: bool,
+        #[case] expected_touch_triggered: bool,
+    ) {
+        let instrument_id = InstrumentId::from("AAPL.XNAS");
+        let mut matching_core = create_matching_core(instrument_id, Price::from("0.01"));
+        matching_core.set_bid_raw(Price::from("100.00"));
+        matching_core.set_ask_raw(Price::from("101.00"));
+        let side = order_side.as_specified();
+
+        assert_eq!(
+            matching_core.is_stop_matched(side, trigger_price),
+            expected_stop_matched
+        );
+        assert_eq!(
+            matching_core.is_touch_triggered(side, trigger_price),
+            expected_touch_triggered
+        );
+        assert!(!matching_core.is_at_trigger_price(side, trigger_price));
+    }
 }
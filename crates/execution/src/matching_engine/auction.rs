@@ -0,0 +1,256 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2025 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+//! Auction uncross calculations for the `OrderMatchingEngine`.
+
+use nautilus_model::{
+    enums::OrderSide,
+    orders::OrderAny,
+    types::{Price, Quantity},
+};
+
+/// The result of an auction uncross.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct AuctionClearing {
+    /// The price at which all matched auction orders trade.
+    pub price: Price,
+    /// The volume matched at the clearing price.
+    pub volume: Quantity,
+}
+
+/// The rule for choosing between auction prices which match the same maximum volume.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum AuctionTieBreak {
+    /// Chooses the price with the smallest imbalance between buy and sell volume.
+    #[default]
+    MinImbalance,
+    /// Chooses the price nearest the reference price, then the smallest imbalance.
+    NearestReference,
+}
+
+/// Returns whether the given auction `order` is executable at `price`.
+///
+/// Market orders are executable at any price, limit orders only at prices within their limit.
+#[must_use]
+pub fn is_executable_at(order: &OrderAny, price: Price) -> bool {
+    match order.price() {
+        None => true,
+        Some(limit_px) => match order.order_side() {
+            OrderSide::Buy => limit_px >= price,
+            _ => limit_px <= price,
+        },
+    }
+}
+
+/// Determines the clearing price which maximizes the matched volume for the given auction `orders`.
+///
+/// The candidate prices are the limit prices of the orders. Ties on matched volume are broken
+/// by the smallest imbalance between buy and sell volume, then by the lowest price.
+///
+/// Returns `None` if there are no limit prices or no volume can be matched.
+#[must_use]
+pub fn calculate_clearing(orders: &[OrderAny]) -> Option<AuctionClearing> {
+    calculate_clearing_with_tie_break(orders, AuctionTieBreak::MinImbalance, None)
+}
+
+/// Determines the clearing price which maximizes the matched volume for the given auction `orders`,
+/// breaking ties on matched volume with the given `tie_break` rule, then by the lowest price.
+///
+/// The `reference` price is used by [`AuctionTieBreak::NearestReference`], which falls back to
+/// [`AuctionTieBreak::MinImbalance`] when there is no reference.
+///
+/// Returns `None` if there are no limit prices or no volume can be matched.
+#[must_use]
+pub fn calculate_clearing_with_tie_break(
+    orders: &[OrderAny],
+    tie_break: AuctionTieBreak,
+    reference: Option<Price>,
+) -> Option<AuctionClearing> {
+    let mut candidates: Vec<Price> = orders.iter().filter_map(OrderAny::price).collect();
+    candidates.sort();
+    candidates.dedup();
+
+    let precision = orders.first()?.quantity().precision;
+    let mut best: Option<(AuctionClearing, Quantity)> = None;
+
+    for price in candidates {
+        let executable_qty = |side: OrderSide| {
+            orders
+                .iter()
+                .filter(|order| order.order_side() == side && is_executable_at(order, price))
+                .fold(Quantity::zero(precision), |acc, order| {
+                    acc + order.leaves_qty()
+                })
+        };
+        let buy_qty = executable_qty(OrderSide::Buy);
+        let sell_qty = executable_qty(OrderSide::Sell);
+        let volume = buy_qty.min(sell_qty);
+        let imbalance = buy_qty.max(sell_qty) - volume;
+
+        let is_better = match &best {
+            None => true,
+            Some((clearing, best_imbalance)) => {
+                volume > clearing.volume
+                    || (volume == clearing.volume
+                        && is_tie_preferred(
+                            tie_break,
+                            reference,
+                            (price, imbalance),
+                            (clearing.price, *best_imbalance),
+                        ))
+            }
+        };
+        if is_better {
+            best = Some((AuctionClearing { price, volume }, imbalance));
+        }
+    }
+
+    best.map(|(clearing, _)| clearing)
+        .filter(|clearing| clearing.volume.is_positive())
+}
+
+fn is_tie_preferred(
+    tie_break: AuctionTieBreak,
+    reference: Option<Price>,
+    (price, imbalance): (Price, Quantity),
+    (best_price, best_imbalance): (Price, Quantity),
+) -> bool {
+    let is_less_imbalanced = imbalance < best_imbalance;
+    match (tie_break, reference) {
+        (AuctionTieBreak::NearestReference, Some(reference)) => {
+            let distance = |px: Price| px.raw.abs_diff(reference.raw);
+            match distance(price).cmp(&distance(best_price)) {
+                std::cmp::Ordering::Less => true,
+                std::cmp::Ordering::Equal => is_less_imbalanced,
+                std::cmp::Ordering::Greater => false,
+            }
+        }
+        _ => is_less_imbalanced,
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use nautilus_model::{
+        enums::OrderType,
+        identifiers::{ClientOrderId, InstrumentId},
+        orders::OrderTestBuilder,
+    };
+    use rstest::rstest;
+
+    use super::*;
+
+    fn auction_order(
+        client_order_id: &str,
+        side: OrderSide,
+        price: Option<&str>,
+        quantity: &str,
+    ) -> OrderAny {
+        let mut builder = OrderTestBuilder::new(if price.is_some() {
+            OrderType::Limit
+        } else {
+            OrderType::Market
+        });
+        builder
+            .instrument_id(InstrumentId::from("AAPL.XNAS"))
+            .client_order_id(ClientOrderId::from(client_order_id))
+            .side(side)
+            .quantity(Quantity::from(quantity));
+        if let Some(price) = price {
+            builder.price(Price::from(price));
+        }
+        builder.build()
+    }
+
+    #[rstest]
+    fn test_calculate_clearing_maximizes_volume() {
+        let orders = vec![
+            auction_order("O-1", OrderSide::Buy, None, "3"),
+            auction_order("O-2", OrderSide::Buy, Some("100.00"), "2"),
+            auction_order("O-3", OrderSide::Buy, Some("99.00"), "2"),
+            auction_order("O-4", OrderSide::Sell, None, "1"),
+            auction_order("O-5", OrderSide::Sell, Some("100.00"), "4"),
+            auction_order("O-6", OrderSide::Sell, Some("102.00"), "2"),
+        ];
+
+        let clearing = calculate_clearing(&orders).unwrap();
+
+        assert_eq!(clearing.price, Price::from("100.00"));
+        assert_eq!(clearing.volume, Quantity::from("5"));
+    }
+
+    #[rstest]
+    fn test_calculate_clearing_breaks_volume_tie_on_imbalance() {
+        // Matched volume is 2 at both 100.00 and 101.00, only 101.00 is balanced
+        let orders = vec![
+            auction_order("O-1", OrderSide::Buy, Some("101.00"), "2"),
+            auction_order("O-2", OrderSide::Sell, Some("100.00"), "2"),
+            auction_order("O-3", OrderSide::Buy, Some("100.00"), "1"),
+        ];
+
+        let clearing = calculate_clearing(&orders).unwrap();
+
+        assert_eq!(clearing.price, Price::from("101.00"));
+        assert_eq!(clearing.volume, Quantity::from("2"));
+    }
+
+    #[rstest]
+    #[case::min_imbalance(AuctionTieBreak::MinImbalance, Some("100.00"), "101.00")]
+    #[case::nearest_reference_below(AuctionTieBreak::NearestReference, Some("100.00"), "100.00")]
+    #[case::nearest_reference_above(AuctionTieBreak::NearestReference, Some("102.00"), "101.00")]
+    #[case::nearest_reference_missing(AuctionTieBreak::NearestReference, None, "101.00")]
+    fn test_calculate_clearing_with_tie_break(
+        #[case] tie_break: AuctionTieBreak,
+        #[case] reference: Option<&str>,
+        #[case] expected_price: &str,
+    ) {
+        // Matched volume is 2 at both 100.00 and 101.00, only 101.00 is balanced
+        let orders = vec![
+            auction_order("O-1", OrderSide::Buy, Some("101.00"), "2"),
+            auction_order("O-2", OrderSide::Sell, Some("100.00"), "2"),
+            auction_order("O-3", OrderSide::Buy, Some("100.00"), "1"),
+        ];
+
+        let clearing =
+            calculate_clearing_with_tie_break(&orders, tie_break, reference.map(Price::from))
+                .unwrap();
+
+        assert_eq!(clearing.price, Price::from(expected_price));
+        assert_eq!(clearing.volume, Quantity::from("2"));
+    }
+
+    #[rstest]
+    fn test_calculate_clearing_when_orders_do_not_cross() {
+        let orders = vec![
+            auction_order("O-1", OrderSide::Buy, Some("99.00"), "1"),
+            auction_order("O-2", OrderSide::Sell, Some("100.00"), "1"),
+        ];
+
+        assert_eq!(calculate_clearing(&orders), None);
+    }
+
+    #[rstest]
+    fn test_calculate_clearing_with_only_market_orders() {
+        let orders = vec![
+            auction_order("O-1", OrderSide::Buy, None, "1"),
+            auction_order("O-2", OrderSide::Sell, None, "1"),
+        ];
+
+        assert_eq!(calculate_clearing(&orders), None);
+    }
+}
@@ -13,6 +13,52 @@
 //  limitations under the License.
 // -------------------------------------------------------------------------------------------------
 
+use nautilus_model::{
+    enums::SelfTradePrevention,
+    identifiers::TraderId,
+    types::{Price, Quantity},
+};
+use ustr::Ustr;
+
+use crate::matching_engine::auction::AuctionTieBreak;
+
+/// The handling of limit orders priced exactly at the opposing best price (locking the market).
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum LockBehavior {
+    /// Matches the order immediately against the opposing best price.
+    #[default]
+    Match,
+    /// Rests the order on the book without matching on submission.
+    Rest,
+    /// Rejects the order.
+    Reject,
+}
+
+/// The handling of GTD orders whose expire time has already passed on submission.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum PastExpireTimeHandling {
+    /// Accepts the order, which then expires on the next iteration of the engine.
+    #[default]
+    Accept,
+    /// Rejects the order.
+    Reject,
+    /// Accepts and then immediately expires the order.
+    Expire,
+}
+
+/// The order in which cancel-all commands emit the cancellations of open orders.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum CancelAllOrdering {
+    /// Cancels orders in the order returned by the cache, which is not deterministic.
+    #[default]
+    Unordered,
+    /// Cancels orders in ascending client order ID order.
+    ClientOrderId,
+    /// Cancels buy orders before sell orders, each in price-time priority: best price first
+    /// (trigger price for orders without a price), then earliest initialized first.
+    PriceTime,
+}
+
 /// Configuration for `OrderMatchingEngine` instances.
 #[derive(Debug, Clone)]
 pub struct OrderMatchingEngineConfig {
@@ -23,6 +69,50 @@ pub struct OrderMatchingEngineConfig {
     pub use_position_ids: bool,
     pub use_random_ids: bool,
     pub use_reduce_only: bool,
+    pub use_market_data_ts_for_fills: bool,
+    pub support_auctions: bool,
+    pub max_open_orders_per_account: Option<usize>,
+    pub queue_market_orders_until_data: bool,
+    pub record_trades: bool,
+    pub resting_fills_at_own_price: bool,
+    pub self_trade_prevention: Option<SelfTradePrevention>,
+    pub post_only_requires_quote: bool,
+    pub reject_settlement_currency_mismatch: bool,
+    pub recent_rejections_capacity: usize,
+    pub emit_updated_on_fill: bool,
+    pub max_fills_per_order: Option<usize>,
+    pub max_sweep_depth_pct: Option<f64>,
+    pub cancel_reduce_only_when_flat: bool,
+    pub max_modifies_per_order: Option<usize>,
+    pub auction_tie_break: AuctionTieBreak,
+    pub taker_fills_at_limit_price: bool,
+    pub min_resting_time_nanos: Option<u64>,
+    pub trigger_on_touch: bool,
+    pub publish_top_of_book_quotes: bool,
+    pub group_batch_cancel_events: bool,
+    pub position_id_pattern: Option<Ustr>,
+    pub batch_order_events: bool,
+    pub expiry_grace_period_nanos: Option<u64>,
+    pub venue_clock_offset_nanos: i64,
+    pub lock_behavior: LockBehavior,
+    pub skip_duplicate_deltas: bool,
+    pub expected_trader_id: Option<TraderId>,
+    pub paper_fill_at_mid: bool,
+    pub cancel_order_list_on_fill: bool,
+    pub stop_limit_protect: bool,
+    pub past_expire_time_handling: PastExpireTimeHandling,
+    pub min_fill_report_qty: Option<Quantity>,
+    pub reject_orders_when_inactive: bool,
+    pub reject_takers: bool,
+    pub round_prices_to_tick: bool,
+    pub resolve_limit_quote_quantity: bool,
+    pub emit_submitted: bool,
+    pub use_trigger_instruments: bool,
+    pub no_market_reference_price: Option<Price>,
+    pub cancel_all_ordering: CancelAllOrdering,
+    pub queue_modifies_until_accepted: bool,
+    pub stop_fills_at_trigger_price: bool,
+    pub trigger_stops_on_trades: bool,
 }
 
 impl OrderMatchingEngineConfig {
@@ -45,8 +135,400 @@ impl OrderMatchingEngineConfig {
             use_position_ids,
             use_random_ids,
             use_reduce_only,
+            use_market_data_ts_for_fills: false,
+            support_auctions: false,
+            max_open_orders_per_account: None,
+            queue_market_orders_until_data: false,
+            record_trades: false,
+            resting_fills_at_own_price: false,
+            self_trade_prevention: None,
+            post_only_requires_quote: false,
+            reject_settlement_currency_mismatch: false,
+            recent_rejections_capacity: 0,
+            emit_updated_on_fill: false,
+            max_fills_per_order: None,
+            max_sweep_depth_pct: None,
+            cancel_reduce_only_when_flat: false,
+            max_modifies_per_order: None,
+            auction_tie_break: AuctionTieBreak::MinImbalance,
+            taker_fills_at_limit_price: false,
+            min_resting_time_nanos: None,
+            trigger_on_touch: true,
+            publish_top_of_book_quotes: false,
+            group_batch_cancel_events: false,
+            position_id_pattern: None,
+            batch_order_events: false,
+            expiry_grace_period_nanos: None,
+            venue_clock_offset_nanos: 0,
+            lock_behavior: LockBehavior::Match,
+            skip_duplicate_deltas: false,
+            expected_trader_id: None,
+            paper_fill_at_mid: false,
+            cancel_order_list_on_fill: false,
+            stop_limit_protect: false,
+            past_expire_time_handling: PastExpireTimeHandling::Accept,
+            min_fill_report_qty: None,
+            reject_orders_when_inactive: false,
+            reject_takers: false,
+            round_prices_to_tick: false,
+            resolve_limit_quote_quantity: false,
+            emit_submitted: false,
+            use_trigger_instruments: false,
+            no_market_reference_price: None,
+            cancel_all_ordering: CancelAllOrdering::Unordered,
+            queue_modifies_until_accepted: false,
+            stop_fills_at_trigger_price: false,
+            trigger_stops_on_trades: false,
         }
     }
+
+    /// Sets whether fill `ts_event` is taken from the triggering market data event
+    /// (rather than the engine clock).
+    #[must_use]
+    pub const fn with_use_market_data_ts_for_fills(mut self, value: bool) -> Self {
+        self.use_market_data_ts_for_fills = value;
+        self
+    }
+
+    /// Sets whether market and limit orders received during an auction collection phase
+    /// are held for the uncross (rather than matched continuously).
+    #[must_use]
+    pub const fn with_support_auctions(mut self, value: bool) -> Self {
+        self.support_auctions = value;
+        self
+    }
+
+    /// Sets the maximum number of orders an account may have resting in the engine,
+    /// beyond which further submissions are rejected (`None` for no limit).
+    #[must_use]
+    pub const fn with_max_open_orders_per_account(mut self, value: Option<usize>) -> Self {
+        self.max_open_orders_per_account = value;
+        self
+    }
+
+    /// Sets whether market orders submitted before the book has a market on the opposite
+    /// side are accepted and held until data arrives (rather than rejected immediately).
+    #[must_use]
+    pub const fn with_queue_market_orders_until_data(mut self, value: bool) -> Self {
+        self.queue_market_orders_until_data = value;
+        self
+    }
+
+    /// Sets whether the engine retains a log of every fill it generates, available
+    /// from `OrderMatchingEngine::trades` (disabled by default to bound memory).
+    #[must_use]
+    pub const fn with_record_trades(mut self, value: bool) -> Self {
+        self.record_trades = value;
+        self
+    }
+
+    /// Sets whether resting (maker) limit orders matched by liquidity at a better price
+    /// fill at their own limit price (rather than the better book price).
+    #[must_use]
+    pub const fn with_resting_fills_at_own_price(mut self, value: bool) -> Self {
+        self.resting_fills_at_own_price = value;
+        self
+    }
+
+    /// Sets the self-trade prevention mode applied when an incoming order would match
+    /// a resting order from the same strategy.
+    #[must_use]
+    pub const fn with_self_trade_prevention(mut self, value: Option<SelfTradePrevention>) -> Self {
+        self.self_trade_prevention = value;
+        self
+    }
+
+    /// Sets whether post-only orders are rejected when there is no opposing quote.
+    #[must_use]
+    pub const fn with_post_only_requires_quote(mut self, value: bool) -> Self {
+        self.post_only_requires_quote = value;
+        self
+    }
+
+    /// Sets whether orders are rejected when the account holds no balance in the instrument
+    /// settlement currency (otherwise settlement is converted by the account).
+    #[must_use]
+    pub const fn with_reject_settlement_currency_mismatch(mut self, value: bool) -> Self {
+        self.reject_settlement_currency_mismatch = value;
+        self
+    }
+
+    /// Sets the number of most recent order rejections retained for inspection (0 disables).
+    #[must_use]
+    pub const fn with_recent_rejections_capacity(mut self, value: usize) -> Self {
+        self.recent_rejections_capacity = value;
+        self
+    }
+
+    /// Sets whether an `OrderUpdated` (with the total order quantity) is emitted after each fill.
+    #[must_use]
+    pub const fn with_emit_updated_on_fill(mut self, value: bool) -> Self {
+        self.emit_updated_on_fill = value;
+        self
+    }
+
+    /// Sets the maximum number of fills per order, after which any remaining quantity is canceled.
+    #[must_use]
+    pub const fn with_max_fills_per_order(mut self, value: Option<usize>) -> Self {
+        self.max_fills_per_order = value;
+        self
+    }
+
+    /// Sets the maximum depth (as a percentage from top-of-book) a single market order may sweep,
+    /// after which any remaining quantity is canceled.
+    #[must_use]
+    pub const fn with_max_sweep_depth_pct(mut self, value: Option<f64>) -> Self {
+        self.max_sweep_depth_pct = value;
+        self
+    }
+
+    /// Sets whether reduce-only orders with no open position to reduce are accepted then
+    /// canceled (otherwise they are rejected).
+    #[must_use]
+    pub const fn with_cancel_reduce_only_when_flat(mut self, value: bool) -> Self {
+        self.cancel_reduce_only_when_flat = value;
+        self
+    }
+
+    /// Sets the maximum number of modifies accepted per order, after which modifies are rejected.
+    #[must_use]
+    pub const fn with_max_modifies_per_order(mut self, value: Option<usize>) -> Self {
+        self.max_modifies_per_order = value;
+        self
+    }
+
+    /// Sets the rule for choosing between auction clearing prices which match the same volume.
+    #[must_use]
+    pub const fn with_auction_tie_break(mut self, value: AuctionTieBreak) -> Self {
+        self.auction_tie_break = value;
+        self
+    }
+
+    /// Sets whether aggressive (taker) limit orders matching liquidity at a better price
+    /// fill at their limit price, as on venues which cap taker fills (rather than the better
+    /// book price).
+    #[must_use]
+    pub const fn with_taker_fills_at_limit_price(mut self, value: bool) -> Self {
+        self.taker_fills_at_limit_price = value;
+        self
+    }
+
+    /// Sets the minimum time (nanoseconds) an order must rest after acceptance before it can be
+    /// canceled, with earlier cancels rejected.
+    #[must_use]
+    pub const fn with_min_resting_time_nanos(mut self, value: Option<u64>) -> Self {
+        self.min_resting_time_nanos = value;
+        self
+    }
+
+    /// Sets whether stop and if-touched orders trigger when the market exactly touches the
+    /// trigger price (otherwise the market must trade through it), enabled by default.
+    #[must_use]
+    pub const fn with_trigger_on_touch(mut self, value: bool) -> Self {
+        self.trigger_on_touch = value;
+        self
+    }
+
+    /// Sets whether a synthetic `QuoteTick` is published to the message bus when order book
+    /// deltas change the top-of-book.
+    #[must_use]
+    pub const fn with_publish_top_of_book_quotes(mut self, value: bool) -> Self {
+        self.publish_top_of_book_quotes = value;
+        self
+    }
+
+    /// Sets whether batch cancels emit all `OrderCanceled` events before any
+    /// `OrderCancelRejected` events (otherwise events follow the command order).
+    #[must_use]
+    pub const fn with_group_batch_cancel_events(mut self, value: bool) -> Self {
+        self.group_batch_cancel_events = value;
+        self
+    }
+
+    /// Sets the pattern for generated position IDs when `use_position_ids` is true, where
+    /// `{venue}`, `{raw_id}`, `{instrument}` and `{counter}` are replaced (e.g. `P-{instrument}-{counter}`).
+    #[must_use]
+    pub const fn with_position_id_pattern(mut self, value: Option<Ustr>) -> Self {
+        self.position_id_pattern = value;
+        self
+    }
+
+    /// Sets whether the order events generated within one engine call are sent as a single
+    /// `Vec<OrderEventAny>` batch message (rather than individually).
+    #[must_use]
+    pub const fn with_batch_order_events(mut self, value: bool) -> Self {
+        self.batch_order_events = value;
+        self
+    }
+
+    /// Sets the grace period (nanoseconds) before instrument expiration during which only
+    /// orders reducing an open position are accepted.
+    #[must_use]
+    pub const fn with_expiry_grace_period_nanos(mut self, value: Option<u64>) -> Self {
+        self.expiry_grace_period_nanos = value;
+        self
+    }
+
+    /// Sets the offset (nanoseconds, may be negative) applied to the `ts_event` of generated
+    /// events, simulating a venue clock which differs from the engine clock.
+    #[must_use]
+    pub const fn with_venue_clock_offset_nanos(mut self, value: i64) -> Self {
+        self.venue_clock_offset_nanos = value;
+        self
+    }
+
+    /// Sets the handling of limit orders which would lock the market on submission.
+    #[must_use]
+    pub const fn with_lock_behavior(mut self, value: LockBehavior) -> Self {
+        self.lock_behavior = value;
+        self
+    }
+
+    /// Sets whether order book deltas with a sequence number already applied to the book
+    /// are skipped (rather than applied again).
+    #[must_use]
+    pub const fn with_skip_duplicate_deltas(mut self, value: bool) -> Self {
+        self.skip_duplicate_deltas = value;
+        self
+    }
+
+    /// Sets the trader ID all orders are expected to belong to, with orders for any other
+    /// trader rejected.
+    #[must_use]
+    pub const fn with_expected_trader_id(mut self, value: Option<TraderId>) -> Self {
+        self.expected_trader_id = value;
+        self
+    }
+
+    /// Sets whether every order is accepted and filled immediately in full at the current
+    /// midpoint, regardless of book depth (a "paper" mode for strategy smoke-testing).
+    #[must_use]
+    pub const fn with_paper_fill_at_mid(mut self, value: bool) -> Self {
+        self.paper_fill_at_mid = value;
+        self
+    }
+
+    /// Sets whether the other open orders sharing an `OrderListId` are canceled as a group
+    /// once any order of the list is completely filled.
+    #[must_use]
+    pub const fn with_cancel_order_list_on_fill(mut self, value: bool) -> Self {
+        self.cancel_order_list_on_fill = value;
+        self
+    }
+
+    /// Sets whether a triggered stop-limit order whose limit is not immediately fillable is
+    /// repriced to the opposing best price, making it marketable.
+    #[must_use]
+    pub const fn with_stop_limit_protect(mut self, value: bool) -> Self {
+        self.stop_limit_protect = value;
+        self
+    }
+
+    /// Sets the handling of GTD orders whose expire time has already passed on submission.
+    #[must_use]
+    pub const fn with_past_expire_time_handling(mut self, value: PastExpireTimeHandling) -> Self {
+        self.past_expire_time_handling = value;
+        self
+    }
+
+    /// Sets the minimum fill quantity to report, with smaller (dust) fills from consecutive
+    /// price levels accumulated into a single fill at their average price.
+    #[must_use]
+    pub const fn with_min_fill_report_qty(mut self, value: Option<Quantity>) -> Self {
+        self.min_fill_report_qty = value;
+        self
+    }
+
+    /// Sets whether orders are rejected while the market is temporarily inactive (paused,
+    /// suspended or not available for trading, e.g. during maintenance).
+    #[must_use]
+    pub const fn with_reject_orders_when_inactive(mut self, value: bool) -> Self {
+        self.reject_orders_when_inactive = value;
+        self
+    }
+
+    /// Sets whether orders which would be a taker (market orders and crossing limit orders)
+    /// are rejected, simulating a maker-only book.
+    #[must_use]
+    pub const fn with_reject_takers(mut self, value: bool) -> Self {
+        self.reject_takers = value;
+        self
+    }
+
+    /// Sets whether order prices and trigger prices are rounded to the instrument tick size
+    /// (emitting an `OrderUpdated`), rather than rejecting orders with an invalid precision.
+    #[must_use]
+    pub const fn with_round_prices_to_tick(mut self, value: bool) -> Self {
+        self.round_prices_to_tick = value;
+        self
+    }
+
+    /// Sets whether limit orders with a quote quantity are resolved to a base quantity at their
+    /// limit price on acceptance (emitting an `OrderUpdated`).
+    #[must_use]
+    pub const fn with_resolve_limit_quote_quantity(mut self, value: bool) -> Self {
+        self.resolve_limit_quote_quantity = value;
+        self
+    }
+
+    /// Sets whether an `OrderSubmitted` is emitted for initialized orders on submission,
+    /// acknowledging the order before it is accepted (or rejected).
+    #[must_use]
+    pub const fn with_emit_submitted(mut self, value: bool) -> Self {
+        self.emit_submitted = value;
+        self
+    }
+
+    /// Sets whether stop and if-touched orders with a `trigger_instrument_id` of another
+    /// instrument are triggered by that instrument's prices (see
+    /// `OrderMatchingEngine::process_trigger_quote_tick`) rather than this engine's book.
+    #[must_use]
+    pub const fn with_use_trigger_instruments(mut self, value: bool) -> Self {
+        self.use_trigger_instruments = value;
+        self
+    }
+
+    /// Sets the reference price market orders fill at when the book has no market for
+    /// their side, instead of being rejected (e.g. when only trade data is available).
+    #[must_use]
+    pub const fn with_no_market_reference_price(mut self, value: Option<Price>) -> Self {
+        self.no_market_reference_price = value;
+        self
+    }
+
+    /// Sets the order in which cancel-all commands emit the cancellations of open orders.
+    #[must_use]
+    pub const fn with_cancel_all_ordering(mut self, value: CancelAllOrdering) -> Self {
+        self.cancel_all_ordering = value;
+        self
+    }
+
+    /// Sets whether modifies of orders still in flight to the venue (see
+    /// `LatencyModel::with_insert_latency`) are queued and applied once the order has been
+    /// processed, rather than rejected as not found.
+    #[must_use]
+    pub const fn with_queue_modifies_until_accepted(mut self, value: bool) -> Self {
+        self.queue_modifies_until_accepted = value;
+        self
+    }
+
+    /// Sets whether resting stop-market and market-if-touched orders which the market gaps
+    /// through fill at their trigger price, rather than the worse available book price.
+    ///
+    /// Orders already in the market on submission still fill at the book price.
+    #[must_use]
+    pub const fn with_stop_fills_at_trigger_price(mut self, value: bool) -> Self {
+        self.stop_fills_at_trigger_price = value;
+        self
+    }
+
+    /// Sets whether trades trigger resting stop orders at the trade price on the side taken by
+    /// the (possibly inferred) aggressor, for L2/L3 books which trades do not move.
+    #[must_use]
+    pub const fn with_trigger_stops_on_trades(mut self, value: bool) -> Self {
+        self.trigger_stops_on_trades = value;
+        self
+    }
 }
 
 #[allow(clippy::derivable_impls)]
@@ -61,6 +543,50 @@ impl Default for OrderMatchingEngineConfig {
             use_position_ids: false,
             use_random_ids: false,
             use_reduce_only: false,
+            use_market_data_ts_for_fills: false,
+            support_auctions: false,
+            max_open_orders_per_account: None,
+            queue_market_orders_until_data: false,
+            record_trades: false,
+            resting_fills_at_own_price: false,
+            self_trade_prevention: None,
+            post_only_requires_quote: false,
+            reject_settlement_currency_mismatch: false,
+            recent_rejections_capacity: 0,
+            emit_updated_on_fill: false,
+            max_fills_per_order: None,
+            max_sweep_depth_pct: None,
+            cancel_reduce_only_when_flat: false,
+            max_modifies_per_order: None,
+            auction_tie_break: AuctionTieBreak::MinImbalance,
+            taker_fills_at_limit_price: false,
+            min_resting_time_nanos: None,
+            trigger_on_touch: true,
+            publish_top_of_book_quotes: false,
+            group_batch_cancel_events: false,
+            position_id_pattern: None,
+            batch_order_events: false,
+            expiry_grace_period_nanos: None,
+            venue_clock_offset_nanos: 0,
+            lock_behavior: LockBehavior::Match,
+            skip_duplicate_deltas: false,
+            expected_trader_id: None,
+            paper_fill_at_mid: false,
+            cancel_order_list_on_fill: false,
+            stop_limit_protect: false,
+            past_expire_time_handling: PastExpireTimeHandling::Accept,
+            min_fill_report_qty: None,
+            reject_orders_when_inactive: false,
+            reject_takers: false,
+            round_prices_to_tick: false,
+            resolve_limit_quote_quantity: false,
+            emit_submitted: false,
+            use_trigger_instruments: false,
+            no_market_reference_price: None,
+            cancel_all_ordering: CancelAllOrdering::Unordered,
+            queue_modifies_until_accepted: false,
+            stop_fills_at_trigger_price: false,
+            trigger_stops_on_trades: false,
         }
     }
 }
@@ -17,32 +17,39 @@
 #![allow(dead_code)]
 #![allow(unused_variables)]
 
+#[cfg(feature = "export")]
+use std::path::Path;
 use std::{
     any::Any,
     cell::RefCell,
     cmp::min,
-    collections::HashMap,
+    collections::{HashMap, VecDeque},
     ops::{Add, Sub},
     rc::Rc,
 };
 
 use chrono::TimeDelta;
 use nautilus_common::{cache::Cache, msgbus::MessageBus};
-use nautilus_core::{AtomicTime, UUID4, UnixNanos};
+use nautilus_core::{AtomicTime, UUID4, UnixNanos, time::TimeSource};
 use nautilus_model::{
-    data::{Bar, BarType, OrderBookDelta, OrderBookDeltas, QuoteTick, TradeTick, order::BookOrder},
+    data::{
+        Bar, BarType, OrderBookDelta, OrderBookDeltas, OrderBookDepth10, QuoteTick, TradeTick,
+        order::BookOrder,
+    },
     enums::{
-        AccountType, AggregationSource, AggressorSide, BarAggregation, BookType, ContingencyType,
-        LiquiditySide, MarketStatus, MarketStatusAction, OmsType, OrderSide, OrderSideSpecified,
-        OrderStatus, OrderType, PriceType, TimeInForce,
+        AccountType, AggregationSource, AggressorSide, BarAggregation, BookAction, BookType,
+        ContingencyType, LiquiditySide, MarketStatus, MarketStatusAction, ModifyRejectReason,
+        OmsType, OrderSide, OrderSideSpecified, OrderStatus, OrderType, PriceType, RecordFlag,
+        SelfTradePrevention, TimeInForce, TriggerType,
     },
     events::{
         OrderAccepted, OrderCancelRejected, OrderCanceled, OrderEventAny, OrderExpired,
-        OrderFilled, OrderModifyRejected, OrderRejected, OrderTriggered, OrderUpdated,
+        OrderFilled, OrderModifyRejected, OrderRejected, OrderSubmitted, OrderTriggered,
+        OrderUpdated,
     },
     identifiers::{
-        AccountId, ClientOrderId, InstrumentId, PositionId, StrategyId, TraderId, Venue,
-        VenueOrderId,
+        AccountId, ClientOrderId, InstrumentId, OrderListId, PositionId, StrategyId, TraderId,
+        Venue, VenueOrderId,
     },
     instruments::{EXPIRING_INSTRUMENT_TYPES, InstrumentAny},
     orderbook::OrderBook,
@@ -55,16 +62,76 @@ use nautilus_model::{
 };
 use ustr::Ustr;
 
+#[cfg(feature = "export")]
+use crate::matching_engine::export::{FillsExportFormat, write_fills};
 use crate::{
     matching_core::OrderMatchingCore,
-    matching_engine::{config::OrderMatchingEngineConfig, ids_generator::IdsGenerator},
+    matching_engine::{
+        auction::{calculate_clearing_with_tie_break, is_executable_at},
+        config::{
+            CancelAllOrdering, LockBehavior, OrderMatchingEngineConfig, PastExpireTimeHandling,
+        },
+        ids_generator::IdsGenerator,
+    },
     messages::{BatchCancelOrders, CancelAllOrders, CancelOrder, ModifyOrder, QueryOrder},
     models::{
         fee::{FeeModel, FeeModelAny},
         fill::FillModel,
+        latency::LatencyModel,
+        queue::{QueueModel, QueueModelAny},
+        tick::{TickScheme, TickSchemeAny},
     },
 };
 
+/// Callback invoked synchronously with each fill generated by the engine.
+pub type FillCallback = Box<dyn FnMut(&OrderFilled)>;
+
+/// Callback invoked synchronously with each rejection generated by the engine.
+pub type RejectCallback = Box<dyn FnMut(&OrderRejected)>;
+
+/// Callback invoked synchronously with each cancel generated by the engine.
+pub type CancelCallback = Box<dyn FnMut(&OrderCanceled)>;
+
+/// Callback invoked synchronously when a specific order expires.
+pub type ExpiredCallback = Box<dyn FnMut(&OrderExpired)>;
+
+/// Callback invoked synchronously when the engine becomes ready.
+pub type ReadyCallback = Box<dyn FnMut()>;
+
+/// Callback invoked synchronously each time the engine's book is updated by market data.
+pub type BookChangedCallback = Box<dyn FnMut(&BookChanged)>;
+
+/// A lightweight notification that the engine's book was updated, with the new top-of-book.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct BookChanged {
+    pub instrument_id: InstrumentId,
+    pub bid_price: Option<Price>,
+    pub ask_price: Option<Price>,
+    pub bid_size: Option<Quantity>,
+    pub ask_size: Option<Quantity>,
+    pub ts_event: UnixNanos,
+}
+
+/// Buffers the order events generated within one engine call, sending them to the
+/// execution engine as a single `Vec<OrderEventAny>` message when dropped.
+struct OrderEventBatch {
+    events: Rc<RefCell<Option<Vec<OrderEventAny>>>>,
+    msgbus: Rc<RefCell<MessageBus>>,
+}
+
+impl Drop for OrderEventBatch {
+    fn drop(&mut self) {
+        let Some(events) = self.events.borrow_mut().take() else {
+            return;
+        };
+        if events.is_empty() {
+            return;
+        }
+        let msgbus = self.msgbus.as_ref().borrow();
+        msgbus.send(&msgbus.switchboard.exec_engine_process, &events as &dyn Any);
+    }
+}
+
 /// An order matching engine for a single market.
 pub struct OrderMatchingEngine {
     /// The venue for the matching engine.
@@ -83,23 +150,62 @@ pub struct OrderMatchingEngine {
     pub market_status: MarketStatus,
     /// The config for the matching engine.
     pub config: OrderMatchingEngineConfig,
-    clock: &'static AtomicTime,
+    clock: Rc<dyn TimeSource>,
     msgbus: Rc<RefCell<MessageBus>>,
     cache: Rc<RefCell<Cache>>,
     book: OrderBook,
     pub core: OrderMatchingCore,
     fill_model: FillModel,
     fee_model: FeeModelAny,
+    queue_model: Option<QueueModelAny>,
+    latency_model: Option<LatencyModel>,
+    tick_scheme: Option<TickSchemeAny>,
     target_bid: Option<Price>,
     target_ask: Option<Price>,
     target_last: Option<Price>,
     last_bar_bid: Option<Bar>,
     last_bar_ask: Option<Bar>,
+    last_market_data_ts: UnixNanos,
+    mark_price: Option<Price>,
     execution_bar_types: HashMap<InstrumentId, BarType>,
     execution_bar_deltas: HashMap<BarType, TimeDelta>,
     account_ids: HashMap<TraderId, AccountId>,
+    order_account_ids: HashMap<ClientOrderId, AccountId>,
     cached_filled_qty: HashMap<ClientOrderId, Quantity>,
+    fill_counts: HashMap<ClientOrderId, usize>,
+    modify_counts: HashMap<ClientOrderId, usize>,
+    accepted_ts: HashMap<ClientOrderId, UnixNanos>,
+    times_to_fill: HashMap<ClientOrderId, u64>,
+    queue_ahead: HashMap<ClientOrderId, (f64, Option<UnixNanos>)>,
     ids_generator: IdsGenerator,
+    is_auction_collecting: bool,
+    auction_orders: Vec<OrderAny>,
+    fill_callbacks: Vec<FillCallback>,
+    reject_callbacks: Vec<RejectCallback>,
+    cancel_callbacks: Vec<CancelCallback>,
+    expired_callbacks: HashMap<ClientOrderId, ExpiredCallback>,
+    ready_callbacks: Vec<ReadyCallback>,
+    book_changed_callbacks: Vec<BookChangedCallback>,
+    was_ready: bool,
+    fills_buffer: Vec<(Price, Quantity)>,
+    pending_market_orders: Vec<OrderAny>,
+    trades: Vec<OrderFilled>,
+    recent_rejections: VecDeque<OrderRejected>,
+    last_top_of_book: Option<(Price, Price, Quantity, Quantity)>,
+    event_batch: Rc<RefCell<Option<Vec<OrderEventAny>>>>,
+    fill_positions: HashMap<PositionId, Position>,
+    closed_realized_pnl: f64,
+    session_traded_notional: f64,
+    session_traded_volume: f64,
+    last_trade: Option<TradeTick>,
+    submitted_count: usize,
+    filled_count: usize,
+    rejected_count: usize,
+    peak_equity: f64,
+    max_drawdown: f64,
+    trigger_cores: HashMap<InstrumentId, OrderMatchingCore>,
+    inflight_orders: Vec<(UnixNanos, OrderAny, AccountId)>,
+    pending_modifies: HashMap<ClientOrderId, Vec<(ModifyOrder, AccountId)>>,
 }
 
 impl OrderMatchingEngine {
@@ -132,7 +238,8 @@ impl OrderMatchingEngine {
             config.use_random_ids,
             config.use_position_ids,
             cache.clone(),
-        );
+        )
+        .with_position_id_pattern(config.position_id_pattern);
 
         Self {
             venue: instrument.id().venue,
@@ -140,10 +247,13 @@ impl OrderMatchingEngine {
             raw_id,
             fill_model,
             fee_model,
+            queue_model: None,
+            latency_model: None,
+            tick_scheme: None,
             book_type,
             oms_type,
             account_type,
-            clock,
+            clock: Rc::new(clock),
             msgbus,
             cache,
             book,
@@ -155,11 +265,47 @@ impl OrderMatchingEngine {
             target_last: None,
             last_bar_bid: None,
             last_bar_ask: None,
+            last_market_data_ts: UnixNanos::default(),
+            mark_price: None,
             execution_bar_types: HashMap::new(),
             execution_bar_deltas: HashMap::new(),
             account_ids: HashMap::new(),
+            order_account_ids: HashMap::new(),
             cached_filled_qty: HashMap::new(),
+            fill_counts: HashMap::new(),
+            modify_counts: HashMap::new(),
+            accepted_ts: HashMap::new(),
+            times_to_fill: HashMap::new(),
+            queue_ahead: HashMap::new(),
             ids_generator,
+            is_auction_collecting: false,
+            auction_orders: Vec::new(),
+            fill_callbacks: Vec::new(),
+            reject_callbacks: Vec::new(),
+            cancel_callbacks: Vec::new(),
+            expired_callbacks: HashMap::new(),
+            ready_callbacks: Vec::new(),
+            book_changed_callbacks: Vec::new(),
+            was_ready: false,
+            fills_buffer: Vec::new(),
+            pending_market_orders: Vec::new(),
+            trades: Vec::new(),
+            recent_rejections: VecDeque::new(),
+            last_top_of_book: None,
+            event_batch: Rc::new(RefCell::new(None)),
+            fill_positions: HashMap::new(),
+            closed_realized_pnl: 0.0,
+            session_traded_notional: 0.0,
+            session_traded_volume: 0.0,
+            last_trade: None,
+            submitted_count: 0,
+            filled_count: 0,
+            rejected_count: 0,
+            peak_equity: 0.0,
+            max_drawdown: 0.0,
+            trigger_cores: HashMap::new(),
+            inflight_orders: Vec::new(),
+            pending_modifies: HashMap::new(),
         }
     }
 
@@ -168,12 +314,41 @@ impl OrderMatchingEngine {
         self.execution_bar_types.clear();
         self.execution_bar_deltas.clear();
         self.account_ids.clear();
+        self.order_account_ids.clear();
         self.cached_filled_qty.clear();
+        self.fill_counts.clear();
+        self.modify_counts.clear();
+        self.accepted_ts.clear();
+        self.times_to_fill.clear();
+        self.queue_ahead.clear();
         self.core.reset();
         self.target_bid = None;
         self.target_ask = None;
         self.target_last = None;
+        self.last_market_data_ts = UnixNanos::default();
+        self.mark_price = None;
         self.ids_generator.reset();
+        self.is_auction_collecting = false;
+        self.auction_orders.clear();
+        self.expired_callbacks.clear();
+        self.was_ready = false;
+        self.pending_market_orders.clear();
+        self.trades.clear();
+        self.recent_rejections.clear();
+        self.last_top_of_book = None;
+        self.fill_positions.clear();
+        self.closed_realized_pnl = 0.0;
+        self.session_traded_notional = 0.0;
+        self.session_traded_volume = 0.0;
+        self.last_trade = None;
+        self.submitted_count = 0;
+        self.filled_count = 0;
+        self.rejected_count = 0;
+        self.peak_equity = 0.0;
+        self.max_drawdown = 0.0;
+        self.trigger_cores.clear();
+        self.inflight_orders.clear();
+        self.pending_modifies.clear();
 
         log::info!("Reset {}", self.instrument.id());
     }
@@ -182,6 +357,95 @@ impl OrderMatchingEngine {
         self.fill_model = fill_model;
     }
 
+    pub fn set_queue_model(&mut self, queue_model: QueueModelAny) {
+        self.queue_model = Some(queue_model);
+    }
+
+    pub fn set_latency_model(&mut self, latency_model: LatencyModel) {
+        self.latency_model = Some(latency_model);
+    }
+
+    /// Sets the clock used for event timestamps, replacing the clock given on construction.
+    pub fn set_clock(&mut self, clock: Rc<dyn TimeSource>) {
+        self.clock = clock;
+    }
+
+    pub fn set_tick_scheme(&mut self, tick_scheme: TickSchemeAny) {
+        self.tick_scheme = Some(tick_scheme);
+    }
+
+    /// Registers a `callback` invoked synchronously with each fill generated by the engine.
+    pub fn on_fill(&mut self, callback: FillCallback) {
+        self.fill_callbacks.push(callback);
+    }
+
+    /// Registers a `callback` invoked synchronously with each rejection generated by the engine.
+    pub fn on_reject(&mut self, callback: RejectCallback) {
+        self.reject_callbacks.push(callback);
+    }
+
+    /// Registers a `callback` invoked synchronously with each cancel generated by the engine.
+    pub fn on_cancel(&mut self, callback: CancelCallback) {
+        self.cancel_callbacks.push(callback);
+    }
+
+    /// Registers a `callback` invoked when the order with `client_order_id` expires.
+    ///
+    /// The callback fires at most once and replaces any callback previously registered for the order.
+    pub fn on_order_expired(&mut self, client_order_id: ClientOrderId, callback: ExpiredCallback) {
+        self.expired_callbacks.insert(client_order_id, callback);
+    }
+
+    /// Registers a `callback` invoked each time the engine becomes ready (see [`Self::is_ready`]).
+    pub fn on_ready(&mut self, callback: ReadyCallback) {
+        self.ready_callbacks.push(callback);
+    }
+
+    /// Registers a `callback` invoked with the new top-of-book each time the engine's book is
+    /// updated by market data, for tracking liquidity without subscribing to raw deltas.
+    pub fn on_book_changed(&mut self, callback: BookChangedCallback) {
+        self.book_changed_callbacks.push(callback);
+    }
+
+    /// Invokes the book changed callbacks with the current top-of-book.
+    fn notify_book_changed(&mut self, ts_event: UnixNanos) {
+        if self.book_changed_callbacks.is_empty() {
+            return;
+        }
+
+        let book_changed = BookChanged {
+            instrument_id: self.instrument.id(),
+            bid_price: self.book.best_bid_price(),
+            ask_price: self.book.best_ask_price(),
+            bid_size: self.book.best_bid_size(),
+            ask_size: self.book.best_ask_size(),
+            ts_event,
+        };
+        for callback in &mut self.book_changed_callbacks {
+            callback(&book_changed);
+        }
+    }
+
+    /// Returns whether the engine is ready to match orders, with both the bid and ask
+    /// initialized by market data and the market open.
+    #[must_use]
+    pub fn is_ready(&self) -> bool {
+        self.core.is_bid_initialized
+            && self.core.is_ask_initialized
+            && self.market_status == MarketStatus::Open
+    }
+
+    /// Invokes the ready callbacks if the engine has become ready since the last check.
+    fn check_ready(&mut self) {
+        let is_ready = self.is_ready();
+        if is_ready && !self.was_ready {
+            for callback in &mut self.ready_callbacks {
+                callback();
+            }
+        }
+        self.was_ready = is_ready;
+    }
+
     #[must_use]
     pub fn best_bid_price(&self) -> Option<Price> {
         self.book.best_bid_price()
@@ -216,45 +480,437 @@ impl OrderMatchingEngine {
         orders
     }
 
+    /// Returns the resting orders of the given `account_id`.
+    #[must_use]
+    pub fn get_open_orders_for_account(&self, account_id: AccountId) -> Vec<PassiveOrderAny> {
+        self.core
+            .get_orders()
+            .into_iter()
+            .filter(|order| self.order_account_id(&order.to_any()) == Some(account_id))
+            .collect()
+    }
+
+    /// Returns the publicly displayed depth of the resting limit orders on the given `side`,
+    /// as aggregated quantity per price level in priority order.
+    ///
+    /// Only the `display_qty` of an order is exposed, so a `display_qty` of zero means a
+    /// fully hidden order which still matches but is omitted from the depth.
+    #[must_use]
+    pub fn book_depth(&self, side: OrderSide) -> Vec<(Price, Quantity)> {
+        let orders = match side {
+            OrderSide::Buy => self.core.get_orders_bid(),
+            _ => self.core.get_orders_ask(),
+        };
+
+        let mut depth: Vec<(Price, Quantity)> = Vec::new();
+        for order in orders {
+            if !matches!(order, PassiveOrderAny::Limit(_)) {
+                continue;
+            }
+            let order = order.to_any();
+            let Some(price) = order.price() else {
+                continue;
+            };
+            let leaves_qty = order.leaves_qty();
+            let display_qty = order
+                .display_qty()
+                .map_or(leaves_qty, |q| min(q, leaves_qty));
+            if display_qty.is_zero() {
+                continue; // Fully hidden
+            }
+
+            match depth.last_mut() {
+                Some((level_px, level_qty)) if *level_px == price => *level_qty += display_qty,
+                _ => depth.push((price, display_qty)),
+            }
+        }
+        depth
+    }
+
+    /// Returns whether the given limit `order` would immediately cross the current book
+    /// and take liquidity, allowing post-only orders to be pre-checked before submission.
+    ///
+    /// Returns `None` if the order has no limit price.
+    #[must_use]
+    pub fn would_be_taker(&self, order: &OrderAny) -> Option<bool> {
+        order.price().map(|price| {
+            self.core
+                .is_limit_matched(order.order_side_specified(), price)
+        })
+    }
+
     #[must_use]
     pub fn order_exists(&self, client_order_id: ClientOrderId) -> bool {
         self.core.order_exists(client_order_id)
     }
 
+    /// Returns all fills generated by the engine in the order they occurred.
+    ///
+    /// The log is only retained when the `record_trades` config option is enabled.
+    #[must_use]
+    pub fn trades(&self) -> &[OrderFilled] {
+        &self.trades
+    }
+
+    /// Exports the retained fills log (see [`Self::trades`]) to a file at `path` in the
+    /// given `format`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the file cannot be created or written.
+    #[cfg(feature = "export")]
+    pub fn export_fills(&self, path: &Path, format: FillsExportFormat) -> anyhow::Result<()> {
+        write_fills(&self.trades, path, format)
+    }
+
+    /// Returns the most recent order rejections, oldest first.
+    ///
+    /// At most `recent_rejections_capacity` (from the config) rejections are retained,
+    /// with the oldest evicted first.
+    #[must_use]
+    pub fn recent_rejections(&self) -> &VecDeque<OrderRejected> {
+        &self.recent_rejections
+    }
+
+    /// Returns the unrealized PnL of the open positions for the given `instrument_id`,
+    /// marked to the last mark price update, otherwise the current book midpoint
+    /// (or the last trade price if there is no midpoint).
+    ///
+    /// Returns `None` if the instrument is not matched by this engine or no mark price is available.
+    #[must_use]
+    pub fn unrealized_pnl(&self, instrument_id: InstrumentId) -> Option<Money> {
+        if instrument_id != self.instrument.id() {
+            return None;
+        }
+
+        let mark_price = self.pnl_mark_price()?;
+        let cache = self.cache.borrow();
+        let positions = cache.positions_open(None, Some(&instrument_id), None, None);
+        let pnl: f64 = positions
+            .iter()
+            .map(|position| position.unrealized_pnl(mark_price).as_f64())
+            .sum();
+
+        Some(Money::new(pnl, self.instrument.settlement_currency()))
+    }
+
+    /// Returns the maximum drawdown (in the settlement currency) of the equity from the fills
+    /// generated by this engine, being its realized PnL plus the unrealized PnL of its open
+    /// positions marked as for [`Self::unrealized_pnl`].
+    ///
+    /// Equity is tracked on each fill and market update, from zero at the start of the run.
+    #[must_use]
+    pub const fn max_drawdown(&self) -> f64 {
+        self.max_drawdown
+    }
+
+    /// Returns the time (nanoseconds) from the acceptance of the order with the given
+    /// `client_order_id` to its last fill, including any fill latency.
+    ///
+    /// Returns `None` if the order was not accepted to rest by this engine or has no fills.
+    #[must_use]
+    pub fn time_to_fill(&self, client_order_id: ClientOrderId) -> Option<u64> {
+        self.times_to_fill.get(&client_order_id).copied()
+    }
+
+    /// Returns the realized PnL (net of commissions) from the fills generated by this engine
+    /// for the given `instrument_id`, including positions which have since been closed.
+    #[must_use]
+    pub fn realized_pnl(&self, instrument_id: InstrumentId) -> Money {
+        let currency = self.instrument.settlement_currency();
+        if instrument_id != self.instrument.id() {
+            return Money::new(0.0, currency);
+        }
+
+        let open_pnl: f64 = self
+            .fill_positions
+            .values()
+            .filter_map(|position| position.realized_pnl)
+            .map(|pnl| pnl.as_f64())
+            .sum();
+
+        Money::new(self.closed_realized_pnl + open_pnl, currency)
+    }
+
+    /// Returns the volume-weighted average price of the trades processed this session.
+    ///
+    /// Returns `None` if no trade volume has been processed.
+    #[must_use]
+    pub fn session_vwap(&self) -> Option<Price> {
+        if self.session_traded_volume == 0.0 {
+            return None;
+        }
+        Some(Price::new(
+            self.session_traded_notional / self.session_traded_volume,
+            self.instrument.price_precision(),
+        ))
+    }
+
+    /// Returns the number of orders submitted to the engine, over the lifetime of the engine
+    /// (or since the last reset).
+    #[must_use]
+    pub const fn submitted_count(&self) -> usize {
+        self.submitted_count
+    }
+
+    /// Returns the number of submitted orders which were completely filled, over the lifetime
+    /// of the engine (or since the last reset).
+    #[must_use]
+    pub const fn filled_count(&self) -> usize {
+        self.filled_count
+    }
+
+    /// Returns the number of submitted orders which were rejected, over the lifetime
+    /// of the engine (or since the last reset).
+    #[must_use]
+    pub const fn rejected_count(&self) -> usize {
+        self.rejected_count
+    }
+
+    /// Returns the ratio of submitted orders which were completely filled, over the lifetime
+    /// of the engine (or since the last reset).
+    #[must_use]
+    pub fn fill_ratio(&self) -> f64 {
+        if self.submitted_count == 0 {
+            return 0.0;
+        }
+        self.filled_count as f64 / self.submitted_count as f64
+    }
+
+    /// Returns the ratio of submitted orders which were rejected, over the lifetime
+    /// of the engine (or since the last reset).
+    #[must_use]
+    pub fn rejection_ratio(&self) -> f64 {
+        if self.submitted_count == 0 {
+            return 0.0;
+        }
+        self.rejected_count as f64 / self.submitted_count as f64
+    }
+
+    /// Returns the last trade processed this session.
+    #[must_use]
+    pub const fn last_trade(&self) -> Option<&TradeTick> {
+        self.last_trade.as_ref()
+    }
+
     // -- DATA PROCESSING -------------------------------------------------------------------------
 
     /// Process the venues market for the given order book delta.
     pub fn process_order_book_delta(&mut self, delta: &OrderBookDelta) {
+        let _event_batch = self.begin_event_batch();
         log::debug!("Processing {delta}");
 
+        if self.is_duplicate_delta(delta, self.book.sequence) {
+            return;
+        }
+
         if self.book_type == BookType::L2_MBP || self.book_type == BookType::L3_MBO {
-            self.book.apply_delta(delta);
+            self.apply_book_delta(delta);
+        }
+
+        // Defer matching until the last delta of a snapshot so orders are never
+        // evaluated against an incomplete book
+        if RecordFlag::F_SNAPSHOT.matches(delta.flags) && !RecordFlag::F_LAST.matches(delta.flags) {
+            return;
+        }
+
+        if self.book_type == BookType::L2_MBP || self.book_type == BookType::L3_MBO {
+            self.notify_book_changed(delta.ts_event);
+        }
+        if self.config.publish_top_of_book_quotes {
+            self.publish_top_of_book_quote(delta.ts_event, delta.ts_init);
         }
 
         self.iterate(delta.ts_event);
     }
 
+    /// Returns whether the `delta` has already been applied to the book, by its sequence
+    /// (when `skip_duplicate_deltas` is configured).
+    fn is_duplicate_delta(&self, delta: &OrderBookDelta, last_sequence: u64) -> bool {
+        let is_duplicate = self.config.skip_duplicate_deltas
+            && delta.sequence != 0
+            && delta.sequence <= last_sequence;
+        if is_duplicate {
+            log::warn!(
+                "Skipping duplicate delta with sequence {}, last applied sequence {last_sequence}",
+                delta.sequence,
+            );
+        }
+        is_duplicate
+    }
+
     pub fn process_order_book_deltas(&mut self, deltas: &OrderBookDeltas) {
+        let _event_batch = self.begin_event_batch();
         log::debug!("Processing {deltas}");
 
+        // Track the last sequence as the batch is filtered, so sequences repeated within
+        // the batch are also skipped
+        let mut last_sequence = self.book.sequence;
+        let deltas_to_apply: Vec<&OrderBookDelta> = deltas
+            .deltas
+            .iter()
+            .filter(|delta| {
+                if self.is_duplicate_delta(delta, last_sequence) {
+                    return false;
+                }
+                last_sequence = last_sequence.max(delta.sequence);
+                true
+            })
+            .collect();
+        if deltas_to_apply.is_empty() {
+            return;
+        }
+
         if self.book_type == BookType::L2_MBP || self.book_type == BookType::L3_MBO {
-            self.book.apply_deltas(deltas);
+            for delta in deltas_to_apply {
+                self.apply_book_delta(delta);
+            }
+            self.notify_book_changed(deltas.ts_event);
+        }
+
+        if self.config.publish_top_of_book_quotes {
+            self.publish_top_of_book_quote(deltas.ts_event, deltas.ts_init);
         }
 
         self.iterate(deltas.ts_event);
     }
 
+    /// Applies the `delta` to the book, treating a zero-size add or update as a removal so
+    /// no empty level is left at the top-of-book.
+    fn apply_book_delta(&mut self, delta: &OrderBookDelta) {
+        let is_zero_size = matches!(delta.action, BookAction::Add | BookAction::Update)
+            && delta.order.size.raw == 0;
+        if is_zero_size {
+            self.book
+                .delete(delta.order, delta.flags, delta.sequence, delta.ts_event);
+        } else {
+            self.book.apply_delta(delta);
+        }
+    }
+
+    /// Returns whether the current time is within the configured grace period before the
+    /// instrument expiration.
+    fn is_within_expiry_grace_period(&self) -> bool {
+        let Some(grace_period_nanos) = self.config.expiry_grace_period_nanos else {
+            return false;
+        };
+        if !EXPIRING_INSTRUMENT_TYPES.contains(&self.instrument.instrument_class()) {
+            return false;
+        }
+        self.instrument
+            .expiration_ns()
+            .is_some_and(|expiration_ns| {
+                self.clock
+                    .get_time_ns()
+                    .as_u64()
+                    .saturating_add(grace_period_nanos)
+                    >= expiration_ns.as_u64()
+            })
+    }
+
+    /// Publishes a synthetic `QuoteTick` for the current top-of-book, if it has changed since
+    /// the last published quote and both sides of the book exist.
+    fn publish_top_of_book_quote(&mut self, ts_event: UnixNanos, ts_init: UnixNanos) {
+        let (Some(bid_price), Some(ask_price), Some(bid_size), Some(ask_size)) = (
+            self.book.best_bid_price(),
+            self.book.best_ask_price(),
+            self.book.best_bid_size(),
+            self.book.best_ask_size(),
+        ) else {
+            return;
+        };
+
+        let top_of_book = (bid_price, ask_price, bid_size, ask_size);
+        if self.last_top_of_book == Some(top_of_book) {
+            return;
+        }
+        self.last_top_of_book = Some(top_of_book);
+
+        let quote = QuoteTick::new(
+            self.instrument.id(),
+            bid_price,
+            ask_price,
+            bid_size,
+            ask_size,
+            ts_event,
+            ts_init,
+        );
+        let topic = self
+            .msgbus
+            .borrow_mut()
+            .switchboard
+            .get_quotes_topic(self.instrument.id());
+        let msgbus = self.msgbus.as_ref().borrow();
+        msgbus.publish(&topic, &quote as &dyn Any);
+    }
+
+    /// Process the venues market for the given order book depth snapshot,
+    /// replacing the current book state.
+    pub fn process_order_book_depth10(&mut self, depth: &OrderBookDepth10) {
+        let _event_batch = self.begin_event_batch();
+        log::debug!("Processing {depth}");
+
+        if self.book_type == BookType::L2_MBP || self.book_type == BookType::L3_MBO {
+            self.book.apply_depth(depth);
+            self.notify_book_changed(depth.ts_event);
+        }
+
+        self.iterate(depth.ts_event);
+    }
+
     pub fn process_quote_tick(&mut self, quote: &QuoteTick) {
+        let _event_batch = self.begin_event_batch();
         log::debug!("Processing {quote}");
 
         if self.book_type == BookType::L1_MBP {
             self.book.update_quote_tick(quote).unwrap();
+            self.notify_book_changed(quote.ts_event);
         }
 
         self.iterate(quote.ts_event);
     }
 
+    /// Process a quote of another instrument, triggering any resting orders which use that
+    /// instrument as their trigger instrument (requires `use_trigger_instruments`).
+    pub fn process_trigger_quote_tick(&mut self, quote: &QuoteTick) {
+        let _event_batch = self.begin_event_batch();
+        log::debug!("Processing trigger instrument {quote}");
+
+        let instrument_id = quote.instrument_id;
+        let cache = &self.cache;
+        let trigger_core = self.trigger_cores.entry(instrument_id).or_insert_with(|| {
+            // Compare at the tick of the trigger instrument, falling back to the quote precision
+            let price_increment = cache.borrow().instrument(&instrument_id).map_or_else(
+                || {
+                    let precision = quote.bid_price.precision;
+                    Price::new(10f64.powi(-i32::from(precision)), precision)
+                },
+                InstrumentAny::price_increment,
+            );
+            OrderMatchingCore::new(instrument_id, price_increment, None, None, None)
+        });
+        trigger_core.set_bid_raw(quote.bid_price);
+        trigger_core.set_ask_raw(quote.ask_price);
+
+        let orders: Vec<OrderAny> = self
+            .core
+            .get_orders()
+            .into_iter()
+            .map(OrderAny::from)
+            .filter(|order| {
+                order.trigger_instrument_id() == Some(instrument_id)
+                    && order.status() != OrderStatus::Triggered
+            })
+            .collect();
+        for mut order in orders {
+            if self.is_trigger_instrument_triggered(&order) == Some(true) {
+                self.trigger_stop_order(&mut order);
+            }
+        }
+    }
+
     pub fn process_bar(&mut self, bar: &Bar) {
+        let _event_batch = self.begin_event_batch();
         log::debug!("Processing {bar}");
 
         // Check if configured for bar execution can only process an L1 book with bars
@@ -443,21 +1099,121 @@ impl OrderMatchingEngine {
     }
 
     pub fn process_trade_tick(&mut self, trade: &TradeTick) {
+        let _event_batch = self.begin_event_batch();
         log::debug!("Processing {trade}");
 
+        // Infer before the trade moves an L1 book
+        let aggressor_side = self.infer_aggressor_side(trade);
+
         if self.book_type == BookType::L1_MBP {
             self.book.update_trade_tick(trade).unwrap();
+            self.notify_book_changed(trade.ts_event);
         }
         self.core.set_last_raw(trade.price);
+        self.session_traded_notional += trade.price.as_f64() * trade.size.as_f64();
+        self.session_traded_volume += trade.size.as_f64();
+        self.last_trade = Some(*trade);
 
         self.iterate(trade.ts_event);
+
+        if self.config.trigger_stops_on_trades && self.book_type != BookType::L1_MBP {
+            self.trigger_stop_orders_at_trade(aggressor_side, trade.price);
+        }
+    }
+
+    /// Returns the aggressor side of the `trade`, inferring it from the current bid/ask
+    /// when the trade has [`AggressorSide::NoAggressor`].
+    ///
+    /// A trade at or above the ask is inferred as a buyer, at or below the bid as a seller,
+    /// and a trade inside the spread remains without an aggressor.
+    #[must_use]
+    pub fn infer_aggressor_side(&self, trade: &TradeTick) -> AggressorSide {
+        if trade.aggressor_side != AggressorSide::NoAggressor {
+            return trade.aggressor_side;
+        }
+
+        if self.core.ask.is_some_and(|ask| trade.price >= ask) {
+            AggressorSide::Buyer
+        } else if self.core.bid.is_some_and(|bid| trade.price <= bid) {
+            AggressorSide::Seller
+        } else {
+            AggressorSide::NoAggressor
+        }
+    }
+
+    /// Triggers the resting stop orders which a trade at `price` reached on the side taken
+    /// by the `aggressor_side`, as trades do not move L2/L3 books.
+    fn trigger_stop_orders_at_trade(&mut self, aggressor_side: AggressorSide, price: Price) {
+        let (bid, ask) = (self.core.bid, self.core.ask);
+        match aggressor_side {
+            AggressorSide::Buyer => self.core.ask = Some(price),
+            AggressorSide::Seller => self.core.bid = Some(price),
+            AggressorSide::NoAggressor => return,
+        }
+
+        let stop_orders: Vec<PassiveOrderAny> = self
+            .core
+            .get_orders()
+            .into_iter()
+            .filter(|order| {
+                matches!(order, PassiveOrderAny::Stop(_))
+                    && order.to_any().status() != OrderStatus::Triggered
+            })
+            .collect();
+        for order in &stop_orders {
+            self.match_order(order);
+        }
+
+        self.core.bid = bid;
+        self.core.ask = ask;
+    }
+
+    /// Process the venues market for the given mark `price`.
+    ///
+    /// Updates the mark reference used for unrealized PnL and triggers any resting
+    /// orders with a [`TriggerType::MarkPrice`] trigger which the mark has reached.
+    pub fn process_mark_price_update(&mut self, price: Price) {
+        let _event_batch = self.begin_event_batch();
+        log::debug!("Processing mark price {price}");
+
+        self.mark_price = Some(price);
+        self.update_drawdown();
+
+        let orders: Vec<OrderAny> = self
+            .core
+            .get_orders()
+            .into_iter()
+            .map(OrderAny::from)
+            .filter(|order| {
+                order.trigger_type() == Some(TriggerType::MarkPrice)
+                    && order.status() != OrderStatus::Triggered
+            })
+            .collect();
+
+        // The mark price is the market on both sides when evaluating triggers
+        let mut mark_core = OrderMatchingCore::new(
+            self.instrument.id(),
+            self.instrument.price_increment(),
+            None,
+            None,
+            None,
+        );
+        mark_core.set_bid_raw(price);
+        mark_core.set_ask_raw(price);
+
+        for mut order in orders {
+            if self.is_core_triggered(&mark_core, &order) {
+                self.trigger_stop_order(&mut order);
+            }
+        }
     }
 
     pub fn process_status(&mut self, action: MarketStatusAction) {
+        let _event_batch = self.begin_event_batch();
         log::debug!("Processing {action}");
 
-        // Check if market is closed and market opens with trading or pre-open status
-        if self.market_status == MarketStatus::Closed
+        // Check if market is closed or inactive and market opens with trading or pre-open status
+        if self.market_status != MarketStatus::Open
             && (action == MarketStatusAction::Trading || action == MarketStatusAction::PreOpen)
         {
             self.market_status = MarketStatus::Open;
@@ -470,21 +1226,215 @@ impl OrderMatchingEngine {
         if self.market_status == MarketStatus::Open && action == MarketStatusAction::Suspend {
             self.market_status = MarketStatus::Suspended;
         }
+        // Check if market is open and becomes unavailable for trading (e.g. maintenance)
+        if self.market_status == MarketStatus::Open
+            && action == MarketStatusAction::NotAvailableForTrading
+        {
+            self.market_status = MarketStatus::NotAvailable;
+        }
         // Check if market is open and we halt or close
         if self.market_status == MarketStatus::Open
             && (action == MarketStatusAction::Halt || action == MarketStatusAction::Close)
         {
             self.market_status = MarketStatus::Closed;
         }
+        self.check_ready();
+
+        if self.config.support_auctions {
+            match action {
+                MarketStatusAction::PreOpen | MarketStatusAction::PreCross => {
+                    self.is_auction_collecting = true;
+                }
+                MarketStatusAction::Cross | MarketStatusAction::Trading
+                    if self.is_auction_collecting =>
+                {
+                    self.uncross_auction();
+                }
+                _ => {}
+            }
+        }
+    }
+
+    /// Uncrosses the orders collected during the auction at the volume maximizing clearing price.
+    ///
+    /// Market orders are treated as "market on open" and any unfilled quantity is canceled,
+    /// limit orders only fill when the clearing price is within their limit and any unfilled
+    /// quantity then rests for continuous trading.
+    pub fn uncross_auction(&mut self) {
+        self.is_auction_collecting = false;
+        let mut orders = std::mem::take(&mut self.auction_orders);
+        if let Some(mode) = self.config.self_trade_prevention {
+            self.prevent_auction_self_trades(&mut orders, mode);
+        }
+        // The last traded price is the reference for nearest reference tie-breaking
+        let clearing = calculate_clearing_with_tie_break(
+            &orders,
+            self.config.auction_tie_break,
+            self.core.last,
+        );
+
+        if let Some(clearing) = clearing {
+            log::info!(
+                "Uncrossing auction for {} at {} for {}",
+                self.instrument.id(),
+                clearing.price,
+                clearing.volume
+            );
+
+            for side in [OrderSide::Buy, OrderSide::Sell] {
+                // Market orders have priority, then limit orders by price, then by arrival
+                let mut eligible: Vec<&mut OrderAny> = orders
+                    .iter_mut()
+                    .filter(|order| {
+                        order.order_side() == side && is_executable_at(order, clearing.price)
+                    })
+                    .collect();
+                eligible.sort_by(|a, b| match (a.price(), b.price()) {
+                    (None, None) => std::cmp::Ordering::Equal,
+                    (None, Some(_)) => std::cmp::Ordering::Less,
+                    (Some(_), None) => std::cmp::Ordering::Greater,
+                    (Some(a_px), Some(b_px)) => match side {
+                        OrderSide::Buy => b_px.cmp(&a_px),
+                        _ => a_px.cmp(&b_px),
+                    },
+                });
+
+                let mut remaining = clearing.volume;
+                for order in eligible {
+                    if remaining.is_zero() {
+                        break;
+                    }
+                    let fill_qty = min(order.leaves_qty(), remaining);
+                    let liquidity_side = if order.price().is_some() {
+                        LiquiditySide::Maker
+                    } else {
+                        LiquiditySide::Taker
+                    };
+                    order.set_liquidity_side(liquidity_side);
+                    self.fill_order(order, clearing.price, fill_qty, liquidity_side, None, None);
+                    remaining -= fill_qty;
+                }
+            }
+        }
+
+        for mut order in orders {
+            let filled_qty = self
+                .cached_filled_qty
+                .get(&order.client_order_id())
+                .copied()
+                .unwrap_or(Quantity::zero(order.quantity().precision));
+            if filled_qty >= order.quantity() {
+                self.cached_filled_qty.remove(&order.client_order_id());
+            } else if order.price().is_none() {
+                // Market on open quantity which did not execute at the uncross,
+                // the order was never added to the matching core
+                self.cached_filled_qty.remove(&order.client_order_id());
+                let venue_order_id = self.ids_generator.get_venue_order_id(&order).unwrap();
+                self.generate_order_canceled(&order, venue_order_id, None);
+            } else {
+                // Unfilled limit quantity rests for continuous trading
+                order.set_liquidity_side(LiquiditySide::Maker);
+                let _ = self.core.add_order(order.into());
+            }
+        }
+    }
+
+    /// Cancels auction `orders` per the self-trade prevention `mode` while orders of the same
+    /// strategy on opposite sides would both execute at the clearing price.
+    fn prevent_auction_self_trades(
+        &mut self,
+        orders: &mut Vec<OrderAny>,
+        mode: SelfTradePrevention,
+    ) {
+        while let Some(clearing) =
+            calculate_clearing_with_tie_break(orders, self.config.auction_tie_break, self.core.last)
+        {
+            // Orders are held in arrival order, so the first of a crossing pair is the oldest
+            let executable: Vec<usize> = (0..orders.len())
+                .filter(|&i| is_executable_at(&orders[i], clearing.price))
+                .collect();
+            let self_cross = executable.iter().find_map(|&oldest| {
+                executable
+                    .iter()
+                    .find(|&&newest| {
+                        newest > oldest
+                            && orders[newest].strategy_id() == orders[oldest].strategy_id()
+                            && orders[newest].order_side() != orders[oldest].order_side()
+                    })
+                    .map(|&newest| (oldest, newest))
+            });
+            let Some((oldest, newest)) = self_cross else {
+                break;
+            };
+
+            let reason = Ustr::from(&format!(
+                "Self-trade prevented at auction uncross: order {} would match order {} ({mode})",
+                orders[newest].client_order_id(),
+                orders[oldest].client_order_id()
+            ));
+            // Removed in descending index order so the remaining indices stay valid
+            let canceled = match mode {
+                SelfTradePrevention::CancelNewest => vec![newest],
+                SelfTradePrevention::CancelOldest => vec![oldest],
+                SelfTradePrevention::CancelBoth => vec![newest, oldest],
+            };
+            for index in canceled {
+                let order = orders.remove(index);
+                let venue_order_id = self.ids_generator.get_venue_order_id(&order).unwrap();
+                self.generate_order_canceled(&order, venue_order_id, Some(reason));
+            }
+        }
     }
 
     // -- TRADING COMMANDS ------------------------------------------------------------------------
 
     #[allow(clippy::needless_return)]
     pub fn process_order(&mut self, order: &mut OrderAny, account_id: AccountId) {
+        // Hold the order in flight until it arrives at the venue on a later iteration
+        let insert_latency_nanos = self
+            .latency_model
+            .as_mut()
+            .map_or(0, LatencyModel::insert_latency);
+        if insert_latency_nanos > 0 {
+            let ts_arrival = self.clock.get_time_ns() + insert_latency_nanos;
+            self.inflight_orders
+                .push((ts_arrival, order.clone(), account_id));
+            return;
+        }
+
+        self.process_order_arrival(order, account_id);
+    }
+
+    /// Processes the in flight orders which have arrived at the venue by `ts_now`, then
+    /// any modifies queued for them while in flight.
+    fn process_inflight_orders(&mut self, ts_now: UnixNanos) {
+        let (arrived, inflight): (Vec<_>, Vec<_>) = std::mem::take(&mut self.inflight_orders)
+            .into_iter()
+            .partition(|(ts_arrival, _, _)| *ts_arrival <= ts_now);
+        self.inflight_orders = inflight;
+        for (_, mut order, account_id) in arrived {
+            self.process_order_arrival(&mut order, account_id);
+            if let Some(modifies) = self.pending_modifies.remove(&order.client_order_id()) {
+                for (command, account_id) in modifies {
+                    self.process_modify(&command, account_id);
+                }
+            }
+        }
+    }
+
+    fn is_order_inflight(&self, client_order_id: ClientOrderId) -> bool {
+        self.inflight_orders
+            .iter()
+            .any(|(_, order, _)| order.client_order_id() == client_order_id)
+    }
+
+    fn process_order_arrival(&mut self, order: &mut OrderAny, account_id: AccountId) {
+        let _event_batch = self.begin_event_batch();
+        self.submitted_count += 1;
         // Enter the scope where you will borrow a cache
         {
-            let cache_borrow = self.cache.as_ref().borrow();
+            let cache = self.cache.clone();
+            let cache_borrow = cache.as_ref().borrow();
 
             if self.core.order_exists(order.client_order_id()) {
                 self.generate_order_rejected(order, "Order already exists".into());
@@ -493,6 +1443,77 @@ impl OrderMatchingEngine {
 
             // Index identifiers
             self.account_ids.insert(order.trader_id(), account_id);
+            self.order_account_ids
+                .insert(order.client_order_id(), account_id);
+
+            // Acknowledge the order before any acceptance or rejection
+            if self.config.emit_submitted && order.status() == OrderStatus::Initialized {
+                self.generate_order_submitted(order, account_id);
+            }
+
+            // Check GTD orders carry an expire time, otherwise they would rest indefinitely
+            if order.time_in_force() == TimeInForce::Gtd && order.expire_time().is_none() {
+                self.generate_order_rejected(order, "GTD order requires expire time".into());
+                return;
+            }
+
+            // Check the order belongs to the expected trader
+            if self
+                .config
+                .expected_trader_id
+                .is_some_and(|trader_id| trader_id != order.trader_id())
+            {
+                self.generate_order_rejected(
+                    order,
+                    format!(
+                        "Order trader ID {} does not match expected trader ID {}",
+                        order.trader_id(),
+                        self.config.expected_trader_id.unwrap()
+                    )
+                    .into(),
+                );
+                return;
+            }
+
+            // Check venue limit on resting orders per account
+            if let Some(max_open_orders) = self.config.max_open_orders_per_account {
+                let open_orders = self
+                    .core
+                    .get_orders()
+                    .iter()
+                    .map(PassiveOrderAny::to_any)
+                    .filter(|o| self.order_account_id(o) == Some(account_id))
+                    .count();
+                if open_orders >= max_open_orders {
+                    self.generate_order_rejected(
+                        order,
+                        format!(
+                            "Max open orders exceeded: {open_orders} open orders for account {account_id}, max {max_open_orders}"
+                        )
+                        .into(),
+                    );
+                    return;
+                }
+            }
+
+            // Check the account holds the instrument settlement currency
+            if self.config.reject_settlement_currency_mismatch {
+                let settlement_currency = self.instrument.settlement_currency();
+                let is_held = cache_borrow
+                    .account(&account_id)
+                    .is_none_or(|account| account.balances().contains_key(&settlement_currency));
+                if !is_held {
+                    self.generate_order_rejected(
+                        order,
+                        format!(
+                            "Account {account_id} holds no {settlement_currency} balance for {} settlement",
+                            self.instrument.id()
+                        )
+                        .into(),
+                    );
+                    return;
+                }
+            }
 
             // Check for instrument expiration or activation
             if EXPIRING_INSTRUMENT_TYPES.contains(&self.instrument.instrument_class()) {
@@ -526,6 +1547,25 @@ impl OrderMatchingEngine {
                 }
             }
 
+            // Check for a temporarily inactive market (distinct from not yet active)
+            if self.config.reject_orders_when_inactive
+                && matches!(
+                    self.market_status,
+                    MarketStatus::Paused | MarketStatus::Suspended | MarketStatus::NotAvailable
+                )
+            {
+                self.generate_order_rejected(
+                    order,
+                    format!(
+                        "Instrument {} is temporarily inactive, market status {}",
+                        self.instrument.id(),
+                        self.market_status
+                    )
+                    .into(),
+                );
+                return;
+            }
+
             // Contingent orders checks
             if self.config.support_contingent_orders {
                 if let Some(parent_order_id) = order.parent_order_id() {
@@ -581,36 +1621,39 @@ impl OrderMatchingEngine {
                 }
             }
 
+            // Round prices to the tick size rather than rejecting an invalid precision
+            if self.config.round_prices_to_tick {
+                let price = order.price().map(|price| self.round_to_tick(price));
+                let trigger_price = order.trigger_price().map(|price| self.round_to_tick(price));
+                if price != order.price() || trigger_price != order.trigger_price() {
+                    self.generate_order_updated(order, order.quantity(), price, trigger_price);
+                }
+            }
+
             // Check fo valid order quantity precision
             if order.quantity().precision != self.instrument.size_precision() {
-                self.generate_order_rejected(
-                    order,
-                    format!(
-                        "Invalid order quantity precision for order {}, was {} when {} size precision is {}",
-                        order.client_order_id(),
-                        order.quantity().precision,
-                        self.instrument.id(),
-                        self.instrument.size_precision()
-                    )
-                        .into(),
+                let reason = self.invalid_precision_reason(
+                    order.client_order_id(),
+                    "quantity",
+                    order.quantity().precision,
+                    "size",
+                    self.instrument.size_precision(),
                 );
+                self.generate_order_rejected(order, reason);
                 return;
             }
 
             // Check for valid order price precision
             if let Some(price) = order.price() {
                 if price.precision != self.instrument.price_precision() {
-                    self.generate_order_rejected(
-                        order,
-                        format!(
-                            "Invalid order price precision for order {}, was {} when {} price precision is {}",
-                            order.client_order_id(),
-                            price.precision,
-                            self.instrument.id(),
-                            self.instrument.price_precision()
-                        )
-                            .into(),
+                    let reason = self.invalid_precision_reason(
+                        order.client_order_id(),
+                        "price",
+                        price.precision,
+                        "price",
+                        self.instrument.price_precision(),
                     );
+                    self.generate_order_rejected(order, reason);
                     return;
                 }
             }
@@ -618,21 +1661,39 @@ impl OrderMatchingEngine {
             // Check for valid order trigger price precision
             if let Some(trigger_price) = order.trigger_price() {
                 if trigger_price.precision != self.instrument.price_precision() {
-                    self.generate_order_rejected(
-                        order,
-                        format!(
-                            "Invalid order trigger price precision for order {}, was {} when {} price precision is {}",
-                            order.client_order_id(),
-                            trigger_price.precision,
-                            self.instrument.id(),
-                            self.instrument.price_precision()
-                        )
-                            .into(),
+                    let reason = self.invalid_precision_reason(
+                        order.client_order_id(),
+                        "trigger price",
+                        trigger_price.precision,
+                        "price",
+                        self.instrument.price_precision(),
                     );
+                    self.generate_order_rejected(order, reason);
                     return;
                 }
             }
 
+            // Check prices are on a tick for their price band
+            let off_tick_price = self.tick_scheme.as_ref().and_then(|tick_scheme| {
+                [order.price(), order.trigger_price()]
+                    .into_iter()
+                    .flatten()
+                    .find(|price| !tick_scheme.is_valid_price(*price))
+                    .map(|price| (price, tick_scheme.tick_size(price)))
+            });
+            if let Some((price, tick_size)) = off_tick_price {
+                self.generate_order_rejected(
+                    order,
+                    format!(
+                        "Invalid order price {price} for order {}, not a multiple of tick size {tick_size} for {}",
+                        order.client_order_id(),
+                        self.instrument.id()
+                    )
+                    .into(),
+                );
+                return;
+            }
+
             // Get position if exists
             let position: Option<&Position> = cache_borrow
                 .position_for_order(&order.client_order_id())
@@ -665,6 +1726,41 @@ impl OrderMatchingEngine {
                 return;
             }
 
+            // Check only reducing orders are accepted within the expiry grace period
+            if self.is_within_expiry_grace_period()
+                && !order.is_reduce_only()
+                && position.is_none_or(|pos| !order.would_reduce_only(pos.side, pos.quantity))
+            {
+                self.generate_order_rejected(
+                    order,
+                    format!(
+                        "Contract {} is within the expiry grace period, only reducing orders accepted: expiration {}",
+                        self.instrument.id(),
+                        self.instrument.expiration_ns().unwrap()
+                    )
+                    .into(),
+                );
+                return;
+            }
+
+            // Check reduce-only instruction on a flat position, which has nothing to reduce
+            if self.config.use_reduce_only
+                && self.config.cancel_reduce_only_when_flat
+                && order.is_reduce_only()
+                && !order.is_closed()
+                && position.is_none_or(Position::is_closed)
+            {
+                log::warn!(
+                    "Canceling reduce-only order {} as there is no open position to reduce",
+                    order.client_order_id()
+                );
+                drop(cache_borrow); // Venue order IDs are registered in the cache
+                let venue_order_id = self.ids_generator.get_venue_order_id(order).unwrap();
+                self.generate_order_accepted(order, venue_order_id);
+                self.generate_order_canceled(order, venue_order_id, None);
+                return;
+            }
+
             // Check reduce-only instruction
             if self.config.use_reduce_only
                 && order.is_reduce_only()
@@ -689,6 +1785,81 @@ impl OrderMatchingEngine {
             }
         }
 
+        // Check for an expire time which has already passed
+        let ts_now = self.clock.get_time_ns();
+        if let Some(expire_time) = order.expire_time().filter(|time| *time <= ts_now) {
+            match self.config.past_expire_time_handling {
+                PastExpireTimeHandling::Accept => {}
+                PastExpireTimeHandling::Reject => {
+                    self.generate_order_rejected(
+                        order,
+                        format!(
+                            "GTD order expire time {expire_time} was in the past: now {ts_now}"
+                        )
+                        .into(),
+                    );
+                    return;
+                }
+                PastExpireTimeHandling::Expire => {
+                    let venue_order_id = self.ids_generator.get_venue_order_id(order).unwrap();
+                    self.generate_order_accepted(order, venue_order_id);
+                    self.generate_order_expired(order);
+                    return;
+                }
+            }
+        }
+
+        self.route_order(order);
+    }
+
+    /// Process the given `order` for the `account_id`, skipping all pre-trade validation
+    /// and going straight to matching.
+    ///
+    /// Intended for hot loops where orders have already been validated upstream. The caller
+    /// is trusted to only submit orders which would pass the checks in [`Self::process_order`]
+    /// (unique client order ID, instrument, precisions, tick scheme, account and reduce-only
+    /// rules); an invalid order is matched as is.
+    pub fn process_order_unchecked(&mut self, order: &mut OrderAny, account_id: AccountId) {
+        let _event_batch = self.begin_event_batch();
+        self.submitted_count += 1;
+        self.account_ids.insert(order.trader_id(), account_id);
+        self.order_account_ids
+            .insert(order.client_order_id(), account_id);
+        self.route_order(order);
+    }
+
+    fn route_order(&mut self, order: &mut OrderAny) {
+        if let Some(is_triggered) = self.is_trigger_instrument_triggered(order) {
+            // Rests until triggered by the trigger instrument's prices rather than this book
+            self.accept_order(order);
+            if is_triggered {
+                self.trigger_stop_order(order);
+            }
+            return;
+        }
+
+        if self.config.reject_takers && self.is_taker_order(order) {
+            self.generate_order_rejected(order, "Taker orders not permitted".into());
+            return;
+        }
+
+        if self.config.paper_fill_at_mid {
+            self.fill_order_at_mid(order);
+            return;
+        }
+
+        let self_trade_prevention = self.config.self_trade_prevention;
+        if self_trade_prevention.is_some_and(|mode| !self.prevent_self_trade(order, mode)) {
+            return;
+        }
+
+        if self.is_auction_collecting
+            && matches!(order.order_type(), OrderType::Market | OrderType::Limit)
+        {
+            self.process_auction_order(order);
+            return;
+        }
+
         match order.order_type() {
             OrderType::Market => self.process_market_order(order),
             OrderType::Limit => self.process_limit_order(order),
@@ -703,76 +1874,401 @@ impl OrderMatchingEngine {
     }
 
     pub fn process_modify(&mut self, command: &ModifyOrder, account_id: AccountId) {
+        let _event_batch = self.begin_event_batch();
+        if command.instrument_id != self.instrument.id() {
+            self.generate_order_modify_rejected(
+                command.trader_id,
+                command.strategy_id,
+                command.instrument_id,
+                command.client_order_id,
+                Ustr::from(
+                    format!(
+                        "Instrument ID mismatch: modify {} for order {}, engine {}",
+                        command.instrument_id,
+                        command.client_order_id,
+                        self.instrument.id()
+                    )
+                    .as_str(),
+                ),
+                Some(command.venue_order_id),
+                Some(account_id),
+                ModifyRejectReason::InstrumentMismatch,
+            );
+            return;
+        }
+
         if let Some(order) = self.core.get_order(command.client_order_id) {
+            let filled_qty = self
+                .cached_filled_qty
+                .get(&command.client_order_id)
+                .copied();
+            if let Some((quantity, filled_qty)) = command
+                .quantity
+                .zip(filled_qty)
+                .filter(|(quantity, filled_qty)| quantity < filled_qty)
+            {
+                self.generate_order_modify_rejected(
+                    command.trader_id,
+                    command.strategy_id,
+                    command.instrument_id,
+                    command.client_order_id,
+                    Ustr::from(
+                        format!(
+                            "Order {} new quantity {} was below filled quantity {}",
+                            command.client_order_id, quantity, filled_qty
+                        )
+                        .as_str(),
+                    ),
+                    Some(command.venue_order_id),
+                    Some(account_id),
+                    ModifyRejectReason::QuantityBelowFilled,
+                );
+                return;
+            }
+
+            if let Some(max_modifies) = self.config.max_modifies_per_order {
+                let modify_count = self
+                    .modify_counts
+                    .get(&command.client_order_id)
+                    .copied()
+                    .unwrap_or(0);
+                if modify_count >= max_modifies {
+                    self.generate_order_modify_rejected(
+                        command.trader_id,
+                        command.strategy_id,
+                        command.instrument_id,
+                        command.client_order_id,
+                        Ustr::from(
+                            format!(
+                                "Modify rate exceeded: order {} reached max modifies per order of {max_modifies}",
+                                command.client_order_id
+                            )
+                            .as_str(),
+                        ),
+                        Some(command.venue_order_id),
+                        Some(account_id),
+                        ModifyRejectReason::RateExceeded,
+                    );
+                    return;
+                }
+                self.modify_counts
+                    .insert(command.client_order_id, modify_count + 1);
+            }
+
+            let mut order = order.to_any();
             self.update_order(
-                &mut order.to_any(),
+                &mut order,
                 command.quantity,
                 command.price,
                 command.trigger_price,
                 None,
             );
+
+            // Keep the resting order in sync so matching reflects the modification immediately
+            if self.core.order_exists(order.client_order_id()) {
+                let _ = self.core.update_order(PassiveOrderAny::from(order));
+            }
+        } else if self.config.queue_modifies_until_accepted
+            && self.is_order_inflight(command.client_order_id)
+        {
+            // Apply the modify once the order arrives, rather than rejecting it as not found
+            self.pending_modifies
+                .entry(command.client_order_id)
+                .or_default()
+                .push((command.clone(), account_id));
         } else {
+            let is_closed = self
+                .cache
+                .borrow()
+                .order(&command.client_order_id)
+                .is_some_and(OrderAny::is_closed);
+            let (reason, reason_code) = if is_closed {
+                (
+                    format!("Order {} already closed", command.client_order_id),
+                    ModifyRejectReason::OrderClosed,
+                )
+            } else {
+                (
+                    format!("Order {} not found", command.client_order_id),
+                    ModifyRejectReason::OrderNotFound,
+                )
+            };
             self.generate_order_modify_rejected(
                 command.trader_id,
                 command.strategy_id,
                 command.instrument_id,
                 command.client_order_id,
-                Ustr::from(format!("Order {} not found", command.client_order_id).as_str()),
+                Ustr::from(reason.as_str()),
                 Some(command.venue_order_id),
                 Some(account_id),
+                reason_code,
             );
         }
     }
 
     pub fn process_cancel(&mut self, command: &CancelOrder, account_id: AccountId) {
-        match self.core.get_order(command.client_order_id) {
-            Some(passive_order) => {
-                if passive_order.is_inflight() || passive_order.is_open() {
-                    self.cancel_order(&OrderAny::from(passive_order.to_owned()), None);
-                }
-            }
-            None => self.generate_order_cancel_rejected(
+        let _event_batch = self.begin_event_batch();
+        if let Some(reason) = self.cancel_reject_reason(command) {
+            self.generate_order_cancel_rejected(
                 command.trader_id,
                 command.strategy_id,
                 account_id,
                 command.instrument_id,
                 command.client_order_id,
                 command.venue_order_id,
-                Ustr::from(format!("Order {} not found", command.client_order_id).as_str()),
-            ),
+                reason,
+            );
+            return;
+        }
+
+        // SAFETY: Order is known to exist in the core
+        let passive_order = self.core.get_order(command.client_order_id).unwrap();
+        if passive_order.is_inflight() || passive_order.is_open() {
+            self.cancel_order(&OrderAny::from(passive_order.to_owned()), None);
         }
     }
 
+    /// Returns the reason the cancel `command` would be rejected, if any.
+    fn cancel_reject_reason(&self, command: &CancelOrder) -> Option<Ustr> {
+        if let Some(min_resting_time) = self.config.min_resting_time_nanos {
+            let ts_now = self.clock.get_time_ns();
+            let rested = self
+                .accepted_ts
+                .get(&command.client_order_id)
+                .map(|ts_accepted| ts_now.as_u64().saturating_sub(ts_accepted.as_u64()));
+            if let Some(rested) = rested.filter(|rested| *rested < min_resting_time) {
+                return Some(Ustr::from(
+                    format!(
+                        "Minimum resting time not met: order {} rested {rested}ns of {min_resting_time}ns",
+                        command.client_order_id,
+                    )
+                    .as_str(),
+                ));
+            }
+        }
+
+        if !self.core.order_exists(command.client_order_id) {
+            let is_closed = self
+                .cache
+                .borrow()
+                .order(&command.client_order_id)
+                .is_some_and(OrderAny::is_closed);
+            let reason = if is_closed {
+                format!("Order {} already closed", command.client_order_id)
+            } else {
+                format!("Order {} not found", command.client_order_id)
+            };
+            return Some(Ustr::from(reason.as_str()));
+        }
+
+        None
+    }
+
     pub fn process_cancel_all(&mut self, command: &CancelAllOrders, account_id: AccountId) {
-        let open_orders = self
+        let _event_batch = self.begin_event_batch();
+        let mut open_orders = self
             .cache
             .borrow()
             .orders_open(None, Some(&command.instrument_id), None, None)
             .into_iter()
             .cloned()
             .collect::<Vec<OrderAny>>();
+        self.sort_cancel_all_orders(&mut open_orders);
         for order in open_orders {
             if command.order_side != OrderSide::NoOrderSide
                 && command.order_side != order.order_side()
             {
                 continue;
             }
+            if command
+                .order_type
+                .is_some_and(|order_type| order_type != order.order_type())
+            {
+                continue;
+            }
+            // Orders of other accounts hosted by this engine are not in scope
+            if self
+                .order_account_id(&order)
+                .is_some_and(|order_account_id| order_account_id != account_id)
+            {
+                continue;
+            }
             if order.is_inflight() || order.is_open() {
                 self.cancel_order(&order, None);
             }
         }
     }
 
+    /// Sorts the open `orders` of a cancel-all command per the configured ordering.
+    fn sort_cancel_all_orders(&self, orders: &mut [OrderAny]) {
+        match self.config.cancel_all_ordering {
+            CancelAllOrdering::Unordered => {}
+            CancelAllOrdering::ClientOrderId => {
+                orders.sort_by(|a, b| {
+                    a.client_order_id()
+                        .as_str()
+                        .cmp(b.client_order_id().as_str())
+                });
+            }
+            CancelAllOrdering::PriceTime => {
+                // Buy orders first, each side best price first (unpriced orders last),
+                // then earliest first
+                let priority = |order: &OrderAny| {
+                    let px = order.price().or_else(|| order.trigger_price());
+                    (order.order_side() != OrderSide::Buy, px.is_none(), px)
+                };
+                orders.sort_by(|a, b| {
+                    let (a_is_sell, a_unpriced, a_px) = priority(a);
+                    let (b_is_sell, b_unpriced, b_px) = priority(b);
+                    // Best buy price is the highest, best sell price the lowest
+                    let px_ordering = if a_is_sell {
+                        a_px.cmp(&b_px)
+                    } else {
+                        b_px.cmp(&a_px)
+                    };
+                    a_is_sell
+                        .cmp(&b_is_sell)
+                        .then(a_unpriced.cmp(&b_unpriced))
+                        .then(px_ordering)
+                        .then(a.ts_init().cmp(&b.ts_init()))
+                        .then(
+                            a.client_order_id()
+                                .as_str()
+                                .cmp(b.client_order_id().as_str()),
+                        )
+                });
+            }
+        }
+    }
+
     pub fn process_batch_cancel(&mut self, command: &BatchCancelOrders, account_id: AccountId) {
-        for order in &command.cancels {
+        let _event_batch = self.begin_event_batch();
+        if !self.config.group_batch_cancel_events {
+            for order in &command.cancels {
+                self.process_cancel(order, account_id);
+            }
+            return;
+        }
+
+        // Acknowledge all successful cancels first, then all rejected cancels
+        let (cancels, rejects): (Vec<&CancelOrder>, Vec<&CancelOrder>) = command
+            .cancels
+            .iter()
+            .partition(|cancel| self.cancel_reject_reason(cancel).is_none());
+        for order in cancels.into_iter().chain(rejects) {
             self.process_cancel(order, account_id);
         }
     }
 
+    /// Cancels all open orders of the order list with the given `order_list_id` for the
+    /// account, managing the list members as a group.
+    pub fn process_cancel_order_list(&mut self, order_list_id: OrderListId, account_id: AccountId) {
+        let _event_batch = self.begin_event_batch();
+        for order in self.open_order_list_members(order_list_id) {
+            // Orders of other accounts hosted by this engine are not in scope
+            if self
+                .order_account_id(&order)
+                .is_some_and(|order_account_id| order_account_id != account_id)
+            {
+                continue;
+            }
+            self.cancel_order(&order, None);
+        }
+    }
+
+    /// Returns the open orders in the matching core belonging to the given `order_list_id`.
+    fn open_order_list_members(&self, order_list_id: OrderListId) -> Vec<OrderAny> {
+        self.core
+            .get_orders()
+            .iter()
+            .map(PassiveOrderAny::to_any)
+            .filter(|order| order.order_list_id() == Some(order_list_id))
+            .filter(|order| order.is_inflight() || order.is_open())
+            .collect()
+    }
+
     pub fn process_query_order(&self, command: &QueryOrder, account_id: AccountId) {
         todo!("implement process_query_order")
     }
 
+    /// Applies the self-trade prevention `mode` to the incoming `order` against resting
+    /// orders from the same strategy which it would match.
+    ///
+    /// Returns whether the incoming order should continue to be processed.
+    fn prevent_self_trade(&mut self, order: &OrderAny, mode: SelfTradePrevention) -> bool {
+        if !matches!(
+            order.order_type(),
+            OrderType::Market | OrderType::Limit | OrderType::MarketToLimit
+        ) {
+            return true;
+        }
+
+        let resting_orders = match order.order_side_specified() {
+            OrderSideSpecified::Buy => self.core.get_orders_ask(),
+            OrderSideSpecified::Sell => self.core.get_orders_bid(),
+        };
+        let self_matches: Vec<OrderAny> = resting_orders
+            .iter()
+            .filter_map(|resting| match resting {
+                PassiveOrderAny::Limit(limit) => Some(OrderAny::from(limit.clone())),
+                PassiveOrderAny::Stop(_) => None,
+            })
+            .filter(|resting| {
+                resting.strategy_id() == order.strategy_id()
+                    && resting
+                        .price()
+                        .zip(order.price())
+                        .is_none_or(|(resting_px, price)| match order.order_side_specified() {
+                            OrderSideSpecified::Buy => price >= resting_px,
+                            OrderSideSpecified::Sell => price <= resting_px,
+                        })
+            })
+            .collect();
+
+        if self_matches.is_empty() {
+            return true;
+        }
+
+        if matches!(
+            mode,
+            SelfTradePrevention::CancelOldest | SelfTradePrevention::CancelBoth
+        ) {
+            for resting in &self_matches {
+                log::info!(
+                    "Canceling resting {} as self-trade prevented with {}",
+                    resting.client_order_id(),
+                    order.client_order_id()
+                );
+                self.cancel_order(resting, None);
+            }
+        }
+
+        if matches!(
+            mode,
+            SelfTradePrevention::CancelNewest | SelfTradePrevention::CancelBoth
+        ) {
+            self.generate_order_rejected(
+                order,
+                format!(
+                    "Self-trade prevented: order {} would match resting order {} ({mode})",
+                    order.client_order_id(),
+                    self_matches[0].client_order_id()
+                )
+                .into(),
+            );
+            return false;
+        }
+
+        true
+    }
+
+    fn process_auction_order(&mut self, order: &mut OrderAny) {
+        // Order is held for the uncross rather than matched continuously
+        let venue_order_id = self.ids_generator.get_venue_order_id(order).unwrap();
+        self.generate_order_accepted(order, venue_order_id);
+        self.auction_orders.push(order.clone());
+    }
+
     fn process_market_order(&mut self, order: &mut OrderAny) {
         if order.time_in_force() == TimeInForce::AtTheOpen
             || order.time_in_force() == TimeInForce::AtTheClose
@@ -785,24 +2281,95 @@ impl OrderMatchingEngine {
         }
 
         // Check if market exists
-        let order_side = order.order_side();
-        let is_ask_initialized = self.core.is_ask_initialized;
-        let is_bid_initialized = self.core.is_bid_initialized;
-        if (order.order_side() == OrderSide::Buy && !self.core.is_ask_initialized)
-            || (order.order_side() == OrderSide::Sell && !self.core.is_bid_initialized)
-        {
+        if !self.is_market_initialized(order.order_side()) {
+            if self.config.queue_market_orders_until_data {
+                // Hold the order until data initializes the opposite side of the book
+                let venue_order_id = self.ids_generator.get_venue_order_id(order).unwrap();
+                self.generate_order_accepted(order, venue_order_id);
+                self.pending_market_orders.push(order.clone());
+            } else if let Some(reference_price) = self.config.no_market_reference_price {
+                // Fill at the configured reference price in the absence of a market
+                let reference_px =
+                    Price::new(reference_price.as_f64(), self.instrument.price_precision());
+                self.fill_order_at_price(order, reference_px);
+            } else {
+                self.generate_order_rejected(
+                    order,
+                    format!("No market for {}", order.instrument_id()).into(),
+                );
+            }
+            return;
+        }
+
+        self.fill_market_order(order);
+    }
+
+    /// Fills the entire `order` immediately at the current midpoint, regardless of book depth.
+    fn fill_order_at_mid(&mut self, order: &mut OrderAny) {
+        let Some(mid) = self.book.midpoint() else {
             self.generate_order_rejected(
                 order,
                 format!("No market for {}", order.instrument_id()).into(),
             );
             return;
+        };
+        let mid_px = Price::new(mid, self.instrument.price_precision());
+        self.fill_order_at_price(order, mid_px);
+    }
+
+    /// Fills the entire `order` immediately at `fill_px`, regardless of book depth.
+    fn fill_order_at_price(&mut self, order: &mut OrderAny, fill_px: Price) {
+        // Market orders fill without being accepted, as on the regular path
+        if order.order_type() != OrderType::Market {
+            let venue_order_id = self.ids_generator.get_venue_order_id(order).unwrap();
+            self.generate_order_accepted(order, venue_order_id);
         }
 
-        self.fill_market_order(order);
+        let venue_position_id = self.ids_generator.get_position_id(order, Some(true));
+        let position: Option<Position> = venue_position_id.and_then(|venue_position_id| {
+            self.cache
+                .as_ref()
+                .borrow()
+                .position(&venue_position_id)
+                .cloned()
+        });
+        order.set_liquidity_side(LiquiditySide::Taker);
+        self.fill_order(
+            order,
+            fill_px,
+            order.leaves_qty(),
+            LiquiditySide::Taker,
+            venue_position_id,
+            position,
+        );
+    }
+
+    fn is_market_initialized(&self, order_side: OrderSide) -> bool {
+        match order_side {
+            OrderSide::Buy => self.core.is_ask_initialized,
+            _ => self.core.is_bid_initialized,
+        }
+    }
+
+    fn fill_pending_market_orders(&mut self) {
+        let pending = std::mem::take(&mut self.pending_market_orders);
+        for mut order in pending {
+            if self.is_market_initialized(order.order_side()) {
+                self.fill_market_order(&mut order);
+            } else {
+                self.pending_market_orders.push(order);
+            }
+        }
     }
 
     fn process_limit_order(&mut self, order: &mut OrderAny) {
         let limit_px = order.price().expect("Limit order must have a price");
+        if self.config.resolve_limit_quote_quantity
+            && order.is_quote_quantity()
+            && !self.instrument.is_inverse()
+        {
+            self.resolve_quote_quantity(order, limit_px);
+        }
         if order.is_post_only()
             && self
                 .core
@@ -827,6 +2394,53 @@ impl OrderMatchingEngine {
             return;
         }
 
+        if order.is_post_only() && self.config.post_only_requires_quote {
+            let opposing_quote = match order.order_side_specified() {
+                OrderSideSpecified::Buy => self.core.ask,
+                OrderSideSpecified::Sell => self.core.bid,
+            };
+            if opposing_quote.is_none() {
+                self.generate_order_rejected(
+                    order,
+                    format!(
+                        "POST_ONLY {} {} order limit px of {} has no opposing quote: bid={}, ask={}",
+                        order.order_type(),
+                        order.order_side(),
+                        limit_px,
+                        self.core
+                            .bid
+                            .map_or_else(|| "None".to_string(), |p| p.to_string()),
+                        self.core
+                            .ask
+                            .map_or_else(|| "None".to_string(), |p| p.to_string())
+                    )
+                    .into(),
+                );
+                return;
+            }
+        }
+
+        let is_locking = self.is_locking_market(order.order_side_specified(), limit_px);
+        if is_locking && self.config.lock_behavior == LockBehavior::Reject {
+            self.generate_order_rejected(
+                order,
+                format!(
+                    "{} {} order limit px of {} would have locked the market: bid={}, ask={}",
+                    order.order_type(),
+                    order.order_side(),
+                    limit_px,
+                    self.core
+                        .bid
+                        .map_or_else(|| "None".to_string(), |p| p.to_string()),
+                    self.core
+                        .ask
+                        .map_or_else(|| "None".to_string(), |p| p.to_string())
+                )
+                .into(),
+            );
+            return;
+        }
+
         // Order is valid and accepted
         self.accept_order(order);
 
@@ -834,6 +2448,7 @@ impl OrderMatchingEngine {
         if self
             .core
             .is_limit_matched(order.order_side_specified(), limit_px)
+            && !(is_locking && self.config.lock_behavior == LockBehavior::Rest)
         {
             // Filling as liquidity taker
             if order.liquidity_side().is_some()
@@ -847,18 +2462,77 @@ impl OrderMatchingEngine {
         }
     }
 
-    fn process_market_to_limit_order(&mut self, order: &OrderAny) {
-        todo!("process_market_to_limit_order")
+    /// Returns the `price` rounded to the nearest multiple of the instrument price increment,
+    /// then to the nearest tick for its price band when a tick scheme is set.
+    fn round_to_tick(&self, price: Price) -> Price {
+        let increment = self.instrument.price_increment().as_f64();
+        let price = self
+            .instrument
+            .make_price((price.as_f64() / increment).round() * increment);
+        match &self.tick_scheme {
+            Some(tick_scheme) => tick_scheme.round_price(price),
+            None => price,
+        }
+    }
+
+    /// Returns whether the `order` would take liquidity on submission.
+    fn is_taker_order(&self, order: &OrderAny) -> bool {
+        match order.order_type() {
+            OrderType::Market | OrderType::MarketToLimit => true,
+            OrderType::Limit => order.price().is_some_and(|limit_px| {
+                let side = order.order_side_specified();
+                self.core.is_limit_matched(side, limit_px)
+                    && !(self.config.lock_behavior == LockBehavior::Rest
+                        && self.is_locking_market(side, limit_px))
+            }),
+            _ => false,
+        }
+    }
+
+    /// Resolves the quote quantity of the `order` to a base quantity at the given `price`.
+    fn resolve_quote_quantity(&mut self, order: &mut OrderAny, price: Price) {
+        let base_qty = self.instrument.get_base_quantity(order.quantity(), price);
+        log::info!(
+            "Setting {} order quote quantity {} to base quantity {base_qty}",
+            order.instrument_id(),
+            order.quantity(),
+        );
+        self.generate_order_updated(order, base_qty, None, None);
+        order.set_is_quote_quantity(false);
+    }
+
+    /// Returns whether a limit order at `price` would be exactly at the opposing best price.
+    fn is_locking_market(&self, side: OrderSideSpecified, price: Price) -> bool {
+        match side {
+            OrderSideSpecified::Buy => self.core.ask == Some(price),
+            OrderSideSpecified::Sell => self.core.bid == Some(price),
+        }
+    }
+
+    fn process_market_to_limit_order(&mut self, order: &mut OrderAny) {
+        // Check that market exists
+        if (order.order_side() == OrderSide::Buy && !self.core.is_ask_initialized)
+            || (order.order_side() == OrderSide::Sell && !self.core.is_bid_initialized)
+        {
+            self.generate_order_rejected(
+                order,
+                format!("No market for {}", order.instrument_id()).into(),
+            );
+            return;
+        }
+
+        // Accept the order ahead of its fills, then immediately fill marketable order
+        // (the remainder will rest as a limit order)
+        let venue_order_id = self.ids_generator.get_venue_order_id(order).unwrap();
+        self.generate_order_accepted(order, venue_order_id);
+        self.fill_market_order(order);
     }
 
     fn process_stop_market_order(&mut self, order: &mut OrderAny) {
         let stop_px = order
             .trigger_price()
             .expect("Stop order must have a trigger price");
-        if self
-            .core
-            .is_stop_matched(order.order_side_specified(), stop_px)
-        {
+        if self.is_stop_triggered(order.order_side_specified(), stop_px) {
             if self.config.reject_stop_orders {
                 self.generate_order_rejected(
                     order,
@@ -889,10 +2563,7 @@ impl OrderMatchingEngine {
         let stop_px = order
             .trigger_price()
             .expect("Stop order must have a trigger price");
-        if self
-            .core
-            .is_stop_matched(order.order_side_specified(), stop_px)
-        {
+        if self.is_stop_triggered(order.order_side_specified(), stop_px) {
             if self.config.reject_stop_orders {
                 self.generate_order_rejected(
                     order,
@@ -914,6 +2585,8 @@ impl OrderMatchingEngine {
 
             self.accept_order(order);
             self.generate_order_triggered(order);
+            self.refresh_core_order(order);
+            self.reprice_triggered_stop_limit(order);
 
             // Check for immediate fill
             let limit_px = order.price().expect("Stop limit order must have a price");
@@ -924,6 +2597,7 @@ impl OrderMatchingEngine {
                 order.set_liquidity_side(LiquiditySide::Taker);
                 self.fill_limit_order(order);
             }
+            return;
         }
 
         // order is not matched but is valid and we accept it
@@ -931,10 +2605,7 @@ impl OrderMatchingEngine {
     }
 
     fn process_market_if_touched_order(&mut self, order: &mut OrderAny) {
-        if self
-            .core
-            .is_touch_triggered(order.order_side_specified(), order.trigger_price().unwrap())
-        {
+        if self.is_touch_triggered(order.order_side_specified(), order.trigger_price().unwrap()) {
             if self.config.reject_stop_orders {
                 self.generate_order_rejected(
                     order,
@@ -962,10 +2633,7 @@ impl OrderMatchingEngine {
     }
 
     fn process_limit_if_touched_order(&mut self, order: &mut OrderAny) {
-        if self
-            .core
-            .is_touch_triggered(order.order_side_specified(), order.trigger_price().unwrap())
-        {
+        if self.is_touch_triggered(order.order_side_specified(), order.trigger_price().unwrap()) {
             if self.config.reject_stop_orders {
                 self.generate_order_rejected(
                     order,
@@ -986,6 +2654,7 @@ impl OrderMatchingEngine {
             }
             self.accept_order(order);
             self.generate_order_triggered(order);
+            self.refresh_core_order(order);
 
             // Check if immediate marketable
             if self
@@ -1015,7 +2684,13 @@ impl OrderMatchingEngine {
     /// Iterate the matching engine by processing the bid and ask order sides
     /// and advancing time up to the given UNIX `timestamp_ns`.
     pub fn iterate(&mut self, timestamp_ns: UnixNanos) {
+        let _event_batch = self.begin_event_batch();
         self.clock.set_time(timestamp_ns);
+        self.last_market_data_ts = timestamp_ns;
+
+        if !self.inflight_orders.is_empty() {
+            self.process_inflight_orders(timestamp_ns);
+        }
 
         // Check for updates in orderbook and set bid and ask in order matching core and iterate
         if self.book.has_bid() {
@@ -1029,11 +2704,20 @@ impl OrderMatchingEngine {
         self.core.bid = self.book.best_bid_price();
         self.core.ask = self.book.best_ask_price();
 
+        if !self.pending_market_orders.is_empty() {
+            self.fill_pending_market_orders();
+        }
+
+        self.update_queue_ahead(timestamp_ns);
+
         let orders_bid = self.core.get_orders_bid().to_vec();
         let orders_ask = self.core.get_orders_ask().to_vec();
 
         self.iterate_orders(timestamp_ns, &orders_bid);
         self.iterate_orders(timestamp_ns, &orders_ask);
+
+        self.update_drawdown();
+        self.check_ready();
     }
 
     fn iterate_orders(&mut self, timestamp_ns: UnixNanos, orders: &[PassiveOrderAny]) {
@@ -1050,6 +2734,7 @@ impl OrderMatchingEngine {
                         self.core.delete_order(order).unwrap();
                         self.cached_filled_qty.remove(&order.client_order_id());
                         self.expire_order(order);
+                        continue;
                     }
                 }
             }
@@ -1063,6 +2748,8 @@ impl OrderMatchingEngine {
                 }
             }
 
+            self.match_order(order);
+
             // Move market back to targets
             if let Some(target_bid) = self.target_bid {
                 self.core.bid = Some(target_bid);
@@ -1084,6 +2771,70 @@ impl OrderMatchingEngine {
         self.target_last = None;
     }
 
+    fn match_order(&mut self, order: &PassiveOrderAny) {
+        let mut order_any = order.to_any();
+        match order {
+            PassiveOrderAny::Limit(o) => {
+                if self
+                    .core
+                    .is_limit_matched(o.order_side_specified(), o.limit_px())
+                {
+                    order_any.set_liquidity_side(LiquiditySide::Maker);
+                    self.fill_limit_order(&mut order_any);
+                }
+            }
+            PassiveOrderAny::Stop(o) => {
+                if order_any.status() == OrderStatus::Triggered {
+                    // Triggered limit orders rest at their limit price
+                    if self
+                        .core
+                        .is_limit_matched(o.order_side_specified(), order_any.price().unwrap())
+                    {
+                        order_any.set_liquidity_side(LiquiditySide::Maker);
+                        self.fill_limit_order(&mut order_any);
+                    }
+                    return;
+                }
+
+                if self.is_trigger_instrument_triggered(&order_any).is_some() {
+                    return; // Triggered by its trigger instrument's prices
+                }
+
+                let is_triggered = match o {
+                    StopOrderAny::LimitIfTouched(_) | StopOrderAny::MarketIfTouched(_) => {
+                        self.is_touch_triggered(o.order_side_specified(), o.stop_px())
+                    }
+                    _ => self.is_stop_triggered(o.order_side_specified(), o.stop_px()),
+                };
+                if is_triggered {
+                    self.trigger_stop_order(&mut order_any);
+                }
+            }
+        }
+    }
+
+    fn update_queue_ahead(&mut self, timestamp_ns: UnixNanos) {
+        let Some(queue_model) = &self.queue_model else {
+            return;
+        };
+
+        self.queue_ahead
+            .retain(|client_order_id, _| self.core.order_exists(*client_order_id));
+        for (volume, last_ts) in self.queue_ahead.values_mut() {
+            if let Some(last_ts) = last_ts {
+                let elapsed_ns = timestamp_ns.as_u64().saturating_sub(last_ts.as_u64());
+                *volume = queue_model.decay(*volume, elapsed_ns);
+            }
+            *last_ts = Some(timestamp_ns);
+        }
+    }
+
+    fn is_queue_ahead_depleted(&self, order: &OrderAny) -> bool {
+        self.queue_ahead
+            .get(&order.client_order_id())
+            .is_none_or(|(volume, _)| *volume <= 0.0)
+    }
+
     fn determine_limit_price_and_volume(&mut self, order: &OrderAny) -> Vec<(Price, Quantity)> {
         match order.price() {
             Some(order_price) => {
@@ -1098,8 +2849,11 @@ impl OrderMatchingEngine {
                     return fills;
                 }
 
-                // check if trigger price exists
-                if let Some(triggered_price) = order.trigger_price() {
+                // check if trigger price exists (and is a price of this instrument)
+                let trigger_price = order
+                    .trigger_price()
+                    .filter(|_| self.is_trigger_instrument_triggered(order).is_none());
+                if let Some(triggered_price) = trigger_price {
                     // Filling as TAKER from trigger
                     if order
                         .liquidity_side()
@@ -1182,13 +2936,36 @@ impl OrderMatchingEngine {
                     }
                 }
 
+                // Either a resting order trades at its own price (the aggressor receiving the
+                // improvement), or the venue caps aggressive fills at the limit price
+                let is_price_capped = match order.liquidity_side() {
+                    Some(LiquiditySide::Maker) => self.config.resting_fills_at_own_price,
+                    Some(LiquiditySide::Taker) => self.config.taker_fills_at_limit_price,
+                    _ => false,
+                };
+                if is_price_capped {
+                    for fill in &mut fills {
+                        let is_better_price = match order.order_side_specified() {
+                            OrderSideSpecified::Buy => fill.0 < order_price,
+                            OrderSideSpecified::Sell => fill.0 > order_price,
+                        };
+                        if is_better_price {
+                            fill.0 = order_price;
+                        }
+                    }
+                }
+
                 fills
             }
             None => panic!("Limit order must have a price"),
         }
     }
 
-    fn determine_market_price_and_volume(&self, order: &OrderAny) -> Vec<(Price, Quantity)> {
+    fn determine_market_price_and_volume(
+        &self,
+        order: &OrderAny,
+        fills: &mut Vec<(Price, Quantity)>,
+    ) {
         // construct price
         let price = match order.order_side().as_specified() {
             OrderSideSpecified::Buy => Price::max(FIXED_PRECISION),
@@ -1197,7 +2974,33 @@ impl OrderMatchingEngine {
 
         // Construct BookOrder from order
         let book_order = BookOrder::new(order.order_side(), price, order.quantity(), 0);
-        self.book.simulate_fills(&book_order)
+        self.book.simulate_fills_into(&book_order, fills);
+
+        // A resting stop which the market gapped through fills at its trigger price rather
+        // than the worse available liquidity, when so configured
+        if self.config.stop_fills_at_trigger_price
+            && matches!(
+                order.order_type(),
+                OrderType::StopMarket | OrderType::MarketIfTouched | OrderType::TrailingStopMarket
+            )
+            && matches!(
+                order.status(),
+                OrderStatus::Accepted | OrderStatus::Triggered
+            )
+        {
+            let trigger_price = order
+                .trigger_price()
+                .expect("Stop order must have a trigger price");
+            for fill in fills.iter_mut() {
+                let is_worse_price = match order.order_side_specified() {
+                    OrderSideSpecified::Buy => fill.0 > trigger_price,
+                    OrderSideSpecified::Sell => fill.0 < trigger_price,
+                };
+                if is_worse_price {
+                    fill.0 = trigger_price;
+                }
+            }
+        }
     }
 
     pub fn fill_market_order(&mut self, order: &mut OrderAny) {
@@ -1232,8 +3035,71 @@ impl OrderMatchingEngine {
         }
         // set order side as taker
         order.set_liquidity_side(LiquiditySide::Taker);
-        let fills = self.determine_market_price_and_volume(order);
-        self.apply_fills(order, fills, LiquiditySide::Taker, None, position);
+        // Reuse the fills buffer to avoid allocating per market order, taking it out of
+        // `self` so any re-entrant fill (e.g. contingent orders) gets its own buffer
+        let mut fills = std::mem::take(&mut self.fills_buffer);
+        self.determine_market_price_and_volume(order, &mut fills);
+        let is_sweep_capped = self.cap_sweep_depth(order, &mut fills);
+        self.apply_fills(
+            order,
+            &fills,
+            LiquiditySide::Taker,
+            venue_position_id,
+            position,
+        );
+        fills.clear();
+        self.fills_buffer = fills;
+
+        if is_sweep_capped {
+            self.cancel_sweep_remainder(order);
+        }
+    }
+
+    /// Drops the `fills` beyond the configured max sweep depth from top-of-book, returning
+    /// whether any were dropped.
+    fn cap_sweep_depth(&self, order: &OrderAny, fills: &mut Vec<(Price, Quantity)>) -> bool {
+        let Some(max_depth_pct) = self.config.max_sweep_depth_pct else {
+            return false;
+        };
+        // Market-to-limit orders rest their remainder after the first level instead
+        if order.order_type() == OrderType::MarketToLimit {
+            return false;
+        }
+        let Some(&(top_px, _)) = fills.first() else {
+            return false;
+        };
+
+        let max_offset = top_px.as_f64() * max_depth_pct / 100.0;
+        let fill_count = fills.len();
+        match order.order_side() {
+            OrderSide::Buy => {
+                let max_px = top_px.as_f64() + max_offset;
+                fills.retain(|(fill_px, _)| fill_px.as_f64() <= max_px);
+            }
+            _ => {
+                let min_px = top_px.as_f64() - max_offset;
+                fills.retain(|(fill_px, _)| fill_px.as_f64() >= min_px);
+            }
+        }
+        fills.len() < fill_count
+    }
+
+    /// Cancels the remaining quantity of the `order` after its sweep was capped.
+    fn cancel_sweep_remainder(&mut self, order: &OrderAny) {
+        let is_partially_filled = self
+            .cached_filled_qty
+            .get(&order.client_order_id())
+            .is_some_and(|filled_qty| *filled_qty < order.quantity());
+        if !is_partially_filled {
+            return; // Rejected, canceled or otherwise already closed during fills
+        }
+
+        log::warn!(
+            "Canceling remaining quantity of {}: reached max sweep depth of {}%",
+            order.client_order_id(),
+            self.config.max_sweep_depth_pct.unwrap_or_default()
+        );
+        self.cancel_remaining_qty(order, Ustr::from("MAX_SWEEP_DEPTH"));
     }
 
     pub fn fill_limit_order(&mut self, order: &mut OrderAny) {
@@ -1255,16 +3121,15 @@ impl OrderMatchingEngine {
                     .liquidity_side()
                     .is_some_and(|liquidity_side| liquidity_side == LiquiditySide::Maker)
                 {
-                    if order.order_side() == OrderSide::Buy
-                        && self.core.bid.is_some_and(|bid| bid == order_price)
-                        && !self.fill_model.is_limit_filled()
-                    {
-                        // no filled
-                        return;
-                    }
-                    if order.order_side() == OrderSide::Sell
-                        && self.core.ask.is_some_and(|ask| ask == order_price)
-                        && !self.fill_model.is_limit_filled()
+                    let at_touch = match order.order_side() {
+                        OrderSide::Buy => self.core.bid.is_some_and(|bid| bid == order_price),
+                        _ => self.core.ask.is_some_and(|ask| ask == order_price),
+                    };
+                    // At the touch, any volume ahead in the queue (with a queue model) must be
+                    // depleted first, then the fill model determines whether the order fills
+                    if at_touch
+                        && (!self.is_queue_ahead_depleted(order)
+                            || !self.fill_model.is_limit_filled())
                     {
                         // no filled
                         return;
@@ -1292,7 +3157,7 @@ impl OrderMatchingEngine {
 
                 self.apply_fills(
                     order,
-                    fills,
+                    &fills,
                     order.liquidity_side().unwrap(),
                     venue_position_id,
                     position,
@@ -1305,14 +3170,14 @@ impl OrderMatchingEngine {
     fn apply_fills(
         &mut self,
         order: &mut OrderAny,
-        fills: Vec<(Price, Quantity)>,
+        fills: &[(Price, Quantity)],
         liquidity_side: LiquiditySide,
         venue_position_id: Option<PositionId>,
         position: Option<Position>,
     ) {
         if order.time_in_force() == TimeInForce::Fok {
             let mut total_size = Quantity::zero(order.quantity().precision);
-            for (fill_px, fill_qty) in &fills {
+            for (fill_px, fill_qty) in fills {
                 total_size = total_size.add(*fill_qty);
             }
 
@@ -1350,7 +3215,8 @@ impl OrderMatchingEngine {
         }
 
         let mut initial_market_to_limit_fill = false;
-        for &(mut fill_px, ref fill_qty) in &fills {
+        let mut dust: Option<(Quantity, f64)> = None;
+        for &(mut fill_px, ref fill_qty) in fills {
             // Validate price precision
             assert!(
                 (fill_px.precision == self.instrument.price_precision()),
@@ -1374,7 +3240,6 @@ impl OrderMatchingEngine {
             if order.filled_qty() == Quantity::zero(order.filled_qty().precision)
                 && order.order_type() == OrderType::MarketToLimit
             {
-                self.generate_order_updated(order, order.quantity(), Some(fill_px), None);
                 initial_market_to_limit_fill = true;
             }
 
@@ -1413,19 +3278,54 @@ impl OrderMatchingEngine {
                 return;
             }
 
+            let (fill_px, fill_qty) = match self.config.min_fill_report_qty {
+                Some(min_qty) if !initial_market_to_limit_fill => {
+                    // Accumulate dust fills until the minimum quantity to report is reached
+                    let (dust_qty, dust_notional) =
+                        dust.get_or_insert((Quantity::zero(fill_qty.precision), 0.0));
+                    *dust_qty += *fill_qty;
+                    *dust_notional += fill_px.as_f64() * fill_qty.as_f64();
+                    if *dust_qty < min_qty {
+                        continue;
+                    }
+                    self.take_dust_fill(&mut dust).unwrap()
+                }
+                _ => (fill_px, *fill_qty),
+            };
+
+            if !self.check_max_fills(order) {
+                return;
+            }
+
+            self.fill_order(
+                order,
+                fill_px,
+                fill_qty,
+                liquidity_side,
+                venue_position_id,
+                position.clone(),
+            );
+
+            if order.order_type() == OrderType::MarketToLimit && initial_market_to_limit_fill {
+                // Filled initial level, rest the remainder as a limit order at the fill price
+                self.rest_market_to_limit_order(order, fill_px);
+                return;
+            }
+        }
+
+        // Report any remaining dust so no filled quantity is lost
+        if let Some((fill_px, fill_qty)) = self.take_dust_fill(&mut dust) {
+            if !self.check_max_fills(order) {
+                return;
+            }
             self.fill_order(
                 order,
                 fill_px,
-                *fill_qty,
+                fill_qty,
                 liquidity_side,
                 venue_position_id,
-                position.clone(),
+                position,
             );
-
-            if order.order_type() == OrderType::MarketToLimit && initial_market_to_limit_fill {
-                // filled initial level
-                return;
-            }
         }
 
         if order.time_in_force() == TimeInForce::Ioc && order.is_open() {
@@ -1448,6 +3348,58 @@ impl OrderMatchingEngine {
         }
     }
 
+    /// Returns the accumulated dust fill at its average price, resetting the accumulator.
+    fn take_dust_fill(&self, dust: &mut Option<(Quantity, f64)>) -> Option<(Price, Quantity)> {
+        let (dust_qty, dust_notional) = dust.take()?;
+        let avg_px = Price::new(
+            dust_notional / dust_qty.as_f64(),
+            self.instrument.price_precision(),
+        );
+        Some((avg_px, dust_qty))
+    }
+
+    /// Returns whether the `order` may be filled again, counting the fill when limited by
+    /// `max_fills_per_order` and otherwise canceling the remaining quantity.
+    fn check_max_fills(&mut self, order: &OrderAny) -> bool {
+        let Some(max_fills) = self.config.max_fills_per_order else {
+            return true;
+        };
+        let fill_count = self
+            .fill_counts
+            .get(&order.client_order_id())
+            .copied()
+            .unwrap_or(0);
+        if fill_count >= max_fills {
+            self.cancel_max_fills_remainder(order, max_fills);
+            return false;
+        }
+        self.fill_counts
+            .insert(order.client_order_id(), fill_count + 1);
+        true
+    }
+
+    /// Cancels the remaining quantity of the `order` once it has reached `max_fills`.
+    fn cancel_max_fills_remainder(&mut self, order: &OrderAny, max_fills: usize) {
+        log::warn!(
+            "Canceling remaining quantity of {}: reached max fills per order of {max_fills}",
+            order.client_order_id()
+        );
+        self.fill_counts.remove(&order.client_order_id());
+        self.cancel_remaining_qty(order, Ustr::from("MAX_FILLS_PER_ORDER"));
+    }
+
+    /// Cancels the remaining quantity of an `order` which may never have rested in the core.
+    fn cancel_remaining_qty(&mut self, order: &OrderAny, reason: Ustr) {
+        if self.core.order_exists(order.client_order_id()) {
+            self.cancel_order_with_reason(order, None, Some(reason));
+        } else {
+            // Aggressive orders which never rested in the core
+            self.cached_filled_qty.remove(&order.client_order_id());
+            let venue_order_id = self.ids_generator.get_venue_order_id(order).unwrap();
+            self.generate_order_canceled(order, venue_order_id, Some(reason));
+        }
+    }
+
     fn fill_order(
         &mut self,
         order: &OrderAny,
@@ -1457,6 +3409,13 @@ impl OrderMatchingEngine {
         venue_position_id: Option<PositionId>,
         position: Option<Position>,
     ) {
+        let is_first_fill = !self
+            .cached_filled_qty
+            .contains_key(&order.client_order_id());
+        let was_fully_filled = self
+            .cached_filled_qty
+            .get(&order.client_order_id())
+            .is_some_and(|filled_qty| *filled_qty >= order.quantity());
         match self.cached_filled_qty.get(&order.client_order_id()) {
             Some(filled_qty) => {
                 let leaves_qty = order.quantity() - *filled_qty;
@@ -1471,8 +3430,12 @@ impl OrderMatchingEngine {
                     .insert(order.client_order_id(), last_qty);
             }
         }
+        let is_fully_filled = self.cached_filled_qty[&order.client_order_id()] >= order.quantity();
+        if is_fully_filled && !was_fully_filled {
+            self.filled_count += 1;
+        }
 
-        // calculate commission
+        // calculate commission (in the base currency for inverse instruments)
         let commission = self
             .fee_model
             .get_commission(order, last_qty, last_px, &self.instrument)
@@ -1485,11 +3448,16 @@ impl OrderMatchingEngine {
             venue_position_id,
             last_qty,
             last_px,
+            // The fill currency is that of `last_px`, which is the quote currency for inverse too
             self.instrument.quote_currency(),
             commission,
             liquidity_side,
         );
 
+        if self.config.emit_updated_on_fill {
+            self.generate_order_fill_updated(order, venue_order_id);
+        }
+
         if order.is_aggressive() && order.is_closed() {
             // remove order from market
             let passive_order = PassiveOrderAny::from(order.clone());
@@ -1497,11 +3465,48 @@ impl OrderMatchingEngine {
             self.cached_filled_qty.remove(&order.client_order_id());
         }
 
+        let filled_order_list_id = order
+            .order_list_id()
+            .filter(|_| self.config.cancel_order_list_on_fill && is_fully_filled);
+        if let Some(order_list_id) = filled_order_list_id {
+            for member in self.open_order_list_members(order_list_id) {
+                if member.client_order_id() != order.client_order_id() {
+                    self.cancel_order(&member, None);
+                }
+            }
+        }
+
         if !self.config.support_contingent_orders {
             return;
         }
 
-        todo!("Check for contingent orders")
+        match order.contingency_type() {
+            None | Some(ContingencyType::NoContingency) => {}
+            Some(ContingencyType::Oco) => {
+                // The first fill of an OCO order cancels its linked orders
+                if is_first_fill {
+                    self.cancel_contingent_orders(order);
+                }
+            }
+            _ => todo!("Check for contingent orders"),
+        }
+    }
+
+    fn rest_market_to_limit_order(&mut self, order: &mut OrderAny, limit_px: Price) {
+        let filled_qty = self
+            .cached_filled_qty
+            .get(&order.client_order_id())
+            .copied()
+            .unwrap_or(Quantity::zero(order.quantity().precision));
+        if filled_qty >= order.quantity() {
+            return; // Fully filled at the initial level
+        }
+
+        // Assign the initial fill price as the limit price of the (already accepted) order,
+        // the remaining quantity then rests on the book as a passive limit order
+        self.generate_order_updated(order, order.quantity(), Some(limit_px), None);
+        order.set_liquidity_side(LiquiditySide::Maker);
+        let _ = self.core.add_order(order.to_owned().into());
     }
 
     fn update_limit_order(&mut self, order: &mut OrderAny, quantity: Quantity, price: Price) {
@@ -1525,6 +3530,7 @@ impl OrderMatchingEngine {
                     ).as_str()),
                     order.venue_order_id(),
                     order.account_id(),
+                    ModifyRejectReason::PostOnlyWouldBeTaker,
                 );
                 return;
             }
@@ -1543,10 +3549,7 @@ impl OrderMatchingEngine {
         quantity: Quantity,
         trigger_price: Price,
     ) {
-        if self
-            .core
-            .is_stop_matched(order.order_side_specified(), trigger_price)
-        {
+        if self.is_stop_triggered(order.order_side_specified(), trigger_price) {
             self.generate_order_modify_rejected(
                 order.trader_id(),
                 order.strategy_id(),
@@ -1569,6 +3572,7 @@ impl OrderMatchingEngine {
                 ),
                 order.venue_order_id(),
                 order.account_id(),
+                ModifyRejectReason::TriggerPriceInMarket,
             );
             return;
         }
@@ -1605,6 +3609,7 @@ impl OrderMatchingEngine {
                         ).as_str()),
                         order.venue_order_id(),
                         order.account_id(),
+                        ModifyRejectReason::PostOnlyWouldBeTaker,
                     );
                     return;
                 }
@@ -1614,11 +3619,38 @@ impl OrderMatchingEngine {
                 return; // Filled
             }
         } else {
+            // A changed limit price must not sit behind the stop price before the order is triggered
+            let is_price_changed =
+                Some(price) != order.price() || Some(trigger_price) != order.trigger_price();
+            let is_incompatible = match order.order_side() {
+                OrderSide::Buy => price < trigger_price,
+                _ => price > trigger_price,
+            };
+            if is_price_changed && is_incompatible {
+                self.generate_order_modify_rejected(
+                    order.trader_id(),
+                    order.strategy_id(),
+                    order.instrument_id(),
+                    order.client_order_id(),
+                    Ustr::from(
+                        format!(
+                            "{} {} order new limit px of {} was incompatible with new stop px of {}",
+                            order.order_type(),
+                            order.order_side(),
+                            price,
+                            trigger_price,
+                        )
+                        .as_str(),
+                    ),
+                    order.venue_order_id(),
+                    order.account_id(),
+                    ModifyRejectReason::InvalidPriceTrigger,
+                );
+                return;
+            }
+
             // Update stop price
-            if self
-                .core
-                .is_stop_matched(order.order_side_specified(), trigger_price)
-            {
+            if self.is_stop_triggered(order.order_side_specified(), trigger_price) {
                 self.generate_order_modify_rejected(
                     order.trader_id(),
                     order.strategy_id(),
@@ -1641,6 +3673,7 @@ impl OrderMatchingEngine {
                     ),
                     order.venue_order_id(),
                     order.account_id(),
+                    ModifyRejectReason::TriggerPriceInMarket,
                 );
                 return;
             }
@@ -1655,10 +3688,7 @@ impl OrderMatchingEngine {
         quantity: Quantity,
         trigger_price: Price,
     ) {
-        if self
-            .core
-            .is_touch_triggered(order.order_side_specified(), trigger_price)
-        {
+        if self.is_touch_triggered(order.order_side_specified(), trigger_price) {
             self.generate_order_modify_rejected(
                 order.trader_id(),
                 order.strategy_id(),
@@ -1681,6 +3711,7 @@ impl OrderMatchingEngine {
                 ),
                 order.venue_order_id(),
                 order.account_id(),
+                ModifyRejectReason::TriggerPriceInMarket,
             );
             // Cannot update order
             return;
@@ -1718,6 +3749,7 @@ impl OrderMatchingEngine {
                         ).as_str()),
                         order.venue_order_id(),
                         order.account_id(),
+                        ModifyRejectReason::PostOnlyWouldBeTaker,
                     );
                     // Cannot update order
                     return;
@@ -1729,10 +3761,7 @@ impl OrderMatchingEngine {
             }
         } else {
             // Update trigger price
-            if self
-                .core
-                .is_touch_triggered(order.order_side_specified(), trigger_price)
-            {
+            if self.is_touch_triggered(order.order_side_specified(), trigger_price) {
                 self.generate_order_modify_rejected(
                     order.trader_id(),
                     order.strategy_id(),
@@ -1755,6 +3784,7 @@ impl OrderMatchingEngine {
                     ),
                     order.venue_order_id(),
                     order.account_id(),
+                    ModifyRejectReason::TriggerPriceInMarket,
                 );
                 return;
             }
@@ -1773,6 +3803,22 @@ impl OrderMatchingEngine {
 
     // -- EVENT HANDLING -----------------------------------------------------
 
+    /// Formats the rejection reason for an order `field` whose precision does not match the
+    /// instrument's `instrument_field` precision.
+    fn invalid_precision_reason(
+        &self,
+        client_order_id: ClientOrderId,
+        field: &str,
+        precision: u8,
+        instrument_field: &str,
+        instrument_precision: u8,
+    ) -> Ustr {
+        Ustr::from(&format!(
+            "Invalid order {field} precision for order {client_order_id}, was {precision} when {} {instrument_field} precision is {instrument_precision}",
+            self.instrument.id(),
+        ))
+    }
+
     fn accept_order(&mut self, order: &mut OrderAny) {
         if order.is_closed() {
             // Temporary guard to prevent invalid processing
@@ -1781,6 +3827,8 @@ impl OrderMatchingEngine {
         if order.status() != OrderStatus::Accepted {
             let venue_order_id = self.ids_generator.get_venue_order_id(order).unwrap();
             self.generate_order_accepted(order, venue_order_id);
+            self.accepted_ts
+                .insert(order.client_order_id(), self.clock.get_time_ns());
 
             if matches!(
                 order.order_type(),
@@ -1798,6 +3846,23 @@ impl OrderMatchingEngine {
         }
 
         let _ = self.core.add_order(order.to_owned().into());
+
+        if let (Some(_), Some(price)) = (&self.queue_model, order.price()) {
+            // Volume already resting at the same price level is ahead in the queue
+            let queue_ahead = match order.order_side() {
+                OrderSide::Buy => self
+                    .book
+                    .bids(None)
+                    .find(|level| level.price.value == price),
+                _ => self
+                    .book
+                    .asks(None)
+                    .find(|level| level.price.value == price),
+            }
+            .map_or(0.0, |level| level.size());
+            self.queue_ahead
+                .insert(order.client_order_id(), (queue_ahead, None));
+        }
     }
 
     fn expire_order(&mut self, order: &PassiveOrderAny) {
@@ -1813,6 +3878,16 @@ impl OrderMatchingEngine {
     }
 
     fn cancel_order(&mut self, order: &OrderAny, cancel_contingencies: Option<bool>) {
+        self.cancel_order_with_reason(order, cancel_contingencies, None);
+    }
+
+    /// Cancels the `order`, reporting the `reason` on the resulting `OrderCanceled`.
+    fn cancel_order_with_reason(
+        &mut self,
+        order: &OrderAny,
+        cancel_contingencies: Option<bool>,
+        reason: Option<Ustr>,
+    ) {
         let cancel_contingencies = cancel_contingencies.unwrap_or(true);
         if order.is_active_local() {
             log::error!(
@@ -1827,9 +3902,10 @@ impl OrderMatchingEngine {
             .core
             .delete_order(&PassiveOrderAny::from(order.clone()));
         self.cached_filled_qty.remove(&order.client_order_id());
+        self.accepted_ts.remove(&order.client_order_id());
 
         let venue_order_id = self.ids_generator.get_venue_order_id(order).unwrap();
-        self.generate_order_canceled(order, venue_order_id);
+        self.generate_order_canceled(order, venue_order_id, reason);
 
         if self.config.support_contingent_orders
             && order.contingency_type().is_some()
@@ -1902,8 +3978,156 @@ impl OrderMatchingEngine {
         }
     }
 
+    /// Returns whether the market has reached the `trigger_price` of a stop order, where a
+    /// market exactly at the trigger only counts when `trigger_on_touch` is configured.
+    fn is_stop_triggered(&self, side: OrderSideSpecified, trigger_price: Price) -> bool {
+        self.core.is_stop_matched(side, trigger_price)
+            && (self.config.trigger_on_touch || !self.core.is_at_trigger_price(side, trigger_price))
+    }
+
+    /// Returns whether the market has touched the `trigger_price` of an if-touched order, where a
+    /// market exactly at the trigger only counts when `trigger_on_touch` is configured.
+    fn is_touch_triggered(&self, side: OrderSideSpecified, trigger_price: Price) -> bool {
+        self.core.is_touch_triggered(side, trigger_price)
+            && (self.config.trigger_on_touch || !self.core.is_at_trigger_price(side, trigger_price))
+    }
+
+    /// Returns whether the `order` is triggered by the prices of its trigger instrument, or
+    /// `None` if the order is not triggered by another instrument.
+    fn is_trigger_instrument_triggered(&self, order: &OrderAny) -> Option<bool> {
+        if !self.config.use_trigger_instruments
+            || !matches!(
+                order.order_type(),
+                OrderType::StopMarket
+                    | OrderType::StopLimit
+                    | OrderType::MarketIfTouched
+                    | OrderType::LimitIfTouched
+            )
+        {
+            return None;
+        }
+        let trigger_instrument_id = order
+            .trigger_instrument_id()
+            .filter(|instrument_id| *instrument_id != self.instrument.id())?;
+
+        // No trigger instrument prices have been processed yet
+        let Some(trigger_core) = self.trigger_cores.get(&trigger_instrument_id) else {
+            return Some(false);
+        };
+        Some(self.is_core_triggered(trigger_core, order))
+    }
+
+    /// Returns whether the market of the given `core` has reached the trigger price of the
+    /// `order`, as an if-touched or stop order by its order type.
+    fn is_core_triggered(&self, core: &OrderMatchingCore, order: &OrderAny) -> bool {
+        let Some(trigger_price) = order.trigger_price() else {
+            return false;
+        };
+        let side = order.order_side_specified();
+        let is_matched = match order.order_type() {
+            OrderType::MarketIfTouched | OrderType::LimitIfTouched => {
+                core.is_touch_triggered(side, trigger_price)
+            }
+            _ => core.is_stop_matched(side, trigger_price),
+        };
+        is_matched
+            && (self.config.trigger_on_touch || !core.is_at_trigger_price(side, trigger_price))
+    }
+
     pub fn trigger_stop_order(&mut self, order: &mut OrderAny) {
-        todo!("trigger_stop_order")
+        let trigger_price = order
+            .trigger_price()
+            .expect("Stop order must have a trigger price");
+
+        if self.is_trigger_instrument_triggered(order).is_none()
+            && self
+                .core
+                .is_at_trigger_price(order.order_side_specified(), trigger_price)
+            && !self.fill_model.is_stop_filled()
+        {
+            return; // Not triggered
+        }
+
+        match order.order_type() {
+            OrderType::StopMarket | OrderType::MarketIfTouched | OrderType::TrailingStopMarket => {
+                // Triggered stop places market order
+                self.fill_market_order(order);
+            }
+            OrderType::StopLimit | OrderType::LimitIfTouched | OrderType::TrailingStopLimit => {
+                self.generate_order_triggered(order);
+                self.refresh_core_order(order);
+                self.reprice_triggered_stop_limit(order);
+
+                // Check for immediate fill
+                let price = order.price().expect("Stop limit order must have a price");
+                if self
+                    .core
+                    .is_limit_matched(order.order_side_specified(), price)
+                {
+                    if order.is_post_only() {
+                        // Would be liquidity taker
+                        let _ = self
+                            .core
+                            .delete_order(&PassiveOrderAny::from(order.clone()));
+                        self.cached_filled_qty.remove(&order.client_order_id());
+                        self.generate_order_rejected(
+                            order,
+                            format!(
+                                "POST_ONLY {} {} order limit px of {} would have been a TAKER: bid={}, ask={}",
+                                order.order_type(),
+                                order.order_side(),
+                                price,
+                                self.core
+                                    .bid
+                                    .map_or_else(|| "None".to_string(), |p| p.to_string()),
+                                self.core
+                                    .ask
+                                    .map_or_else(|| "None".to_string(), |p| p.to_string())
+                            )
+                            .into(),
+                        );
+                        return;
+                    }
+                    order.set_liquidity_side(LiquiditySide::Taker);
+                    self.fill_limit_order(order);
+                }
+            }
+            _ => panic!("Invalid order type for trigger: {}", order.order_type()),
+        }
+    }
+
+    /// Reprices the limit of a triggered stop-limit `order` to the opposing best price when
+    /// it is not immediately fillable, if `stop_limit_protect` is configured.
+    fn reprice_triggered_stop_limit(&mut self, order: &mut OrderAny) {
+        if !self.config.stop_limit_protect
+            || order.order_type() != OrderType::StopLimit
+            || order.is_post_only()
+        {
+            return;
+        }
+
+        let side = order.order_side_specified();
+        let price = order.price().expect("Stop limit order must have a price");
+        if self.core.is_limit_matched(side, price) {
+            return;
+        }
+
+        let opposing_best = match side {
+            OrderSideSpecified::Buy => self.core.ask,
+            OrderSideSpecified::Sell => self.core.bid,
+        };
+        if let Some(opposing_best) = opposing_best {
+            self.generate_order_updated(order, order.quantity(), Some(opposing_best), None);
+            self.refresh_core_order(order);
+        }
+    }
+
+    /// Replaces the order held by the matching core with the latest state of `order`.
+    fn refresh_core_order(&mut self, order: &OrderAny) {
+        let passive_order = PassiveOrderAny::from(order.clone());
+        if self.core.delete_order(&passive_order).is_ok() {
+            let _ = self.core.add_order(passive_order);
+        }
     }
 
     fn update_contingent_order(&mut self, order: &OrderAny) {
@@ -1922,21 +4146,135 @@ impl OrderMatchingEngine {
                     continue;
                 }
                 if !contingent_order.is_closed() {
-                    self.cancel_order(&contingent_order, Some(false));
+                    // Report the linked order which caused the cancel
+                    let reason = format!("Canceled by linked order {}", order.client_order_id());
+                    self.cancel_order_with_reason(
+                        &contingent_order,
+                        Some(false),
+                        Some(reason.into()),
+                    );
                 }
             }
         }
     }
 
+    /// Returns the account of the `order`, as indexed when the order was processed.
+    fn order_account_id(&self, order: &OrderAny) -> Option<AccountId> {
+        order
+            .account_id()
+            .or_else(|| {
+                self.order_account_ids
+                    .get(&order.client_order_id())
+                    .copied()
+            })
+            .or_else(|| self.account_ids.get(&order.trader_id()).copied())
+    }
+
     // -- EVENT GENERATORS -----------------------------------------------------
 
-    fn generate_order_rejected(&self, order: &OrderAny, reason: Ustr) {
-        let ts_now = self.clock.get_time_ns();
-        let account_id = order
-            .account_id()
-            .unwrap_or(self.account_ids.get(&order.trader_id()).unwrap().to_owned());
+    /// Starts buffering order events when `batch_order_events` is configured, returning the
+    /// batch which sends them on drop (`None` if not batching or already within a batch).
+    fn begin_event_batch(&self) -> Option<OrderEventBatch> {
+        if !self.config.batch_order_events || self.event_batch.borrow().is_some() {
+            return None;
+        }
+        *self.event_batch.borrow_mut() = Some(Vec::new());
+        Some(OrderEventBatch {
+            events: self.event_batch.clone(),
+            msgbus: self.msgbus.clone(),
+        })
+    }
+
+    fn send_order_event(&self, event: &OrderEventAny) {
+        if let Some(events) = self.event_batch.borrow_mut().as_mut() {
+            events.push(event.clone());
+            return;
+        }
+        let msgbus = self.msgbus.as_ref().borrow();
+        msgbus.send(&msgbus.switchboard.exec_engine_process, event as &dyn Any);
+    }
+
+    /// Applies the given fill to the engine's own view of the position, for realized PnL tracking.
+    fn update_fill_position(&mut self, fill: &OrderFilled) {
+        let position_id = fill.position_id.unwrap_or_else(|| {
+            PositionId::new(format!("{}-{}", fill.instrument_id, fill.strategy_id).as_str())
+        });
+
+        match self.fill_positions.get_mut(&position_id) {
+            Some(position) => {
+                // Applying a fill to a closed position reopens it, resetting its realized PnL
+                if position.is_closed() {
+                    self.closed_realized_pnl +=
+                        position.realized_pnl.map_or(0.0, |pnl| pnl.as_f64());
+                }
+                position.apply(fill);
+            }
+            None => {
+                let mut fill = *fill;
+                fill.position_id = Some(position_id);
+                self.fill_positions
+                    .insert(position_id, Position::new(&self.instrument, fill));
+            }
+        }
+
+        let position = &self.fill_positions[&position_id];
+        if position.is_closed() {
+            log::info!(
+                "Position {position_id} closed with realized PnL {}",
+                position
+                    .realized_pnl
+                    .unwrap_or_else(|| Money::new(0.0, self.instrument.settlement_currency())),
+            );
+        }
+    }
+
+    /// Returns the mark price for unrealized PnL: the last mark price update, otherwise the
+    /// current book midpoint (or the last trade price if there is no midpoint).
+    fn pnl_mark_price(&self) -> Option<Price> {
+        match (self.mark_price, self.book.midpoint()) {
+            (Some(mark), _) => Some(mark),
+            // Extra decimal place so a half-tick midpoint is represented exactly
+            (None, Some(mid)) => Some(Price::new(
+                mid,
+                (self.instrument.price_precision() + 1).min(FIXED_PRECISION),
+            )),
+            (None, None) => self.core.last,
+        }
+    }
+
+    /// Updates the running equity of the fills generated by this engine and its max drawdown.
+    fn update_drawdown(&mut self) {
+        let unrealized_pnl: f64 = self.pnl_mark_price().map_or(0.0, |mark_price| {
+            self.fill_positions
+                .values()
+                .filter(|position| position.is_open())
+                .map(|position| position.unrealized_pnl(mark_price).as_f64())
+                .sum()
+        });
+        let equity = self.realized_pnl(self.instrument.id()).as_f64() + unrealized_pnl;
+        self.peak_equity = self.peak_equity.max(equity);
+        self.max_drawdown = self.max_drawdown.max(self.peak_equity - equity);
+    }
+
+    /// Applies the configured venue clock offset to the given engine timestamp.
+    fn venue_ts_event(&self, ts: UnixNanos) -> UnixNanos {
+        UnixNanos::from(
+            ts.as_u64()
+                .saturating_add_signed(self.config.venue_clock_offset_nanos),
+        )
+    }
 
-        let event = OrderEventAny::Rejected(OrderRejected::new(
+    fn generate_order_rejected(&mut self, order: &OrderAny, reason: Ustr) {
+        let ts_now = self.clock.get_time_ns();
+        let account_id = self.order_account_id(order).unwrap();
+        let venue_order_id = order.venue_order_id().or_else(|| {
+            self.cache
+                .borrow()
+                .venue_order_id(&order.client_order_id())
+                .copied()
+        });
+
+        let rejected = OrderRejected::new(
             order.trader_id(),
             order.strategy_id(),
             order.instrument_id(),
@@ -1944,19 +4282,48 @@ impl OrderMatchingEngine {
             account_id,
             reason,
             UUID4::new(),
-            ts_now,
+            self.venue_ts_event(ts_now),
             ts_now,
             false,
+            venue_order_id,
+        );
+        self.rejected_count += 1;
+        for callback in &mut self.reject_callbacks {
+            callback(&rejected);
+        }
+        let capacity = self.config.recent_rejections_capacity;
+        if capacity > 0 {
+            if self.recent_rejections.len() == capacity {
+                self.recent_rejections.pop_front();
+            }
+            self.recent_rejections.push_back(rejected);
+        }
+
+        let event = OrderEventAny::Rejected(rejected);
+        self.send_order_event(&event);
+    }
+
+    fn generate_order_submitted(&self, order: &mut OrderAny, account_id: AccountId) {
+        let ts_now = self.clock.get_time_ns();
+        let event = OrderEventAny::Submitted(OrderSubmitted::new(
+            order.trader_id(),
+            order.strategy_id(),
+            order.instrument_id(),
+            order.client_order_id(),
+            account_id,
+            UUID4::new(),
+            self.venue_ts_event(ts_now),
+            ts_now,
         ));
-        let msgbus = self.msgbus.as_ref().borrow();
-        msgbus.send(&msgbus.switchboard.exec_engine_process, &event as &dyn Any);
+        self.send_order_event(&event);
+
+        // TODO remove this when execution engine msgbus handlers are correctly set
+        order.apply(event).expect("Failed to apply order event");
     }
 
     fn generate_order_accepted(&self, order: &mut OrderAny, venue_order_id: VenueOrderId) {
         let ts_now = self.clock.get_time_ns();
-        let account_id = order
-            .account_id()
-            .unwrap_or(self.account_ids.get(&order.trader_id()).unwrap().to_owned());
+        let account_id = self.order_account_id(order).unwrap();
         let event = OrderEventAny::Accepted(OrderAccepted::new(
             order.trader_id(),
             order.strategy_id(),
@@ -1965,12 +4332,11 @@ impl OrderMatchingEngine {
             venue_order_id,
             account_id,
             UUID4::new(),
-            ts_now,
+            self.venue_ts_event(ts_now),
             ts_now,
             false,
         ));
-        let msgbus = self.msgbus.as_ref().borrow();
-        msgbus.send(&msgbus.switchboard.exec_engine_process, &event as &dyn Any);
+        self.send_order_event(&event);
 
         // TODO remove this when execution engine msgbus handlers are correctly set
         order.apply(event).expect("Failed to apply order event");
@@ -1986,6 +4352,7 @@ impl OrderMatchingEngine {
         reason: Ustr,
         venue_order_id: Option<VenueOrderId>,
         account_id: Option<AccountId>,
+        reason_code: ModifyRejectReason,
     ) {
         let ts_now = self.clock.get_time_ns();
         let event = OrderEventAny::ModifyRejected(OrderModifyRejected::new(
@@ -1995,14 +4362,14 @@ impl OrderMatchingEngine {
             client_order_id,
             reason,
             UUID4::new(),
-            ts_now,
+            self.venue_ts_event(ts_now),
             ts_now,
             false,
             venue_order_id,
             account_id,
+            Some(reason_code),
         ));
-        let msgbus = self.msgbus.as_ref().borrow();
-        msgbus.send(&msgbus.switchboard.exec_engine_process, &event as &dyn Any);
+        self.send_order_event(&event);
     }
 
     #[allow(clippy::too_many_arguments)]
@@ -2024,14 +4391,13 @@ impl OrderMatchingEngine {
             client_order_id,
             reason,
             UUID4::new(),
-            ts_now,
+            self.venue_ts_event(ts_now),
             ts_now,
             false,
             Some(venue_order_id),
             Some(account_id),
         ));
-        let msgbus = self.msgbus.as_ref().borrow();
-        msgbus.send(&msgbus.switchboard.exec_engine_process, &event as &dyn Any);
+        self.send_order_event(&event);
     }
 
     fn generate_order_updated(
@@ -2049,73 +4415,155 @@ impl OrderMatchingEngine {
             order.client_order_id(),
             quantity,
             UUID4::new(),
-            ts_now,
+            self.venue_ts_event(ts_now),
             ts_now,
             false,
             order.venue_order_id(),
-            order.account_id(),
+            self.order_account_id(order),
             price,
             trigger_price,
         ));
-        let msgbus = self.msgbus.as_ref().borrow();
-        msgbus.send(&msgbus.switchboard.exec_engine_process, &event as &dyn Any);
+        self.send_order_event(&event);
 
         // TODO remove this when execution engine msgbus handlers are correctly set
         order.apply(event).expect("Failed to apply order event");
     }
 
-    fn generate_order_canceled(&self, order: &OrderAny, venue_order_id: VenueOrderId) {
+    /// Emits an `OrderUpdated` for the `order` after a fill.
+    ///
+    /// The update carries the total order quantity (leaves are derived from the fills applied),
+    /// so applying it leaves the order quantity unchanged.
+    fn generate_order_fill_updated(&self, order: &OrderAny, venue_order_id: VenueOrderId) {
         let ts_now = self.clock.get_time_ns();
-        let event = OrderEventAny::Canceled(OrderCanceled::new(
+        let account_id = self.order_account_id(order);
+        let event = OrderEventAny::Updated(OrderUpdated::new(
             order.trader_id(),
             order.strategy_id(),
             order.instrument_id(),
             order.client_order_id(),
+            order.quantity(),
             UUID4::new(),
-            ts_now,
+            self.venue_ts_event(ts_now),
             ts_now,
             false,
             Some(venue_order_id),
-            order.account_id(),
+            account_id,
+            order.price(),
+            order.trigger_price(),
         ));
-        let msgbus = self.msgbus.as_ref().borrow();
-        msgbus.send(&msgbus.switchboard.exec_engine_process, &event as &dyn Any);
+        self.send_order_event(&event);
     }
 
-    fn generate_order_triggered(&self, order: &OrderAny) {
+    fn generate_order_canceled(
+        &mut self,
+        order: &OrderAny,
+        venue_order_id: VenueOrderId,
+        reason: Option<Ustr>,
+    ) {
         let ts_now = self.clock.get_time_ns();
-        let event = OrderEventAny::Triggered(OrderTriggered::new(
+        let canceled = OrderCanceled::new(
             order.trader_id(),
             order.strategy_id(),
             order.instrument_id(),
             order.client_order_id(),
             UUID4::new(),
+            self.venue_ts_event(ts_now),
             ts_now,
+            false,
+            Some(venue_order_id),
+            self.order_account_id(order),
+            reason,
+        );
+        for callback in &mut self.cancel_callbacks {
+            callback(&canceled);
+        }
+
+        let event = OrderEventAny::Canceled(canceled);
+        self.send_order_event(&event);
+    }
+
+    fn generate_order_triggered(&self, order: &mut OrderAny) {
+        let ts_now = self.clock.get_time_ns();
+        // The market price reaching the trigger is that of the side the order would take,
+        // of the trigger instrument when triggered by another instrument
+        let trigger_core = order
+            .trigger_instrument_id()
+            .filter(|_| self.is_trigger_instrument_triggered(order).is_some())
+            .and_then(|instrument_id| self.trigger_cores.get(&instrument_id))
+            .unwrap_or(&self.core);
+        let market_price = match order.order_side_specified() {
+            OrderSideSpecified::Buy => trigger_core.ask,
+            OrderSideSpecified::Sell => trigger_core.bid,
+        };
+        let event = OrderEventAny::Triggered(OrderTriggered::new(
+            order.trader_id(),
+            order.strategy_id(),
+            order.instrument_id(),
+            order.client_order_id(),
+            UUID4::new(),
+            self.venue_ts_event(ts_now),
             ts_now,
             false,
             order.venue_order_id(),
-            order.account_id(),
+            self.order_account_id(order),
+            order.trigger_price(),
+            market_price,
         ));
-        let msgbus = self.msgbus.as_ref().borrow();
-        msgbus.send(&msgbus.switchboard.exec_engine_process, &event as &dyn Any);
+        self.send_order_event(&event);
+
+        // TODO remove this when execution engine msgbus handlers are correctly set
+        order.apply(event).expect("Failed to apply order event");
     }
 
-    fn generate_order_expired(&self, order: &OrderAny) {
+    fn generate_order_expired(&mut self, order: &OrderAny) {
         let ts_now = self.clock.get_time_ns();
-        let event = OrderEventAny::Expired(OrderExpired::new(
+        let expired = OrderExpired::new(
             order.trader_id(),
             order.strategy_id(),
             order.instrument_id(),
             order.client_order_id(),
             UUID4::new(),
-            ts_now,
+            self.venue_ts_event(ts_now),
             ts_now,
             false,
             order.venue_order_id(),
-            order.account_id(),
-        ));
-        let msgbus = self.msgbus.as_ref().borrow();
-        msgbus.send(&msgbus.switchboard.exec_engine_process, &event as &dyn Any);
+            self.order_account_id(order),
+        );
+        if let Some(mut callback) = self.expired_callbacks.remove(&order.client_order_id()) {
+            callback(&expired);
+        }
+        let event = OrderEventAny::Expired(expired);
+        self.send_order_event(&event);
+    }
+
+    /// Returns the slippage of the fill price `last_px` from the expected price for the
+    /// `order`, positive when adverse.
+    ///
+    /// The expected price is the order price, otherwise the book midpoint on arrival.
+    fn fill_slippage(&self, order: &OrderAny, last_px: Price) -> Option<Price> {
+        let expected_px = match order.price() {
+            Some(price) => price,
+            None => {
+                let mid = self.book.midpoint()?;
+                let price_precision = self.instrument.price_precision();
+                let on_tick_mid = Price::new(mid, price_precision);
+                // Extra decimal place only when the midpoint falls between ticks
+                let sub_tick_mid = Price::new(mid, (price_precision + 1).min(FIXED_PRECISION));
+                if sub_tick_mid == on_tick_mid {
+                    on_tick_mid
+                } else {
+                    sub_tick_mid
+                }
+            }
+        };
+        let slippage_raw = match order.order_side_specified() {
+            OrderSideSpecified::Buy => last_px.raw - expected_px.raw,
+            OrderSideSpecified::Sell => expected_px.raw - last_px.raw,
+        };
+        Some(Price::from_raw(
+            slippage_raw,
+            last_px.precision.max(expected_px.precision),
+        ))
     }
 
     #[allow(clippy::too_many_arguments)]
@@ -2131,10 +4579,25 @@ impl OrderMatchingEngine {
         liquidity_side: LiquiditySide,
     ) {
         let ts_now = self.clock.get_time_ns();
-        let account_id = order
-            .account_id()
-            .unwrap_or(self.account_ids.get(&order.trader_id()).unwrap().to_owned());
-        let event = OrderEventAny::Filled(OrderFilled::new(
+        let ts_event = if self.config.use_market_data_ts_for_fills {
+            self.last_market_data_ts
+        } else {
+            ts_now
+        };
+        // Conditional orders only fill once triggered, so take the stop fill latency
+        let latency_nanos = self.latency_model.as_mut().map_or(0, |latency_model| {
+            if order.trigger_price().is_some() {
+                latency_model.stop_fill_latency()
+            } else {
+                latency_model.fill_latency()
+            }
+        });
+        let ts_filled = UnixNanos::from(ts_event.as_u64() + latency_nanos);
+        // The fill event is only initialized once any fill latency has elapsed, on engine time
+        let ts_init = ts_now.max(ts_filled);
+        let ts_event = self.venue_ts_event(ts_filled);
+        let account_id = self.order_account_id(order).unwrap();
+        let filled = OrderFilled::new(
             order.trader_id(),
             order.strategy_id(),
             order.instrument_id(),
@@ -2149,13 +4612,30 @@ impl OrderMatchingEngine {
             quote_currency,
             liquidity_side,
             UUID4::new(),
-            ts_now,
-            ts_now,
+            ts_event,
+            ts_init,
             false,
             venue_position_id,
             Some(commission),
-        ));
-        let msgbus = self.msgbus.as_ref().borrow();
-        msgbus.send(&msgbus.switchboard.exec_engine_process, &event as &dyn Any);
+            self.fill_slippage(order, last_px),
+        );
+        for callback in &mut self.fill_callbacks {
+            callback(&filled);
+        }
+        if self.config.record_trades {
+            self.trades.push(filled);
+        }
+        if let Some(ts_accepted) = self.accepted_ts.get(&order.client_order_id()) {
+            let time_to_fill = ts_event
+                .as_u64()
+                .saturating_sub(self.venue_ts_event(*ts_accepted).as_u64());
+            self.times_to_fill
+                .insert(order.client_order_id(), time_to_fill);
+        }
+        self.update_fill_position(&filled);
+        self.update_drawdown();
+
+        let event = OrderEventAny::Filled(filled);
+        self.send_order_event(&event);
     }
 }
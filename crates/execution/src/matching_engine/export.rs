@@ -0,0 +1,136 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2025 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+//! Export of the fills generated by the `OrderMatchingEngine` for external analysis.
+
+use std::{fs::File, path::Path, sync::Arc};
+
+use arrow::{
+    array::{ArrayRef, Float64Array, StringArray, UInt64Array},
+    csv::Writer as CsvWriter,
+    datatypes::{DataType, Field, Schema},
+    record_batch::RecordBatch,
+};
+use nautilus_model::events::OrderFilled;
+use parquet::arrow::ArrowWriter;
+
+/// The file format for exported fills.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum FillsExportFormat {
+    /// Comma-separated values with a header row.
+    Csv,
+    /// Apache Parquet.
+    Parquet,
+}
+
+/// Returns the schema of exported fills, one row per fill.
+#[must_use]
+pub fn fills_schema() -> Schema {
+    Schema::new(vec![
+        Field::new("trader_id", DataType::Utf8, false),
+        Field::new("strategy_id", DataType::Utf8, false),
+        Field::new("instrument_id", DataType::Utf8, false),
+        Field::new("client_order_id", DataType::Utf8, false),
+        Field::new("venue_order_id", DataType::Utf8, false),
+        Field::new("account_id", DataType::Utf8, false),
+        Field::new("trade_id", DataType::Utf8, false),
+        Field::new("position_id", DataType::Utf8, true),
+        Field::new("order_side", DataType::Utf8, false),
+        Field::new("order_type", DataType::Utf8, false),
+        Field::new("last_qty", DataType::Float64, false),
+        Field::new("last_px", DataType::Float64, false),
+        Field::new("currency", DataType::Utf8, false),
+        Field::new("liquidity_side", DataType::Utf8, false),
+        Field::new("commission", DataType::Float64, true),
+        Field::new("commission_currency", DataType::Utf8, true),
+        Field::new("ts_event", DataType::UInt64, false),
+        Field::new("ts_init", DataType::UInt64, false),
+    ])
+}
+
+/// Converts the `fills` into a single record batch of the [`fills_schema`].
+///
+/// # Errors
+///
+/// Returns an error if the record batch cannot be created.
+pub fn fills_to_record_batch(fills: &[OrderFilled]) -> anyhow::Result<RecordBatch> {
+    let strings = |f: fn(&OrderFilled) -> String| -> ArrayRef {
+        Arc::new(StringArray::from_iter_values(fills.iter().map(f)))
+    };
+    let columns: Vec<ArrayRef> = vec![
+        strings(|fill| fill.trader_id.to_string()),
+        strings(|fill| fill.strategy_id.to_string()),
+        strings(|fill| fill.instrument_id.to_string()),
+        strings(|fill| fill.client_order_id.to_string()),
+        strings(|fill| fill.venue_order_id.to_string()),
+        strings(|fill| fill.account_id.to_string()),
+        strings(|fill| fill.trade_id.to_string()),
+        Arc::new(StringArray::from_iter(fills.iter().map(|fill| {
+            fill.position_id.map(|position_id| position_id.to_string())
+        }))),
+        strings(|fill| fill.order_side.to_string()),
+        strings(|fill| fill.order_type.to_string()),
+        Arc::new(Float64Array::from_iter_values(
+            fills.iter().map(|fill| fill.last_qty.as_f64()),
+        )),
+        Arc::new(Float64Array::from_iter_values(
+            fills.iter().map(|fill| fill.last_px.as_f64()),
+        )),
+        strings(|fill| fill.currency.to_string()),
+        strings(|fill| fill.liquidity_side.to_string()),
+        Arc::new(Float64Array::from_iter(fills.iter().map(|fill| {
+            fill.commission.map(|commission| commission.as_f64())
+        }))),
+        Arc::new(StringArray::from_iter(fills.iter().map(|fill| {
+            fill.commission
+                .map(|commission| commission.currency.to_string())
+        }))),
+        Arc::new(UInt64Array::from_iter_values(
+            fills.iter().map(|fill| fill.ts_event.as_u64()),
+        )),
+        Arc::new(UInt64Array::from_iter_values(
+            fills.iter().map(|fill| fill.ts_init.as_u64()),
+        )),
+    ];
+
+    Ok(RecordBatch::try_new(Arc::new(fills_schema()), columns)?)
+}
+
+/// Writes the `fills` to a file at `path` in the given `format`.
+///
+/// # Errors
+///
+/// Returns an error if the file cannot be created or written.
+pub fn write_fills(
+    fills: &[OrderFilled],
+    path: &Path,
+    format: FillsExportFormat,
+) -> anyhow::Result<()> {
+    let batch = fills_to_record_batch(fills)?;
+    let file = File::create(path)?;
+    match format {
+        FillsExportFormat::Csv => {
+            let mut writer = CsvWriter::new(file);
+            writer.write(&batch)?;
+        }
+        FillsExportFormat::Parquet => {
+            let mut writer = ArrowWriter::try_new(file, batch.schema(), None)?;
+            writer.write(&batch)?;
+            writer.close()?;
+        }
+    }
+
+    Ok(())
+}
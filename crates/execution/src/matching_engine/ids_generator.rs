@@ -18,9 +18,10 @@ use std::{cell::RefCell, rc::Rc};
 use nautilus_common::cache::Cache;
 use nautilus_model::{
     enums::OmsType,
-    identifiers::{PositionId, TradeId, Venue, VenueOrderId},
+    identifiers::{InstrumentId, PositionId, TradeId, Venue, VenueOrderId},
     orders::OrderAny,
 };
+use ustr::Ustr;
 use uuid::Uuid;
 
 pub struct IdsGenerator {
@@ -29,6 +30,7 @@ pub struct IdsGenerator {
     oms_type: OmsType,
     use_random_ids: bool,
     use_position_ids: bool,
+    position_id_pattern: Option<Ustr>,
     cache: Rc<RefCell<Cache>>,
     position_count: usize,
     order_count: usize,
@@ -51,12 +53,21 @@ impl IdsGenerator {
             cache,
             use_random_ids,
             use_position_ids,
+            position_id_pattern: None,
             position_count: 0,
             order_count: 0,
             execution_count: 0,
         }
     }
 
+    /// Sets the pattern for generated position IDs, where `{venue}`, `{raw_id}`, `{instrument}`
+    /// and `{counter}` are replaced. The pattern takes precedence over random IDs.
+    #[must_use]
+    pub const fn with_position_id_pattern(mut self, pattern: Option<Ustr>) -> Self {
+        self.position_id_pattern = pattern;
+        self
+    }
+
     pub const fn reset(&mut self) {
         self.position_count = 0;
         self.order_count = 0;
@@ -98,7 +109,7 @@ impl IdsGenerator {
                 }
             }
             if generate {
-                self.generate_venue_position_id()
+                self.generate_venue_position_id(order.instrument_id())
             } else {
                 panic!(
                     "Position id should be generated. Hedging Oms type order matching engine doesnt exists in cache."
@@ -127,13 +138,23 @@ impl IdsGenerator {
         TradeId::from(trade_id.as_str())
     }
 
-    pub fn generate_venue_position_id(&mut self) -> Option<PositionId> {
+    pub fn generate_venue_position_id(
+        &mut self,
+        instrument_id: InstrumentId,
+    ) -> Option<PositionId> {
         if !self.use_position_ids {
             return None;
         }
 
         self.position_count += 1;
-        if self.use_random_ids {
+        if let Some(pattern) = self.position_id_pattern {
+            let position_id = pattern
+                .replace("{venue}", self.venue.as_str())
+                .replace("{raw_id}", &self.raw_id.to_string())
+                .replace("{instrument}", &instrument_id.to_string())
+                .replace("{counter}", &self.position_count.to_string());
+            Some(PositionId::new(position_id.as_str()))
+        } else if self.use_random_ids {
             Some(PositionId::new(Uuid::new_v4().to_string()))
         } else {
             Some(PositionId::new(
@@ -162,12 +183,13 @@ mod tests {
     use nautilus_model::{
         enums::OmsType,
         events::OrderFilled,
-        identifiers::{PositionId, Venue, VenueOrderId},
+        identifiers::{InstrumentId, PositionId, Venue, VenueOrderId},
         instruments::InstrumentAny,
         orders::OrderAny,
         position::Position,
     };
     use rstest::rstest;
+    use ustr::Ustr;
 
     use crate::matching_engine::{
         ids_generator::IdsGenerator,
@@ -252,17 +274,32 @@ mod tests {
             get_ids_generator(cache.clone(), true, OmsType::Netting);
         let mut ids_generator_no_position_ids = get_ids_generator(cache, false, OmsType::Netting);
 
+        let instrument_id = InstrumentId::from("ETHUSDT-PERP.BINANCE");
         assert_eq!(
-            ids_generator_no_position_ids.generate_venue_position_id(),
+            ids_generator_no_position_ids.generate_venue_position_id(instrument_id),
             None
         );
 
-        let position_id_1 = ids_generator_with_position_ids.generate_venue_position_id();
-        let position_id_2 = ids_generator_with_position_ids.generate_venue_position_id();
+        let position_id_1 =
+            ids_generator_with_position_ids.generate_venue_position_id(instrument_id);
+        let position_id_2 =
+            ids_generator_with_position_ids.generate_venue_position_id(instrument_id);
         assert_eq!(position_id_1, Some(PositionId::new("BINANCE-1-1")));
         assert_eq!(position_id_2, Some(PositionId::new("BINANCE-1-2")));
     }
 
+    #[rstest]
+    fn test_generate_venue_position_id_with_pattern(market_order_buy: OrderAny) {
+        let cache = Rc::new(RefCell::new(Cache::default()));
+        let mut ids_generator = get_ids_generator(cache, true, OmsType::Hedging)
+            .with_position_id_pattern(Some(Ustr::from("P-{venue}-{raw_id}-{counter}")));
+
+        let position_id_1 = ids_generator.get_position_id(&market_order_buy, None);
+        let position_id_2 = ids_generator.get_position_id(&market_order_buy, None);
+        assert_eq!(position_id_1, Some(PositionId::new("P-BINANCE-1-1")));
+        assert_eq!(position_id_2, Some(PositionId::new("P-BINANCE-1-2")));
+    }
+
     #[rstest]
     fn get_venue_position_id(market_order_buy: OrderAny, market_order_sell: OrderAny) {
         let cache = Rc::new(RefCell::new(Cache::default()));
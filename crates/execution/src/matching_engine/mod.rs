@@ -14,9 +14,13 @@
 // -------------------------------------------------------------------------------------------------
 
 pub mod adapter;
+pub mod auction;
 pub mod config;
 pub mod engine;
+#[cfg(feature = "export")]
+pub mod export;
 pub mod ids_generator;
+pub mod pool;
 
 #[cfg(test)]
 mod tests;
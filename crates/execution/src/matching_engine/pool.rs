@@ -0,0 +1,282 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2025 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+use std::{cell::RefCell, collections::HashMap, rc::Rc};
+
+use nautilus_common::{cache::Cache, msgbus::MessageBus};
+use nautilus_core::{AtomicTime, UnixNanos};
+use nautilus_model::{
+    data::{Bar, OrderBookDelta, OrderBookDeltas, QuoteTick, TradeTick},
+    enums::{AccountType, BookType, OmsType},
+    identifiers::{AccountId, InstrumentId},
+    instruments::InstrumentAny,
+    orders::OrderAny,
+};
+
+use crate::{
+    matching_engine::{config::OrderMatchingEngineConfig, engine::OrderMatchingEngine},
+    messages::{BatchCancelOrders, CancelAllOrders, CancelOrder, ModifyOrder},
+    models::{fee::FeeModelAny, fill::FillModel},
+};
+
+/// Manages an `OrderMatchingEngine` per instrument, routing orders, commands and market data
+/// to the engine of their instrument.
+///
+/// Engines are created with the pool's default config, unless a config override was set for
+/// their instrument.
+pub struct MatchingEnginePool {
+    default_config: OrderMatchingEngineConfig,
+    config_overrides: HashMap<InstrumentId, OrderMatchingEngineConfig>,
+    engines: HashMap<InstrumentId, OrderMatchingEngine>,
+}
+
+impl MatchingEnginePool {
+    /// Creates a new [`MatchingEnginePool`] instance.
+    #[must_use]
+    pub fn new(default_config: OrderMatchingEngineConfig) -> Self {
+        Self {
+            default_config,
+            config_overrides: HashMap::new(),
+            engines: HashMap::new(),
+        }
+    }
+
+    /// Sets the `config` used instead of the default config for the engine of `instrument_id`.
+    ///
+    /// Only applies to engines added after the override is set.
+    pub fn set_config_override(
+        &mut self,
+        instrument_id: InstrumentId,
+        config: OrderMatchingEngineConfig,
+    ) {
+        self.config_overrides.insert(instrument_id, config);
+    }
+
+    /// Returns the config for the engine of `instrument_id`.
+    #[must_use]
+    pub fn config(&self, instrument_id: &InstrumentId) -> &OrderMatchingEngineConfig {
+        self.config_overrides
+            .get(instrument_id)
+            .unwrap_or(&self.default_config)
+    }
+
+    /// Creates and adds an engine for the `instrument`, replacing any existing engine for it.
+    #[allow(clippy::too_many_arguments)]
+    pub fn add_engine(
+        &mut self,
+        instrument: InstrumentAny,
+        raw_id: u32,
+        fill_model: FillModel,
+        fee_model: FeeModelAny,
+        book_type: BookType,
+        oms_type: OmsType,
+        account_type: AccountType,
+        clock: &'static AtomicTime,
+        msgbus: Rc<RefCell<MessageBus>>,
+        cache: Rc<RefCell<Cache>>,
+    ) {
+        let instrument_id = instrument.id();
+        let config = self.config(&instrument_id).clone();
+        let engine = OrderMatchingEngine::new(
+            instrument,
+            raw_id,
+            fill_model,
+            fee_model,
+            book_type,
+            oms_type,
+            account_type,
+            clock,
+            msgbus,
+            cache,
+            config,
+        );
+        self.engines.insert(instrument_id, engine);
+    }
+
+    /// Returns the engine for `instrument_id`, if any.
+    #[must_use]
+    pub fn engine(&self, instrument_id: &InstrumentId) -> Option<&OrderMatchingEngine> {
+        self.engines.get(instrument_id)
+    }
+
+    /// Returns a mutable reference to the engine for `instrument_id`, if any.
+    #[must_use]
+    pub fn engine_mut(&mut self, instrument_id: &InstrumentId) -> Option<&mut OrderMatchingEngine> {
+        self.engines.get_mut(instrument_id)
+    }
+
+    /// Returns the instrument IDs of the engines in the pool.
+    #[must_use]
+    pub fn instrument_ids(&self) -> Vec<InstrumentId> {
+        self.engines.keys().copied().collect()
+    }
+
+    fn routed_engine(
+        &mut self,
+        instrument_id: &InstrumentId,
+    ) -> anyhow::Result<&mut OrderMatchingEngine> {
+        self.engines.get_mut(instrument_id).ok_or_else(|| {
+            anyhow::anyhow!("No matching engine found for instrument {instrument_id}")
+        })
+    }
+
+    // -- ROUTING ---------------------------------------------------------------------------------
+
+    /// Routes the `order` to the engine of its instrument.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the pool has no engine for the instrument (as for all routing methods).
+    pub fn process_order(
+        &mut self,
+        order: &mut OrderAny,
+        account_id: AccountId,
+    ) -> anyhow::Result<()> {
+        self.routed_engine(&order.instrument_id())?
+            .process_order(order, account_id);
+        Ok(())
+    }
+
+    pub fn process_modify(
+        &mut self,
+        command: &ModifyOrder,
+        account_id: AccountId,
+    ) -> anyhow::Result<()> {
+        self.routed_engine(&command.instrument_id)?
+            .process_modify(command, account_id);
+        Ok(())
+    }
+
+    pub fn process_cancel(
+        &mut self,
+        command: &CancelOrder,
+        account_id: AccountId,
+    ) -> anyhow::Result<()> {
+        self.routed_engine(&command.instrument_id)?
+            .process_cancel(command, account_id);
+        Ok(())
+    }
+
+    pub fn process_cancel_all(
+        &mut self,
+        command: &CancelAllOrders,
+        account_id: AccountId,
+    ) -> anyhow::Result<()> {
+        self.routed_engine(&command.instrument_id)?
+            .process_cancel_all(command, account_id);
+        Ok(())
+    }
+
+    pub fn process_batch_cancel(
+        &mut self,
+        command: &BatchCancelOrders,
+        account_id: AccountId,
+    ) -> anyhow::Result<()> {
+        self.routed_engine(&command.instrument_id)?
+            .process_batch_cancel(command, account_id);
+        Ok(())
+    }
+
+    pub fn process_order_book_delta(&mut self, delta: &OrderBookDelta) -> anyhow::Result<()> {
+        self.routed_engine(&delta.instrument_id)?
+            .process_order_book_delta(delta);
+        Ok(())
+    }
+
+    pub fn process_order_book_deltas(&mut self, deltas: &OrderBookDeltas) -> anyhow::Result<()> {
+        self.routed_engine(&deltas.instrument_id)?
+            .process_order_book_deltas(deltas);
+        Ok(())
+    }
+
+    pub fn process_quote_tick(&mut self, quote: &QuoteTick) -> anyhow::Result<()> {
+        self.routed_engine(&quote.instrument_id)?
+            .process_quote_tick(quote);
+        Ok(())
+    }
+
+    pub fn process_trade_tick(&mut self, trade: &TradeTick) -> anyhow::Result<()> {
+        self.routed_engine(&trade.instrument_id)?
+            .process_trade_tick(trade);
+        Ok(())
+    }
+
+    pub fn process_bar(&mut self, bar: &Bar) -> anyhow::Result<()> {
+        self.routed_engine(&bar.bar_type.instrument_id())?
+            .process_bar(bar);
+        Ok(())
+    }
+
+    /// Iterates all engines in the pool at `timestamp_ns`.
+    pub fn iterate(&mut self, timestamp_ns: UnixNanos) {
+        for engine in self.engines.values_mut() {
+            engine.iterate(timestamp_ns);
+        }
+    }
+
+    /// Resets all engines in the pool.
+    pub fn reset(&mut self) {
+        for engine in self.engines.values_mut() {
+            engine.reset();
+        }
+    }
+
+    // -- AGGREGATE STATS -------------------------------------------------------------------------
+
+    /// Returns the number of orders submitted across all engines.
+    #[must_use]
+    pub fn submitted_count(&self) -> usize {
+        self.engines
+            .values()
+            .map(OrderMatchingEngine::submitted_count)
+            .sum()
+    }
+
+    /// Returns the number of submitted orders which were completely filled across all engines.
+    #[must_use]
+    pub fn filled_count(&self) -> usize {
+        self.engines
+            .values()
+            .map(OrderMatchingEngine::filled_count)
+            .sum()
+    }
+
+    /// Returns the number of submitted orders which were rejected across all engines.
+    #[must_use]
+    pub fn rejected_count(&self) -> usize {
+        self.engines
+            .values()
+            .map(OrderMatchingEngine::rejected_count)
+            .sum()
+    }
+
+    /// Returns the ratio of submitted orders which were completely filled across all engines.
+    #[must_use]
+    pub fn fill_ratio(&self) -> f64 {
+        match self.submitted_count() {
+            0 => 0.0,
+            submitted_count => self.filled_count() as f64 / submitted_count as f64,
+        }
+    }
+
+    /// Returns the ratio of submitted orders which were rejected across all engines.
+    #[must_use]
+    pub fn rejection_ratio(&self) -> f64 {
+        match self.submitted_count() {
+            0 => 0.0,
+            submitted_count => self.rejected_count() as f64 / submitted_count as f64,
+        }
+    }
+}
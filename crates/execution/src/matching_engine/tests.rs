@@ -26,33 +26,60 @@ use nautilus_common::{
 };
 use nautilus_core::{AtomicTime, UUID4, UnixNanos};
 use nautilus_model::{
-    data::{BookOrder, TradeTick, stubs::OrderBookDeltaTestBuilder},
+    accounts::{AccountAny, stubs::cash_account_million_usd},
+    data::{
+        BookOrder, DEPTH10_LEN, OrderBookDelta, OrderBookDeltas, OrderBookDepth10, QuoteTick,
+        TradeTick, stubs::OrderBookDeltaTestBuilder,
+    },
     enums::{
-        AccountType, AggressorSide, BookAction, BookType, ContingencyType, LiquiditySide, OmsType,
-        OrderSide, OrderType, TimeInForce,
+        AccountType, AggressorSide, BookAction, BookType, ContingencyType, LiquiditySide,
+        MarketStatus, MarketStatusAction, ModifyRejectReason, OmsType, OrderSide, OrderStatus,
+        OrderType, RecordFlag, SelfTradePrevention, TimeInForce, TriggerType,
     },
     events::{
-        OrderEventAny, OrderEventType, OrderFilled, OrderRejected,
-        order::rejected::OrderRejectedBuilder,
+        OrderEventAny, OrderEventType, OrderExpired, OrderFilled, OrderRejected,
+        account::stubs::cash_account_state_million_usd, order::rejected::OrderRejectedBuilder,
     },
     identifiers::{
-        AccountId, ClientId, ClientOrderId, InstrumentId, PositionId, StrategyId, TradeId,
-        TraderId, VenueOrderId, stubs::account_id,
+        AccountId, ClientId, ClientOrderId, InstrumentId, OrderListId, PositionId, StrategyId,
+        Symbol, TradeId, TraderId, Venue, VenueOrderId, stubs::account_id,
     },
     instruments::{
         CryptoPerpetual, Equity, InstrumentAny,
-        stubs::{crypto_perpetual_ethusdt, equity_aapl, futures_contract_es},
+        stubs::{
+            crypto_perpetual_ethusdt, default_fx_ccy, equity_aapl, futures_contract_es,
+            xbtusd_bitmex,
+        },
+    },
+    orderbook::OrderBook,
+    orders::{
+        OrderAny, OrderTestBuilder,
+        stubs::{TestOrderEventStubs, TestOrderStubs},
     },
-    orders::{OrderAny, OrderTestBuilder, stubs::TestOrderStubs},
-    types::{Price, Quantity},
+    position::Position,
+    types::{Currency, Money, Price, Quantity, fixed::FIXED_PRECISION},
 };
 use rstest::{fixture, rstest};
 use ustr::Ustr;
 
+#[cfg(feature = "export")]
+use crate::matching_engine::export::FillsExportFormat;
 use crate::{
-    matching_engine::{config::OrderMatchingEngineConfig, engine::OrderMatchingEngine},
+    matching_engine::{
+        config::{
+            CancelAllOrdering, LockBehavior, OrderMatchingEngineConfig, PastExpireTimeHandling,
+        },
+        engine::{BookChanged, OrderMatchingEngine},
+        pool::MatchingEnginePool,
+    },
     messages::{BatchCancelOrders, CancelAllOrders, CancelOrder, ModifyOrder},
-    models::{fee::FeeModelAny, fill::FillModel},
+    models::{
+        fee::FeeModelAny,
+        fill::FillModel,
+        latency::LatencyModel,
+        queue::{DecayQueueModel, QueueModelAny},
+        tick::{TickSchemeAny, TieredTickScheme},
+    },
 };
 
 static ATOMIC_TIME: LazyLock<AtomicTime> =
@@ -115,6 +142,7 @@ pub fn market_order_fill(
         false,
         Some(PositionId::new("P-1")),
         None,
+        None,
     )
 }
 
@@ -157,6 +185,7 @@ fn engine_config() -> OrderMatchingEngineConfig {
         use_position_ids: false,
         use_random_ids: false,
         use_reduce_only: true,
+        ..Default::default()
     }
 }
 // -- HELPERS ---------------------------------------------------------------------------
@@ -247,6 +276,95 @@ fn test_process_order_when_instrument_already_expired(
     );
 }
 
+#[rstest]
+fn test_process_order_within_expiry_grace_period(
+    mut msgbus: MessageBus,
+    order_event_handler: ShareableMessageHandler,
+    account_id: AccountId,
+) {
+    // Expires in one hour, within the one day grace period
+    let expiration = UnixNanos::from(ATOMIC_TIME.get_time_ns().as_u64() + 3_600_000_000_000);
+    let instrument = InstrumentAny::FuturesContract(futures_contract_es(None, Some(expiration)));
+    msgbus.register(
+        msgbus.switchboard.exec_engine_process,
+        order_event_handler.clone(),
+    );
+    let cache = Rc::new(RefCell::new(Cache::default()));
+    let config = OrderMatchingEngineConfig::default()
+        .with_expiry_grace_period_nanos(Some(86_400_000_000_000));
+    let mut engine = get_order_matching_engine(
+        instrument.clone(),
+        Rc::new(RefCell::new(msgbus)),
+        Some(cache.clone()),
+        None,
+        Some(config),
+    );
+
+    // Open a long position of 1 contract
+    let opening_order = OrderTestBuilder::new(OrderType::Market)
+        .instrument_id(instrument.id())
+        .side(OrderSide::Buy)
+        .quantity(Quantity::from(1))
+        .build();
+    let position_id =
+        PositionId::new(format!("{}-{}", instrument.id(), opening_order.strategy_id()).as_str());
+    let fill = TestOrderEventStubs::order_filled(
+        &opening_order,
+        &instrument,
+        None,
+        Some(position_id),
+        Some(Price::from("4500.00")),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+    );
+    let OrderEventAny::Filled(fill) = fill else {
+        panic!("Expected `OrderFilled` event");
+    };
+    cache
+        .borrow_mut()
+        .add_position(Position::new(&instrument, fill), OmsType::Netting)
+        .unwrap();
+
+    let mut closing_order = OrderTestBuilder::new(OrderType::Limit)
+        .instrument_id(instrument.id())
+        .client_order_id(ClientOrderId::from("O-19700101-000000-001-001-2"))
+        .side(OrderSide::Sell)
+        .price(Price::from("4600.00"))
+        .quantity(Quantity::from(1))
+        .build();
+    let mut increasing_order = OrderTestBuilder::new(OrderType::Limit)
+        .instrument_id(instrument.id())
+        .client_order_id(ClientOrderId::from("O-19700101-000000-001-001-3"))
+        .side(OrderSide::Buy)
+        .price(Price::from("4400.00"))
+        .quantity(Quantity::from(1))
+        .build();
+    engine.process_order(&mut closing_order, account_id);
+    engine.process_order(&mut increasing_order, account_id);
+
+    let saved_messages = get_order_event_handler_messages(order_event_handler);
+    assert_eq!(saved_messages.len(), 2);
+    assert_eq!(saved_messages[0].event_type(), OrderEventType::Accepted);
+    assert_eq!(
+        saved_messages[0].client_order_id(),
+        closing_order.client_order_id()
+    );
+    assert_eq!(saved_messages[1].event_type(), OrderEventType::Rejected);
+    assert_eq!(
+        saved_messages[1].message().unwrap(),
+        Ustr::from(
+            format!(
+                "Contract ESZ21.GLBX is within the expiry grace period, only reducing orders accepted: expiration {expiration}"
+            )
+            .as_str()
+        )
+    );
+}
+
 #[rstest]
 fn test_process_order_when_instrument_not_active(
     mut msgbus: MessageBus,
@@ -292,6 +410,56 @@ fn test_process_order_when_instrument_not_active(
     );
 }
 
+#[rstest]
+#[case(MarketStatusAction::Suspend, "SUSPENDED")]
+#[case(MarketStatusAction::NotAvailableForTrading, "NOT_AVAILABLE")]
+fn test_process_order_when_instrument_temporarily_inactive(
+    mut msgbus: MessageBus,
+    order_event_handler: ShareableMessageHandler,
+    account_id: AccountId,
+    instrument_eth_usdt: InstrumentAny,
+    #[case] action: MarketStatusAction,
+    #[case] expected_status: &str,
+) {
+    msgbus.register(
+        msgbus.switchboard.exec_engine_process,
+        order_event_handler.clone(),
+    );
+    let config = OrderMatchingEngineConfig::default().with_reject_orders_when_inactive(true);
+    let mut engine = get_order_matching_engine(
+        instrument_eth_usdt.clone(),
+        Rc::new(RefCell::new(msgbus)),
+        None,
+        None,
+        Some(config),
+    );
+
+    // Market goes under maintenance
+    engine.process_status(action);
+    let mut limit_order = OrderTestBuilder::new(OrderType::Limit)
+        .instrument_id(instrument_eth_usdt.id())
+        .side(OrderSide::Buy)
+        .price(Price::from("1500.00"))
+        .quantity(Quantity::from("1.000"))
+        .build();
+    engine.process_order(&mut limit_order, account_id);
+
+    let saved_messages = get_order_event_handler_messages(order_event_handler);
+    assert_eq!(saved_messages.len(), 1);
+    let first_message = saved_messages.first().unwrap();
+    assert_eq!(first_message.event_type(), OrderEventType::Rejected);
+    assert_eq!(
+        first_message.message().unwrap(),
+        Ustr::from(&format!(
+            "Instrument ETHUSDT-PERP.BINANCE is temporarily inactive, market status {expected_status}"
+        ))
+    );
+
+    // Market returns to trading after maintenance
+    engine.process_status(MarketStatusAction::Trading);
+    assert_eq!(engine.market_status, MarketStatus::Open);
+}
+
 #[rstest]
 fn test_process_order_when_invalid_quantity_precision(
     mut msgbus: MessageBus,
@@ -423,6 +591,172 @@ fn test_process_order_when_invalid_trigger_price_precision(
     );
 }
 
+#[rstest]
+fn test_process_order_rounds_trigger_price_to_tick(
+    mut msgbus: MessageBus,
+    order_event_handler: ShareableMessageHandler,
+    account_id: AccountId,
+    instrument_es: InstrumentAny,
+) {
+    msgbus.register(
+        msgbus.switchboard.exec_engine_process,
+        order_event_handler.clone(),
+    );
+    let config = OrderMatchingEngineConfig::default().with_round_prices_to_tick(true);
+    let mut engine = get_order_matching_engine(
+        instrument_es.clone(),
+        Rc::new(RefCell::new(msgbus)),
+        None,
+        None,
+        Some(config),
+    );
+    let mut stop_order = OrderTestBuilder::new(OrderType::StopMarket)
+        .instrument_id(instrument_es.id())
+        .side(OrderSide::Sell)
+        .trigger_price(Price::from("100.12333"))
+        .quantity(Quantity::from("1"))
+        .build();
+
+    engine.process_order(&mut stop_order, account_id);
+
+    let saved_messages = get_order_event_handler_messages(order_event_handler);
+    let event_types: Vec<OrderEventType> = saved_messages
+        .iter()
+        .map(OrderEventAny::event_type)
+        .collect();
+    assert_eq!(
+        event_types,
+        vec![OrderEventType::Updated, OrderEventType::Accepted]
+    );
+    let OrderEventAny::Updated(order_updated) = &saved_messages[0] else {
+        panic!("Expected `OrderUpdated` event");
+    };
+    assert_eq!(order_updated.trigger_price, Some(Price::from("100.12")));
+    assert_eq!(order_updated.price, None);
+    assert_eq!(stop_order.trigger_price(), Some(Price::from("100.12")));
+}
+
+#[rstest]
+fn test_process_order_rounds_price_to_tick_scheme(
+    mut msgbus: MessageBus,
+    order_event_handler: ShareableMessageHandler,
+    account_id: AccountId,
+    instrument_eth_usdt: InstrumentAny,
+) {
+    msgbus.register(
+        msgbus.switchboard.exec_engine_process,
+        order_event_handler.clone(),
+    );
+    let config = OrderMatchingEngineConfig::default().with_round_prices_to_tick(true);
+    let mut engine = get_order_matching_engine(
+        instrument_eth_usdt.clone(),
+        Rc::new(RefCell::new(msgbus)),
+        None,
+        None,
+        Some(config),
+    );
+    // Ticks of 0.01 below 1000.00 and 0.50 from 1000.00
+    let tick_scheme = TieredTickScheme::new(vec![
+        (Price::from("0.00"), Price::from("0.01")),
+        (Price::from("1000.00"), Price::from("0.50")),
+    ])
+    .unwrap();
+    engine.set_tick_scheme(TickSchemeAny::Tiered(tick_scheme));
+    let mut limit_order = OrderTestBuilder::new(OrderType::Limit)
+        .instrument_id(instrument_eth_usdt.id())
+        .side(OrderSide::Buy)
+        .price(Price::from("1500.2234"))
+        .quantity(Quantity::from("1.000"))
+        .build();
+
+    engine.process_order(&mut limit_order, account_id);
+
+    // Rounded to the 0.50 tick of its price band, rather than rejected as off tick
+    let saved_messages = get_order_event_handler_messages(order_event_handler);
+    let event_types: Vec<OrderEventType> = saved_messages
+        .iter()
+        .map(OrderEventAny::event_type)
+        .collect();
+    assert_eq!(
+        event_types,
+        vec![OrderEventType::Updated, OrderEventType::Accepted]
+    );
+    assert_eq!(limit_order.price(), Some(Price::from("1500.00")));
+}
+
+#[rstest]
+#[case::quantity(OrderType::Market, ("1.0", None, None), ("quantity", 1, "size", 0))]
+#[case::price(OrderType::Limit, ("1", Some("100.123"), None), ("price", 3, "price", 2))]
+#[case::trigger_price(
+    OrderType::StopMarket,
+    ("1", None, Some("100.1")),
+    ("trigger price", 1, "price", 2)
+)]
+fn test_process_order_invalid_precision_reasons_share_format(
+    mut msgbus: MessageBus,
+    order_event_handler: ShareableMessageHandler,
+    account_id: AccountId,
+    instrument_es: InstrumentAny,
+    #[case] order_type: OrderType,
+    #[case] order_values: (&str, Option<&str>, Option<&str>),
+    #[case] expected: (&str, u8, &str, u8),
+) {
+    let (quantity, price, trigger_price) = order_values;
+    let (
+        expected_field,
+        expected_precision,
+        expected_instrument_field,
+        expected_instrument_precision,
+    ) = expected;
+    msgbus.register(
+        msgbus.switchboard.exec_engine_process,
+        order_event_handler.clone(),
+    );
+    let mut engine = get_order_matching_engine(
+        instrument_es.clone(),
+        Rc::new(RefCell::new(msgbus)),
+        None,
+        None,
+        None,
+    );
+    let mut builder = OrderTestBuilder::new(order_type);
+    builder
+        .instrument_id(instrument_es.id())
+        .side(OrderSide::Sell)
+        .quantity(Quantity::from(quantity));
+    if let Some(price) = price {
+        builder.price(Price::from(price));
+    }
+    if let Some(trigger_price) = trigger_price {
+        builder.trigger_price(Price::from(trigger_price));
+    }
+    let mut order = builder.build();
+
+    engine.process_order(&mut order, account_id);
+
+    let saved_messages = get_order_event_handler_messages(order_event_handler);
+    assert_eq!(saved_messages.len(), 1);
+    let reason = saved_messages.first().unwrap().message().unwrap();
+
+    // Parse "Invalid order {field} precision for order {id}, was {n} when {instrument} {field} precision is {n}"
+    let rest = reason.as_str().strip_prefix("Invalid order ").unwrap();
+    let (field, rest) = rest.split_once(" precision for order ").unwrap();
+    let (client_order_id, rest) = rest.split_once(", was ").unwrap();
+    let (precision, rest) = rest.split_once(" when ").unwrap();
+    let (instrument, rest) = rest.split_once(' ').unwrap();
+    let (instrument_field, instrument_precision) = rest.split_once(" precision is ").unwrap();
+
+    assert_eq!(field, expected_field);
+    assert_eq!(client_order_id, order.client_order_id().as_str());
+    assert_eq!(precision.parse::<u8>().unwrap(), expected_precision);
+    assert_eq!(instrument, instrument_es.id().to_string());
+    assert_eq!(instrument_field, expected_instrument_field);
+    assert_eq!(
+        instrument_precision.parse::<u8>().unwrap(),
+        expected_instrument_precision
+    );
+}
+
 #[rstest]
 fn test_process_order_when_shorting_equity_without_margin_account(
     mut msgbus: MessageBus,
@@ -508,6 +842,54 @@ fn test_process_order_when_invalid_reduce_only(
     );
 }
 
+#[rstest]
+#[case::reject(false, OrderEventType::Rejected)]
+#[case::cancel(true, OrderEventType::Canceled)]
+fn test_process_order_reduce_only_when_flat(
+    mut msgbus: MessageBus,
+    order_event_handler: ShareableMessageHandler,
+    account_id: AccountId,
+    instrument_eth_usdt: InstrumentAny,
+    engine_config: OrderMatchingEngineConfig,
+    #[case] cancel_reduce_only_when_flat: bool,
+    #[case] expected_event_type: OrderEventType,
+) {
+    msgbus.register(
+        msgbus.switchboard.exec_engine_process,
+        order_event_handler.clone(),
+    );
+    let config = engine_config.with_cancel_reduce_only_when_flat(cancel_reduce_only_when_flat);
+    let mut engine = get_order_matching_engine(
+        instrument_eth_usdt.clone(),
+        Rc::new(RefCell::new(msgbus)),
+        None,
+        None,
+        Some(config),
+    );
+    let mut market_order_reduce = OrderTestBuilder::new(OrderType::Market)
+        .instrument_id(instrument_eth_usdt.id())
+        .side(OrderSide::Sell)
+        .quantity(Quantity::from("1.000"))
+        .reduce_only(true)
+        .build();
+
+    engine.process_order(&mut market_order_reduce, account_id);
+
+    // No position exists, so the reduce-only order is never filled
+    let saved_messages = get_order_event_handler_messages(order_event_handler);
+    assert!(
+        saved_messages
+            .iter()
+            .all(|event| event.event_type() != OrderEventType::Filled)
+    );
+    let last_message = saved_messages.last().unwrap();
+    assert_eq!(last_message.event_type(), expected_event_type);
+    assert_eq!(
+        last_message.client_order_id(),
+        market_order_reduce.client_order_id()
+    );
+}
+
 #[rstest]
 fn test_process_order_when_invalid_contingent_orders(
     mut msgbus: MessageBus,
@@ -660,37 +1042,122 @@ fn test_process_order_when_closed_linked_order(
 }
 
 #[rstest]
-fn test_process_market_order_no_market_rejected(
+fn test_oco_fill_cancels_linked_order_with_reason(
     mut msgbus: MessageBus,
     order_event_handler: ShareableMessageHandler,
     account_id: AccountId,
     instrument_eth_usdt: InstrumentAny,
-    mut market_order_buy: OrderAny,
-    mut market_order_sell: OrderAny,
+    engine_config: OrderMatchingEngineConfig,
 ) {
-    // Register saving message handler to exec engine endpoint
     msgbus.register(
         msgbus.switchboard.exec_engine_process,
         order_event_handler.clone(),
     );
-
-    // Create engine and process order
-    let mut engine = get_order_matching_engine(
-        instrument_eth_usdt,
+    let cache = Rc::new(RefCell::new(Cache::default()));
+    let mut engine_l2 = get_order_matching_engine_l2(
+        instrument_eth_usdt.clone(),
         Rc::new(RefCell::new(msgbus)),
+        Some(cache.clone()),
         None,
-        None,
-        None,
+        Some(engine_config),
     );
 
-    engine.process_order(&mut market_order_buy, account_id);
-    engine.process_order(&mut market_order_sell, account_id);
+    let buy_client_order_id = ClientOrderId::from("O-19700101-000000-001-001-1");
+    let sell_client_order_id = ClientOrderId::from("O-19700101-000000-001-001-2");
+    let mut buy_order = OrderTestBuilder::new(OrderType::Limit)
+        .instrument_id(instrument_eth_usdt.id())
+        .side(OrderSide::Buy)
+        .price(Price::from("1490.00"))
+        .quantity(Quantity::from("1.000"))
+        .contingency_type(ContingencyType::Oco)
+        .client_order_id(buy_client_order_id)
+        .linked_order_ids(vec![sell_client_order_id])
+        .build();
+    let mut sell_order = OrderTestBuilder::new(OrderType::Limit)
+        .instrument_id(instrument_eth_usdt.id())
+        .side(OrderSide::Sell)
+        .price(Price::from("1510.00"))
+        .quantity(Quantity::from("1.000"))
+        .contingency_type(ContingencyType::Oco)
+        .client_order_id(sell_client_order_id)
+        .linked_order_ids(vec![buy_client_order_id])
+        .build();
+    for order in [&buy_order, &sell_order] {
+        cache
+            .borrow_mut()
+            .add_order(order.clone(), None, None, false)
+            .unwrap();
+    }
+    engine_l2.process_order(&mut buy_order, account_id);
+    engine_l2.process_order(&mut sell_order, account_id);
+    cache.borrow_mut().update_order(&buy_order).unwrap();
+
+    // Bid moves through the sell order, which fills and cancels its linked buy order
+    let orderbook_delta_buy = OrderBookDeltaTestBuilder::new(instrument_eth_usdt.id())
+        .book_action(BookAction::Add)
+        .book_order(BookOrder::new(
+            OrderSide::Buy,
+            Price::from("1511.00"),
+            Quantity::from("1.000"),
+            1,
+        ))
+        .build();
+    engine_l2.process_order_book_delta(&orderbook_delta_buy);
 
-    // Get messages and test
     let saved_messages = get_order_event_handler_messages(order_event_handler);
-    assert_eq!(saved_messages.len(), 2);
-    let first = saved_messages.first().unwrap();
-    let second = saved_messages.get(1).unwrap();
+    let event_types: Vec<OrderEventType> = saved_messages
+        .iter()
+        .map(OrderEventAny::event_type)
+        .collect();
+    assert_eq!(
+        event_types,
+        vec![
+            OrderEventType::Accepted,
+            OrderEventType::Accepted,
+            OrderEventType::Filled,
+            OrderEventType::Canceled,
+        ]
+    );
+    let order_canceled = saved_messages.last().unwrap();
+    assert_eq!(order_canceled.client_order_id(), buy_client_order_id);
+    assert_eq!(
+        order_canceled.message().unwrap(),
+        Ustr::from(&format!("Canceled by linked order {sell_client_order_id}"))
+    );
+}
+
+#[rstest]
+fn test_process_market_order_no_market_rejected(
+    mut msgbus: MessageBus,
+    order_event_handler: ShareableMessageHandler,
+    account_id: AccountId,
+    instrument_eth_usdt: InstrumentAny,
+    mut market_order_buy: OrderAny,
+    mut market_order_sell: OrderAny,
+) {
+    // Register saving message handler to exec engine endpoint
+    msgbus.register(
+        msgbus.switchboard.exec_engine_process,
+        order_event_handler.clone(),
+    );
+
+    // Create engine and process order
+    let mut engine = get_order_matching_engine(
+        instrument_eth_usdt,
+        Rc::new(RefCell::new(msgbus)),
+        None,
+        None,
+        None,
+    );
+
+    engine.process_order(&mut market_order_buy, account_id);
+    engine.process_order(&mut market_order_sell, account_id);
+
+    // Get messages and test
+    let saved_messages = get_order_event_handler_messages(order_event_handler);
+    assert_eq!(saved_messages.len(), 2);
+    let first = saved_messages.first().unwrap();
+    let second = saved_messages.get(1).unwrap();
     assert_eq!(first.event_type(), OrderEventType::Rejected);
     assert_eq!(second.event_type(), OrderEventType::Rejected);
     assert_eq!(
@@ -703,6 +1170,85 @@ fn test_process_market_order_no_market_rejected(
     );
 }
 
+#[rstest]
+fn test_process_market_order_no_market_fills_at_reference_price(
+    mut msgbus: MessageBus,
+    order_event_handler: ShareableMessageHandler,
+    account_id: AccountId,
+    instrument_eth_usdt: InstrumentAny,
+    mut market_order_buy: OrderAny,
+) {
+    msgbus.register(
+        msgbus.switchboard.exec_engine_process,
+        order_event_handler.clone(),
+    );
+
+    let config = OrderMatchingEngineConfig::default()
+        .with_no_market_reference_price(Some(Price::from("1500.00")));
+    let mut engine = get_order_matching_engine(
+        instrument_eth_usdt,
+        Rc::new(RefCell::new(msgbus)),
+        None,
+        None,
+        Some(config),
+    );
+
+    engine.process_order(&mut market_order_buy, account_id);
+
+    let saved_messages = get_order_event_handler_messages(order_event_handler);
+    assert_eq!(saved_messages.len(), 1);
+    let OrderEventAny::Filled(order_filled) = &saved_messages[0] else {
+        panic!("Expected `OrderFilled` event");
+    };
+    assert_eq!(order_filled.last_px, Price::from("1500.00"));
+    assert_eq!(order_filled.last_qty, market_order_buy.quantity());
+    assert_eq!(order_filled.liquidity_side, LiquiditySide::Taker);
+}
+
+#[rstest]
+fn test_process_order_book_delta_zero_size_removes_level(
+    instrument_eth_usdt: InstrumentAny,
+    msgbus: MessageBus,
+) {
+    let mut engine_l2 = get_order_matching_engine_l2(
+        instrument_eth_usdt.clone(),
+        Rc::new(RefCell::new(msgbus)),
+        None,
+        None,
+        None,
+    );
+    let bid_delta = |action: BookAction, price: &str| {
+        OrderBookDeltaTestBuilder::new(instrument_eth_usdt.id())
+            .book_action(action)
+            .book_order(BookOrder::new(
+                OrderSide::Buy,
+                Price::from(price),
+                Quantity::from("1.000"),
+                1,
+            ))
+            .build()
+    };
+    // Zero-size deltas fail construction checks, but may still arrive from decoded data
+    let zero_size_bid_delta = |action: BookAction, price: &str| {
+        let mut delta = bid_delta(action, price);
+        delta.order.size = Quantity::from("0.000");
+        delta
+    };
+    engine_l2.process_order_book_delta(&bid_delta(BookAction::Add, "1494.00"));
+    engine_l2.process_order_book_delta(&bid_delta(BookAction::Add, "1495.00"));
+    assert_eq!(engine_l2.best_bid_price(), Some(Price::from("1495.00")));
+
+    // Zero-size update removes the level, exposing the next level as top-of-book
+    engine_l2.process_order_book_delta(&zero_size_bid_delta(BookAction::Update, "1495.00"));
+    assert_eq!(engine_l2.best_bid_price(), Some(Price::from("1494.00")));
+    assert_eq!(engine_l2.core.bid, Some(Price::from("1494.00")));
+
+    // Zero-size add does not create a level
+    engine_l2.process_order_book_delta(&zero_size_bid_delta(BookAction::Add, "1496.00"));
+    assert_eq!(engine_l2.best_bid_price(), Some(Price::from("1494.00")));
+    assert_eq!(engine_l2.core.bid, Some(Price::from("1494.00")));
+}
+
 #[rstest]
 fn test_bid_ask_initialized(msgbus: MessageBus, instrument_es: InstrumentAny) {
     let mut engine_l2 = get_order_matching_engine_l2(
@@ -868,6 +1414,165 @@ fn test_valid_market_buy(
     assert_eq!(order_filled_second.last_qty, Quantity::from("1.000"));
 }
 
+#[rstest]
+fn test_fill_callback_invoked_for_market_order(
+    instrument_eth_usdt: InstrumentAny,
+    msgbus: MessageBus,
+    account_id: AccountId,
+) {
+    let mut engine_l2 = get_order_matching_engine_l2(
+        instrument_eth_usdt.clone(),
+        Rc::new(RefCell::new(msgbus)),
+        None,
+        None,
+        None,
+    );
+    let fills: Rc<RefCell<Vec<OrderFilled>>> = Rc::new(RefCell::new(Vec::new()));
+    let fills_clone = fills.clone();
+    engine_l2.on_fill(Box::new(move |fill: &OrderFilled| {
+        fills_clone.borrow_mut().push(*fill);
+    }));
+
+    let book_order = BookOrder::new(
+        OrderSide::Sell,
+        Price::from("1500.00"),
+        Quantity::from("1.000"),
+        1,
+    );
+    let orderbook_delta_sell = OrderBookDeltaTestBuilder::new(instrument_eth_usdt.id())
+        .book_action(BookAction::Add)
+        .book_order(book_order)
+        .build();
+    let client_order_id = ClientOrderId::from("O-19700101-000000-001-001-1");
+    let mut market_order = OrderTestBuilder::new(OrderType::Market)
+        .instrument_id(instrument_eth_usdt.id())
+        .side(OrderSide::Buy)
+        .quantity(Quantity::from("1.000"))
+        .client_order_id(client_order_id)
+        .build();
+
+    engine_l2.process_order_book_delta(&orderbook_delta_sell);
+    engine_l2.process_order(&mut market_order, account_id);
+
+    let fills = fills.borrow();
+    assert_eq!(fills.len(), 1);
+    let fill = fills.first().unwrap();
+    assert_eq!(fill.client_order_id, client_order_id);
+    assert_eq!(fill.order_side, OrderSide::Buy);
+    assert_eq!(fill.last_px, Price::from("1500.00"));
+    assert_eq!(fill.last_qty, Quantity::from("1.000"));
+    assert_eq!(fill.liquidity_side, LiquiditySide::Taker);
+}
+
+#[rstest]
+fn test_reject_callback_invoked_for_market_order_with_no_market(
+    instrument_eth_usdt: InstrumentAny,
+    msgbus: MessageBus,
+    account_id: AccountId,
+    mut market_order_buy: OrderAny,
+) {
+    let mut engine = get_order_matching_engine(
+        instrument_eth_usdt,
+        Rc::new(RefCell::new(msgbus)),
+        None,
+        None,
+        None,
+    );
+    let rejects: Rc<RefCell<Vec<OrderRejected>>> = Rc::new(RefCell::new(Vec::new()));
+    let rejects_clone = rejects.clone();
+    engine.on_reject(Box::new(move |rejected: &OrderRejected| {
+        rejects_clone.borrow_mut().push(*rejected);
+    }));
+
+    engine.process_order(&mut market_order_buy, account_id);
+
+    let rejects = rejects.borrow();
+    assert_eq!(rejects.len(), 1);
+    assert_eq!(
+        rejects[0].client_order_id,
+        market_order_buy.client_order_id()
+    );
+    assert_eq!(
+        rejects[0].reason,
+        Ustr::from("No market for ETHUSDT-PERP.BINANCE")
+    );
+}
+
+#[rstest]
+fn test_unrealized_pnl_marked_to_midpoint(
+    instrument_eth_usdt: InstrumentAny,
+    msgbus: MessageBus,
+    market_order_buy: OrderAny,
+) {
+    let cache = Rc::new(RefCell::new(Cache::default()));
+    let mut engine = get_order_matching_engine(
+        instrument_eth_usdt.clone(),
+        Rc::new(RefCell::new(msgbus)),
+        Some(cache.clone()),
+        None,
+        None,
+    );
+    let instrument_id = instrument_eth_usdt.id();
+
+    // No market yet
+    assert_eq!(engine.unrealized_pnl(instrument_id), None);
+
+    // Open a 1.000 ETH long position at 1500.00
+    let fill = TestOrderEventStubs::order_filled(
+        &market_order_buy,
+        &instrument_eth_usdt,
+        None,
+        None,
+        Some(Price::from("1500.00")),
+        Some(Quantity::from("1.000")),
+        None,
+        Some(Money::from("0 USDT")),
+        None,
+        None,
+        None,
+    );
+    let OrderEventAny::Filled(fill) = fill else {
+        panic!("Expected `OrderFilled` event");
+    };
+    let position = Position::new(&instrument_eth_usdt, fill);
+    cache
+        .borrow_mut()
+        .add_position(position, OmsType::Netting)
+        .unwrap();
+
+    engine.process_quote_tick(&QuoteTick::new(
+        instrument_id,
+        Price::from("1499.50"),
+        Price::from("1500.50"),
+        Quantity::from("10.000"),
+        Quantity::from("10.000"),
+        UnixNanos::from(1),
+        UnixNanos::from(1),
+    ));
+    assert_eq!(
+        engine.unrealized_pnl(instrument_id),
+        Some(Money::from("0 USDT"))
+    );
+
+    // Move the market up, midpoint is 1512.25
+    engine.process_quote_tick(&QuoteTick::new(
+        instrument_id,
+        Price::from("1512.00"),
+        Price::from("1512.50"),
+        Quantity::from("10.000"),
+        Quantity::from("10.000"),
+        UnixNanos::from(2),
+        UnixNanos::from(2),
+    ));
+    assert_eq!(
+        engine.unrealized_pnl(instrument_id),
+        Some(Money::from("12.25 USDT"))
+    );
+
+    // Other instruments are not matched by this engine
+    assert_eq!(engine.unrealized_pnl(InstrumentId::from("AAPL.XNAS")), None);
+}
+
 #[rstest]
 fn test_process_limit_post_only_order_that_would_be_a_taker(
     instrument_eth_usdt: InstrumentAny,
@@ -1171,6 +1876,68 @@ fn test_process_stop_market_order_valid_trigger_filled(
     assert_eq!(order_filled.last_qty, Quantity::from("1.000"));
 }
 
+#[rstest]
+#[case(false, "1510.00")]
+#[case(true, "1505.00")]
+fn test_stop_market_order_gapped_through_fill_price(
+    instrument_eth_usdt: InstrumentAny,
+    mut msgbus: MessageBus,
+    order_event_handler: ShareableMessageHandler,
+    account_id: AccountId,
+    #[case] stop_fills_at_trigger_price: bool,
+    #[case] expected_fill_px: &str,
+) {
+    msgbus.register(
+        msgbus.switchboard.exec_engine_process,
+        order_event_handler.clone(),
+    );
+    let config = OrderMatchingEngineConfig::default()
+        .with_stop_fills_at_trigger_price(stop_fills_at_trigger_price);
+    let mut engine_l2 = get_order_matching_engine_l2(
+        instrument_eth_usdt.clone(),
+        Rc::new(RefCell::new(msgbus)),
+        None,
+        None,
+        Some(config),
+    );
+    let ask_delta = |action: BookAction, price: &str| {
+        OrderBookDeltaTestBuilder::new(instrument_eth_usdt.id())
+            .book_action(action)
+            .book_order(BookOrder::new(
+                OrderSide::Sell,
+                Price::from(price),
+                Quantity::from("10.000"),
+                1,
+            ))
+            .build()
+    };
+    engine_l2.process_order_book_delta(&ask_delta(BookAction::Add, "1500.00"));
+
+    let client_order_id = ClientOrderId::from("O-19700101-000000-001-001-1");
+    let mut stop_order = OrderTestBuilder::new(OrderType::StopMarket)
+        .instrument_id(instrument_eth_usdt.id())
+        .side(OrderSide::Buy)
+        .trigger_price(Price::from("1505.00"))
+        .quantity(Quantity::from("1.000"))
+        .client_order_id(client_order_id)
+        .build();
+    engine_l2.process_order(&mut stop_order, account_id);
+
+    // Market gaps through the trigger, with no liquidity at the trigger price
+    engine_l2.process_order_book_delta(&ask_delta(BookAction::Delete, "1500.00"));
+    engine_l2.process_order_book_delta(&ask_delta(BookAction::Add, "1510.00"));
+
+    let saved_messages = get_order_event_handler_messages(order_event_handler);
+    assert_eq!(saved_messages.len(), 2);
+    assert_eq!(saved_messages[0].event_type(), OrderEventType::Accepted);
+    let OrderEventAny::Filled(order_filled) = &saved_messages[1] else {
+        panic!("Expected `OrderFilled` event");
+    };
+    assert_eq!(order_filled.client_order_id, client_order_id);
+    assert_eq!(order_filled.last_px, Price::from(expected_fill_px));
+    assert_eq!(order_filled.last_qty, Quantity::from("1.000"));
+}
+
 #[rstest]
 fn test_process_stop_market_order_valid_not_triggered_accepted(
     instrument_eth_usdt: InstrumentAny,
@@ -1562,6 +2329,7 @@ fn test_process_cancel_all_command(
         StrategyId::from("STRATEGY-001"),
         instrument_eth_usdt.id(),
         OrderSide::Buy,
+        None,
         UUID4::new(),
         UnixNanos::default(),
     )
@@ -1611,26 +2379,30 @@ fn test_process_cancel_all_command(
 }
 
 #[rstest]
-fn test_process_batch_cancel_command(
+#[case(CancelAllOrdering::ClientOrderId, [1, 2, 3, 4])]
+#[case(CancelAllOrdering::PriceTime, [2, 3, 1, 4])]
+fn test_process_cancel_all_command_deterministic_ordering(
     instrument_eth_usdt: InstrumentAny,
     mut msgbus: MessageBus,
     order_event_handler: ShareableMessageHandler,
     account_id: AccountId,
+    #[case] ordering: CancelAllOrdering,
+    #[case] expected_order: [usize; 4],
 ) {
     msgbus.register(
         msgbus.switchboard.exec_engine_process,
         order_event_handler.clone(),
     );
     let cache = Rc::new(RefCell::new(Cache::default()));
+    let config = OrderMatchingEngineConfig::default().with_cancel_all_ordering(ordering);
     let mut engine_l2 = get_order_matching_engine_l2(
         instrument_eth_usdt.clone(),
         Rc::new(RefCell::new(msgbus)),
-        Some(cache),
-        None,
+        Some(cache.clone()),
         None,
+        Some(config),
     );
 
-    // Add SELL limit orderbook delta to have ask initialized
     let orderbook_delta_sell = OrderBookDeltaTestBuilder::new(instrument_eth_usdt.id())
         .book_action(BookAction::Add)
         .book_order(BookOrder::new(
@@ -1642,92 +2414,61 @@ fn test_process_batch_cancel_command(
         .build();
     engine_l2.process_order_book_delta(&orderbook_delta_sell);
 
-    // Create 2 limits order which will be canceled with batch cancel command
-    let client_order_id_1 = ClientOrderId::from("O-19700101-000000-001-001-1");
-    let mut limit_order_1 = OrderTestBuilder::new(OrderType::Limit)
-        .instrument_id(instrument_eth_usdt.id())
-        .side(OrderSide::Buy)
-        .price(Price::from("1495.00"))
-        .quantity(Quantity::from("1.000"))
-        .client_order_id(client_order_id_1)
-        .build();
-    let client_order_id_2 = ClientOrderId::from("O-19700101-000000-001-001-2");
-    let mut limit_order_2 = OrderTestBuilder::new(OrderType::Limit)
-        .instrument_id(instrument_eth_usdt.id())
-        .side(OrderSide::Buy)
-        .price(Price::from("1496.00"))
-        .quantity(Quantity::from("1.000"))
-        .client_order_id(client_order_id_2)
-        .build();
-
-    engine_l2.process_order(&mut limit_order_1, account_id);
-    engine_l2.process_order(&mut limit_order_2, account_id);
+    // Price-time priority cancels the buys best price first, then the sell
+    let orders = [
+        (OrderSide::Buy, "1495.00"),
+        (OrderSide::Buy, "1497.00"),
+        (OrderSide::Buy, "1496.00"),
+        (OrderSide::Sell, "1505.00"),
+    ];
+    for (i, (side, price)) in orders.into_iter().enumerate() {
+        let mut order = OrderTestBuilder::new(OrderType::Limit)
+            .instrument_id(instrument_eth_usdt.id())
+            .side(side)
+            .price(Price::from(price))
+            .quantity(Quantity::from("1.000"))
+            .client_order_id(ClientOrderId::from(
+                format!("O-19700101-000000-001-001-{}", i + 1).as_str(),
+            ))
+            .build();
+        cache
+            .borrow_mut()
+            .add_order(order.clone(), None, None, false)
+            .unwrap();
+        engine_l2.process_order(&mut order, account_id);
+        cache.borrow_mut().update_order(&order).unwrap();
+    }
 
-    let cancel_1 = CancelOrder::new(
-        TraderId::from("TRADER-001"),
-        ClientId::from("CLIENT-001"),
-        StrategyId::from("STRATEGY-001"),
-        instrument_eth_usdt.id(),
-        client_order_id_1,
-        VenueOrderId::from("V1"),
-        UUID4::new(),
-        UnixNanos::default(),
-    )
-    .unwrap();
-    let cancel_2 = CancelOrder::new(
+    let cancel_all_command = CancelAllOrders::new(
         TraderId::from("TRADER-001"),
         ClientId::from("CLIENT-001"),
         StrategyId::from("STRATEGY-001"),
         instrument_eth_usdt.id(),
-        client_order_id_2,
-        VenueOrderId::from("V2"),
+        OrderSide::NoOrderSide,
+        None,
         UUID4::new(),
         UnixNanos::default(),
     )
     .unwrap();
-    let batch_cancel_command = BatchCancelOrders::new(
-        TraderId::from("TRADER-001"),
-        ClientId::from("CLIENT-001"),
-        StrategyId::from("STRATEGY-001"),
-        instrument_eth_usdt.id(),
-        vec![cancel_1, cancel_2],
-        UUID4::new(),
-        UnixNanos::default(),
-    )
-    .unwrap();
-
-    engine_l2.process_batch_cancel(&batch_cancel_command, account_id);
+    engine_l2.process_cancel_all(&cancel_all_command, account_id);
 
-    // Check we have received 2 OrderAccepted and 2 OrderCanceled events
     let saved_messages = get_order_event_handler_messages(order_event_handler);
-    assert_eq!(saved_messages.len(), 4);
-    let order_event_first = saved_messages.first().unwrap();
-    let order_accepted = match order_event_first {
-        OrderEventAny::Accepted(order_accepted) => order_accepted,
-        _ => panic!("Expected OrderAccepted event in first message"),
-    };
-    assert_eq!(order_accepted.client_order_id, client_order_id_1);
-    let order_event_second = saved_messages.get(1).unwrap();
-    let order_accepted = match order_event_second {
-        OrderEventAny::Accepted(order_accepted) => order_accepted,
-        _ => panic!("Expected OrderAccepted event in second message"),
-    };
-    assert_eq!(order_accepted.client_order_id, client_order_id_2);
-    let order_event_third = saved_messages.get(2).unwrap();
-    let order_canceled_1 = match order_event_third {
-        OrderEventAny::Canceled(order_canceled) => order_canceled,
-        _ => panic!("Expected OrderCanceled event in third message"),
-    };
-    let order_event_fourth = saved_messages.get(3).unwrap();
-    let _order_canceled_2 = match order_event_fourth {
-        OrderEventAny::Canceled(order_canceled) => order_canceled,
-        _ => panic!("Expected OrderCanceled event in fourth message"),
-    };
-    assert_eq!(order_canceled_1.client_order_id, client_order_id_1);
+    let canceled_ids: Vec<ClientOrderId> = saved_messages
+        .iter()
+        .filter_map(|event| match event {
+            OrderEventAny::Canceled(order_canceled) => Some(order_canceled.client_order_id),
+            _ => None,
+        })
+        .collect();
+    let expected_ids: Vec<ClientOrderId> = expected_order
+        .iter()
+        .map(|i| ClientOrderId::from(format!("O-19700101-000000-001-001-{i}").as_str()))
+        .collect();
+    assert_eq!(canceled_ids, expected_ids);
 }
 
 #[rstest]
-fn test_expire_order(
+fn test_process_cancel_all_command_filtered_by_order_type(
     instrument_eth_usdt: InstrumentAny,
     mut msgbus: MessageBus,
     order_event_handler: ShareableMessageHandler,
@@ -1737,19 +2478,15 @@ fn test_expire_order(
         msgbus.switchboard.exec_engine_process,
         order_event_handler.clone(),
     );
-
-    // Create order matching engine with gtd support
-    let mut engine_config = OrderMatchingEngineConfig::default();
-    engine_config.support_gtd_orders = true;
+    let cache = Rc::new(RefCell::new(Cache::default()));
     let mut engine_l2 = get_order_matching_engine_l2(
         instrument_eth_usdt.clone(),
         Rc::new(RefCell::new(msgbus)),
+        Some(cache.clone()),
         None,
         None,
-        Some(engine_config),
     );
 
-    // Add SELL limit orderbook delta to have ask initialized
     let orderbook_delta_sell = OrderBookDeltaTestBuilder::new(instrument_eth_usdt.id())
         .book_action(BookAction::Add)
         .book_order(BookOrder::new(
@@ -1761,107 +2498,153 @@ fn test_expire_order(
         .build();
     engine_l2.process_order_book_delta(&orderbook_delta_sell);
 
-    // Create GTD LIMIT order which will expire after we process tick
-    // that has higher timestamp than expire_time.
-    let client_order_id = ClientOrderId::from("O-19700101-000000-001-001-1");
-    let expire_time = DateTime::parse_from_rfc3339("2019-10-23T10:32:49.669Z")
-        .unwrap()
-        .with_timezone(&Utc)
-        .timestamp_nanos_opt()
-        .unwrap();
-    let tick_time = DateTime::parse_from_rfc3339("2025-10-23T10:32:50.000Z")
-        .unwrap()
-        .with_timezone(&Utc)
-        .timestamp_nanos_opt()
-        .unwrap();
-
-    let mut limit_order_expire = OrderTestBuilder::new(OrderType::Limit)
+    // Rest one limit order and one stop order, both BUY
+    let client_order_id_limit = ClientOrderId::from("O-19700101-000000-001-001-1");
+    let mut limit_order = OrderTestBuilder::new(OrderType::Limit)
         .instrument_id(instrument_eth_usdt.id())
         .side(OrderSide::Buy)
         .price(Price::from("1495.00"))
         .quantity(Quantity::from("1.000"))
-        .expire_time(UnixNanos::from(expire_time as u64))
-        .client_order_id(client_order_id)
+        .client_order_id(client_order_id_limit)
         .build();
-    let tick = TradeTick::new(
+    let client_order_id_stop = ClientOrderId::from("O-19700101-000000-001-001-2");
+    let mut stop_order = OrderTestBuilder::new(OrderType::StopMarket)
+        .instrument_id(instrument_eth_usdt.id())
+        .side(OrderSide::Buy)
+        .trigger_price(Price::from("1510.00"))
+        .quantity(Quantity::from("1.000"))
+        .client_order_id(client_order_id_stop)
+        .build();
+    for order in [&mut limit_order, &mut stop_order] {
+        cache
+            .borrow_mut()
+            .add_order(order.clone(), None, None, false)
+            .unwrap();
+        engine_l2.process_order(order, account_id);
+        cache.borrow_mut().update_order(order).unwrap();
+    }
+
+    let cancel_all_command = CancelAllOrders::new(
+        TraderId::from("TRADER-001"),
+        ClientId::from("CLIENT-001"),
+        StrategyId::from("STRATEGY-001"),
         instrument_eth_usdt.id(),
-        Price::from("1500.00"),
-        Quantity::from("1.000"),
-        AggressorSide::Buyer,
-        TradeId::new("1"),
-        UnixNanos::from(tick_time as u64),
-        UnixNanos::from(tick_time as u64),
-    );
-    engine_l2.process_order(&mut limit_order_expire, account_id);
-    engine_l2.process_trade_tick(&tick);
+        OrderSide::NoOrderSide,
+        Some(OrderType::StopMarket),
+        UUID4::new(),
+        UnixNanos::default(),
+    )
+    .unwrap();
+    engine_l2.process_cancel_all(&cancel_all_command, account_id);
 
-    // Check we have received OrderAccepted and then OrderExpired event
+    // Check we have received 2 OrderAccepted and 1 OrderCanceled for the stop order only
     let saved_messages = get_order_event_handler_messages(order_event_handler);
-    assert_eq!(saved_messages.len(), 2);
-    let order_event_first = saved_messages.first().unwrap();
-    let order_accepted = match order_event_first {
-        OrderEventAny::Accepted(order_accepted) => order_accepted,
-        _ => panic!("Expected OrderAccepted event in first message"),
-    };
-    assert_eq!(order_accepted.client_order_id, client_order_id);
-    let order_event_second = saved_messages.get(1).unwrap();
-    let order_expired = match order_event_second {
-        OrderEventAny::Expired(order_expired) => order_expired,
-        _ => panic!("Expected OrderExpired event in second message"),
+    assert_eq!(saved_messages.len(), 3);
+    let order_canceled = match saved_messages.last().unwrap() {
+        OrderEventAny::Canceled(order_canceled) => order_canceled,
+        _ => panic!("Expected OrderCanceled event in last message"),
     };
-    assert_eq!(order_expired.client_order_id, client_order_id);
+    assert_eq!(order_canceled.client_order_id, client_order_id_stop);
+    assert!(engine_l2.core.order_exists(client_order_id_limit));
+    assert!(!engine_l2.core.order_exists(client_order_id_stop));
 }
 
 #[rstest]
-fn test_process_modify_order_rejected_not_found(
+#[case::command_order(false, [2, 0, 1])]
+#[case::grouped(true, [0, 1, 2])]
+fn test_process_batch_cancel_event_ordering(
     instrument_eth_usdt: InstrumentAny,
     mut msgbus: MessageBus,
     order_event_handler: ShareableMessageHandler,
     account_id: AccountId,
+    #[case] group_batch_cancel_events: bool,
+    #[case] expected_order: [usize; 3],
 ) {
     msgbus.register(
         msgbus.switchboard.exec_engine_process,
         order_event_handler.clone(),
     );
+    let config = OrderMatchingEngineConfig::default()
+        .with_group_batch_cancel_events(group_batch_cancel_events);
     let mut engine_l2 = get_order_matching_engine_l2(
         instrument_eth_usdt.clone(),
         Rc::new(RefCell::new(msgbus)),
         None,
         None,
-        None,
+        Some(config),
     );
 
-    // Create modify order command with client order id that didn't pass through the engine
-    let client_order_id = ClientOrderId::from("O-19700101-000000-001-001-1");
-    let modify_order_command = ModifyOrder::new(
+    let orderbook_delta_sell = OrderBookDeltaTestBuilder::new(instrument_eth_usdt.id())
+        .book_action(BookAction::Add)
+        .book_order(BookOrder::new(
+            OrderSide::Sell,
+            Price::from("1500.00"),
+            Quantity::from("1.000"),
+            1,
+        ))
+        .build();
+    engine_l2.process_order_book_delta(&orderbook_delta_sell);
+
+    let client_order_ids = [
+        ClientOrderId::from("O-19700101-000000-001-001-1"),
+        ClientOrderId::from("O-19700101-000000-001-001-2"),
+        ClientOrderId::from("O-19700101-000000-001-001-3"), // <-- Not found
+    ];
+    for (client_order_id, price) in client_order_ids[..2].iter().zip(["1495.00", "1496.00"]) {
+        let mut limit_order = OrderTestBuilder::new(OrderType::Limit)
+            .instrument_id(instrument_eth_usdt.id())
+            .side(OrderSide::Buy)
+            .price(Price::from(price))
+            .quantity(Quantity::from("1.000"))
+            .client_order_id(*client_order_id)
+            .build();
+        engine_l2.process_order(&mut limit_order, account_id);
+    }
+
+    // Mixed batch with the failing cancel first
+    let cancels = [2, 0, 1]
+        .into_iter()
+        .map(|i| {
+            CancelOrder::new(
+                TraderId::from("TRADER-001"),
+                ClientId::from("CLIENT-001"),
+                StrategyId::from("STRATEGY-001"),
+                instrument_eth_usdt.id(),
+                client_order_ids[i],
+                VenueOrderId::from(format!("V{i}").as_str()),
+                UUID4::new(),
+                UnixNanos::default(),
+            )
+            .unwrap()
+        })
+        .collect();
+    let batch_cancel_command = BatchCancelOrders::new(
         TraderId::from("TRADER-001"),
         ClientId::from("CLIENT-001"),
         StrategyId::from("STRATEGY-001"),
         instrument_eth_usdt.id(),
-        client_order_id,
-        VenueOrderId::from("V1"),
-        None,
-        None,
-        None,
+        cancels,
         UUID4::new(),
         UnixNanos::default(),
     )
     .unwrap();
-    engine_l2.process_modify(&modify_order_command, account_id);
+    engine_l2.process_batch_cancel(&batch_cancel_command, account_id);
 
-    // Check if we have received OrderModifyRejected event
     let saved_messages = get_order_event_handler_messages(order_event_handler);
-    assert_eq!(saved_messages.len(), 1);
-    let order_event = saved_messages.first().unwrap();
-    let order_rejected = match order_event {
-        OrderEventAny::ModifyRejected(order_rejected) => order_rejected,
-        _ => panic!("Expected OrderRejected event in first message"),
-    };
-    assert_eq!(order_rejected.client_order_id, client_order_id);
+    assert_eq!(saved_messages.len(), 5);
+    for (event, i) in saved_messages[2..].iter().zip(expected_order) {
+        let expected_event_type = if i == 2 {
+            OrderEventType::CancelRejected
+        } else {
+            OrderEventType::Canceled
+        };
+        assert_eq!(event.event_type(), expected_event_type);
+        assert_eq!(event.client_order_id(), client_order_ids[i]);
+    }
 }
 
 #[rstest]
-fn test_update_limit_order_post_only_matched(
+fn test_process_batch_cancel_partial_failures(
     instrument_eth_usdt: InstrumentAny,
     mut msgbus: MessageBus,
     order_event_handler: ShareableMessageHandler,
@@ -1871,27 +2654,153 @@ fn test_update_limit_order_post_only_matched(
         msgbus.switchboard.exec_engine_process,
         order_event_handler.clone(),
     );
+    let cache = Rc::new(RefCell::new(Cache::default()));
     let mut engine_l2 = get_order_matching_engine_l2(
         instrument_eth_usdt.clone(),
         Rc::new(RefCell::new(msgbus)),
-        None,
+        Some(cache.clone()),
         None,
         None,
     );
 
-    // Add SELL limit orderbook delta to have ask initialized
-    let orderbook_delta_sell = OrderBookDeltaTestBuilder::new(instrument_eth_usdt.id())
-        .book_action(BookAction::Add)
-        .book_order(BookOrder::new(
-            OrderSide::Sell,
-            Price::from("1500.00"),
-            Quantity::from("1.000"),
-            1,
-        ))
-        .build();
-    engine_l2.process_order_book_delta(&orderbook_delta_sell);
+    let client_order_ids = [
+        ClientOrderId::from("O-19700101-000000-001-001-1"),
+        ClientOrderId::from("O-19700101-000000-001-001-2"), // <-- Already closed
+        ClientOrderId::from("O-19700101-000000-001-001-3"), // <-- Not found
+    ];
+    let cancel = |i: usize| {
+        CancelOrder::new(
+            TraderId::from("TRADER-001"),
+            ClientId::from("CLIENT-001"),
+            StrategyId::from("STRATEGY-001"),
+            instrument_eth_usdt.id(),
+            client_order_ids[i],
+            VenueOrderId::from(format!("V{i}").as_str()),
+            UUID4::new(),
+            UnixNanos::default(),
+        )
+        .unwrap()
+    };
+    let mut limit_orders: Vec<OrderAny> = client_order_ids[..2]
+        .iter()
+        .zip(["1495.00", "1496.00"])
+        .map(|(client_order_id, price)| {
+            OrderTestBuilder::new(OrderType::Limit)
+                .instrument_id(instrument_eth_usdt.id())
+                .side(OrderSide::Buy)
+                .price(Price::from(price))
+                .quantity(Quantity::from("1.000"))
+                .client_order_id(*client_order_id)
+                .build()
+        })
+        .collect();
+    for limit_order in &mut limit_orders {
+        cache
+            .borrow_mut()
+            .add_order(limit_order.clone(), None, None, false)
+            .unwrap();
+        engine_l2.process_order(limit_order, account_id);
+        cache.borrow_mut().update_order(limit_order).unwrap();
+    }
+
+    // Close the second order ahead of the batch
+    engine_l2.process_cancel(&cancel(1), account_id);
+    let canceled_event = get_order_event_handler_messages(order_event_handler.clone())
+        .last()
+        .cloned()
+        .unwrap();
+    limit_orders[1].apply(canceled_event).unwrap();
+    cache.borrow_mut().update_order(&limit_orders[1]).unwrap();
+
+    let batch_cancel_command = BatchCancelOrders::new(
+        TraderId::from("TRADER-001"),
+        ClientId::from("CLIENT-001"),
+        StrategyId::from("STRATEGY-001"),
+        instrument_eth_usdt.id(),
+        (0..3).map(cancel).collect(),
+        UUID4::new(),
+        UnixNanos::default(),
+    )
+    .unwrap();
+    engine_l2.process_batch_cancel(&batch_cancel_command, account_id);
+
+    // The valid cancel succeeds while the others are rejected individually
+    let saved_messages = get_order_event_handler_messages(order_event_handler);
+    let batch_events: Vec<(OrderEventType, ClientOrderId, Option<Ustr>)> = saved_messages[3..]
+        .iter()
+        .map(|event| (event.event_type(), event.client_order_id(), event.message()))
+        .collect();
+    assert_eq!(
+        batch_events,
+        vec![
+            (OrderEventType::Canceled, client_order_ids[0], None),
+            (
+                OrderEventType::CancelRejected,
+                client_order_ids[1],
+                Some(Ustr::from(&format!(
+                    "Order {} already closed",
+                    client_order_ids[1]
+                ))),
+            ),
+            (
+                OrderEventType::CancelRejected,
+                client_order_ids[2],
+                Some(Ustr::from(&format!(
+                    "Order {} not found",
+                    client_order_ids[2]
+                ))),
+            ),
+        ]
+    );
+}
+
+#[rstest]
+fn test_process_cancel_rejected_until_min_resting_time_met(
+    instrument_eth_usdt: InstrumentAny,
+    mut msgbus: MessageBus,
+    order_event_handler: ShareableMessageHandler,
+    account_id: AccountId,
+) {
+    // Static clock so time only advances with market data
+    static TIME: LazyLock<AtomicTime> =
+        LazyLock::new(|| AtomicTime::new(false, UnixNanos::from(1_000)));
+
+    msgbus.register(
+        msgbus.switchboard.exec_engine_process,
+        order_event_handler.clone(),
+    );
+    let config = OrderMatchingEngineConfig::default().with_min_resting_time_nanos(Some(500));
+    let mut engine_l2 = OrderMatchingEngine::new(
+        instrument_eth_usdt.clone(),
+        1,
+        FillModel::default(),
+        FeeModelAny::default(),
+        BookType::L2_MBP,
+        OmsType::Netting,
+        AccountType::Cash,
+        &TIME,
+        Rc::new(RefCell::new(msgbus)),
+        Rc::new(RefCell::new(Cache::default())),
+        config,
+    );
+    let ask_delta = |ts: u64| {
+        OrderBookDelta::new(
+            instrument_eth_usdt.id(),
+            BookAction::Add,
+            BookOrder::new(
+                OrderSide::Sell,
+                Price::from("1500.00"),
+                Quantity::from("1.000"),
+                ts,
+            ),
+            0,
+            0,
+            UnixNanos::from(ts),
+            UnixNanos::from(ts),
+        )
+    };
+    engine_l2.process_order_book_delta(&ask_delta(1_000));
 
-    // Create BUY LIMIT order post only which will won't be filled
     let client_order_id = ClientOrderId::from("O-19700101-000000-001-001-1");
     let mut limit_order = OrderTestBuilder::new(OrderType::Limit)
         .instrument_id(instrument_eth_usdt.id())
@@ -1899,52 +2808,47 @@ fn test_update_limit_order_post_only_matched(
         .price(Price::from("1495.00"))
         .quantity(Quantity::from("1.000"))
         .client_order_id(client_order_id)
-        .post_only(true)
         .build();
     engine_l2.process_order(&mut limit_order, account_id);
 
-    // Create ModifyOrder command to update price of the order to be matched
-    let modify_order_command = ModifyOrder::new(
+    let cancel_command = CancelOrder::new(
         TraderId::from("TRADER-001"),
         ClientId::from("CLIENT-001"),
         StrategyId::from("STRATEGY-001"),
         instrument_eth_usdt.id(),
         client_order_id,
         VenueOrderId::from("V1"),
-        None,
-        Some(Price::from("1500.00")), // Set price which will be matched
-        None,
         UUID4::new(),
         UnixNanos::default(),
     )
     .unwrap();
-    engine_l2.process_modify(&modify_order_command, account_id);
 
-    // Check that we have received OrderAccepted and then OrderModifyRejected event because of post only
+    // Immediate cancel is rejected
+    engine_l2.process_cancel(&cancel_command, account_id);
+
+    // Market data advances time beyond the minimum resting time
+    engine_l2.process_order_book_delta(&ask_delta(1_500));
+    engine_l2.process_cancel(&cancel_command, account_id);
+
     let saved_messages = get_order_event_handler_messages(order_event_handler);
-    assert_eq!(saved_messages.len(), 2);
-    let order_event_first = saved_messages.first().unwrap();
-    let order_accepted = match order_event_first {
-        OrderEventAny::Accepted(order_accepted) => order_accepted,
-        _ => panic!("Expected OrderAccepted event in first message"),
-    };
-    assert_eq!(order_accepted.client_order_id, client_order_id);
-    let order_event_second = saved_messages.get(1).unwrap();
-    let order_rejected = match order_event_second {
-        OrderEventAny::ModifyRejected(order_rejected) => order_rejected,
-        _ => panic!("Expected OrderModifyRejected event in second message"),
+    assert_eq!(saved_messages.len(), 3);
+    assert_eq!(saved_messages[0].event_type(), OrderEventType::Accepted);
+    let cancel_rejected = match &saved_messages[1] {
+        OrderEventAny::CancelRejected(cancel_rejected) => cancel_rejected,
+        _ => panic!("Expected OrderCancelRejected event in second message"),
     };
-    assert_eq!(order_rejected.client_order_id, client_order_id);
     assert_eq!(
-        order_rejected.reason,
+        cancel_rejected.reason,
         Ustr::from(
-            "POST_ONLY LIMIT BUY order with new limit px of 1500.00 would have been a TAKER: bid=None, ask=1500.00"
+            "Minimum resting time not met: order O-19700101-000000-001-001-1 rested 0ns of 500ns"
         )
     );
+    assert_eq!(saved_messages[2].event_type(), OrderEventType::Canceled);
+    assert_eq!(saved_messages[2].client_order_id(), client_order_id);
 }
 
 #[rstest]
-fn test_update_limit_order_valid(
+fn test_process_cancel_all_command_scoped_to_account(
     instrument_eth_usdt: InstrumentAny,
     mut msgbus: MessageBus,
     order_event_handler: ShareableMessageHandler,
@@ -1954,15 +2858,15 @@ fn test_update_limit_order_valid(
         msgbus.switchboard.exec_engine_process,
         order_event_handler.clone(),
     );
+    let cache = Rc::new(RefCell::new(Cache::default()));
     let mut engine_l2 = get_order_matching_engine_l2(
         instrument_eth_usdt.clone(),
         Rc::new(RefCell::new(msgbus)),
-        None,
+        Some(cache.clone()),
         None,
         None,
     );
 
-    // Add SELL limit orderbook delta to have ask initialized
     let orderbook_delta_sell = OrderBookDeltaTestBuilder::new(instrument_eth_usdt.id())
         .book_action(BookAction::Add)
         .book_order(BookOrder::new(
@@ -1974,64 +2878,67 @@ fn test_update_limit_order_valid(
         .build();
     engine_l2.process_order_book_delta(&orderbook_delta_sell);
 
-    // Create BUY LIMIT order post only which will won't be filled
-    let client_order_id = ClientOrderId::from("O-19700101-000000-001-001-1");
-    let mut limit_order = OrderTestBuilder::new(OrderType::Limit)
-        .instrument_id(instrument_eth_usdt.id())
-        .side(OrderSide::Buy)
-        .price(Price::from("1495.00"))
-        .quantity(Quantity::from("1.000"))
-        .client_order_id(client_order_id)
-        .build();
-    engine_l2.process_order(&mut limit_order, account_id);
+    // One engine hosting resting orders for two accounts of the same trader
+    let other_account_id = AccountId::from("SIM-002");
+    let client_order_id_1 = ClientOrderId::from("O-19700101-000000-001-001-1");
+    let client_order_id_2 = ClientOrderId::from("O-19700101-000000-001-001-2");
+    for (client_order_id, order_account_id, price) in [
+        (client_order_id_1, account_id, "1495.00"),
+        (client_order_id_2, other_account_id, "1496.00"),
+    ] {
+        let mut limit_order = OrderTestBuilder::new(OrderType::Limit)
+            .instrument_id(instrument_eth_usdt.id())
+            .side(OrderSide::Buy)
+            .price(Price::from(price))
+            .quantity(Quantity::from("1.000"))
+            .client_order_id(client_order_id)
+            .build();
+        cache
+            .borrow_mut()
+            .add_order(limit_order.clone(), None, None, false)
+            .unwrap();
+        engine_l2.process_order(&mut limit_order, order_account_id);
+        cache.borrow_mut().update_order(&limit_order).unwrap();
+    }
+    assert_eq!(engine_l2.get_open_orders_for_account(account_id).len(), 1);
+    assert_eq!(
+        engine_l2
+            .get_open_orders_for_account(other_account_id)
+            .len(),
+        1
+    );
 
-    // Create ModifyOrder command to update price to 1500.00 where it will be matched immediately
-    let new_limit_price = Price::from("1500.00");
-    let modify_order_command = ModifyOrder::new(
+    let cancel_all_command = CancelAllOrders::new(
         TraderId::from("TRADER-001"),
         ClientId::from("CLIENT-001"),
         StrategyId::from("STRATEGY-001"),
         instrument_eth_usdt.id(),
-        client_order_id,
-        VenueOrderId::from("V1"),
-        None,
-        Some(new_limit_price),
+        OrderSide::NoOrderSide,
         None,
         UUID4::new(),
         UnixNanos::default(),
     )
     .unwrap();
-    engine_l2.process_modify(&modify_order_command, account_id);
+    engine_l2.process_cancel_all(&cancel_all_command, account_id);
 
-    // Check that we have received OrderAccepted and then OrderUpdated
-    // and then OrderFilled as limit order was matched immediately
     let saved_messages = get_order_event_handler_messages(order_event_handler);
     assert_eq!(saved_messages.len(), 3);
-    let order_event_first = saved_messages.first().unwrap();
-    let order_accepted = match order_event_first {
-        OrderEventAny::Accepted(order_accepted) => order_accepted,
-        _ => panic!("Expected OrderAccepted event in first message"),
-    };
-    assert_eq!(order_accepted.client_order_id, client_order_id);
-    let order_event_second = saved_messages.get(1).unwrap();
-    let order_updated = match order_event_second {
-        OrderEventAny::Updated(order_updated) => order_updated,
-        _ => panic!("Expected OrderUpdated event in second message"),
-    };
-    assert_eq!(order_updated.client_order_id, client_order_id);
-    assert_eq!(order_updated.price.unwrap(), new_limit_price);
-    let order_event_third = saved_messages.get(2).unwrap();
-    let order_filled = match order_event_third {
-        OrderEventAny::Filled(order_filled) => order_filled,
-        _ => panic!("Expected OrderFilled event in third message"),
+    assert_eq!(saved_messages[0].account_id(), Some(account_id));
+    assert_eq!(saved_messages[1].account_id(), Some(other_account_id));
+    let order_canceled = match saved_messages.last().unwrap() {
+        OrderEventAny::Canceled(order_canceled) => order_canceled,
+        _ => panic!("Expected OrderCanceled event in last message"),
     };
-    assert_eq!(order_filled.client_order_id, client_order_id);
-    assert_eq!(order_filled.last_px, new_limit_price);
-    assert_eq!(order_filled.last_qty, Quantity::from("1.000"));
+    assert_eq!(order_canceled.client_order_id, client_order_id_1);
+    assert_eq!(order_canceled.account_id, Some(account_id));
+    assert!(engine_l2.get_open_orders_for_account(account_id).is_empty());
+    let other_orders = engine_l2.get_open_orders_for_account(other_account_id);
+    assert_eq!(other_orders.len(), 1);
+    assert_eq!(other_orders[0].client_order_id(), client_order_id_2);
 }
 
 #[rstest]
-fn test_update_stop_market_order_valid(
+fn test_process_batch_cancel_command(
     instrument_eth_usdt: InstrumentAny,
     mut msgbus: MessageBus,
     order_event_handler: ShareableMessageHandler,
@@ -2041,10 +2948,11 @@ fn test_update_stop_market_order_valid(
         msgbus.switchboard.exec_engine_process,
         order_event_handler.clone(),
     );
+    let cache = Rc::new(RefCell::new(Cache::default()));
     let mut engine_l2 = get_order_matching_engine_l2(
         instrument_eth_usdt.clone(),
         Rc::new(RefCell::new(msgbus)),
-        None,
+        Some(cache),
         None,
         None,
     );
@@ -2061,56 +2969,92 @@ fn test_update_stop_market_order_valid(
         .build();
     engine_l2.process_order_book_delta(&orderbook_delta_sell);
 
-    // Create BUY STOP MARKET order which is not activated as trigger price of 1505.00 is above current ask of 1500.00
-    let client_order_id = ClientOrderId::from("O-19700101-000000-001-001-1");
-    let mut stop_market_order = OrderTestBuilder::new(OrderType::StopMarket)
+    // Create 2 limits order which will be canceled with batch cancel command
+    let client_order_id_1 = ClientOrderId::from("O-19700101-000000-001-001-1");
+    let mut limit_order_1 = OrderTestBuilder::new(OrderType::Limit)
         .instrument_id(instrument_eth_usdt.id())
         .side(OrderSide::Buy)
-        .trigger_price(Price::from("1505.00"))
+        .price(Price::from("1495.00"))
         .quantity(Quantity::from("1.000"))
-        .client_order_id(client_order_id)
+        .client_order_id(client_order_id_1)
+        .build();
+    let client_order_id_2 = ClientOrderId::from("O-19700101-000000-001-001-2");
+    let mut limit_order_2 = OrderTestBuilder::new(OrderType::Limit)
+        .instrument_id(instrument_eth_usdt.id())
+        .side(OrderSide::Buy)
+        .price(Price::from("1496.00"))
+        .quantity(Quantity::from("1.000"))
+        .client_order_id(client_order_id_2)
         .build();
-    engine_l2.process_order(&mut stop_market_order, account_id);
 
-    // Create modify command which moves trigger price to 1501.00 which won't trigger the stop price
-    //  as ask is at 1500.00 and order will be correctly updated
-    let new_trigger_price = Price::from("1501.00");
-    let modify_order_command = ModifyOrder::new(
+    engine_l2.process_order(&mut limit_order_1, account_id);
+    engine_l2.process_order(&mut limit_order_2, account_id);
+
+    let cancel_1 = CancelOrder::new(
         TraderId::from("TRADER-001"),
         ClientId::from("CLIENT-001"),
         StrategyId::from("STRATEGY-001"),
         instrument_eth_usdt.id(),
-        client_order_id,
+        client_order_id_1,
         VenueOrderId::from("V1"),
-        None,
-        None,
-        Some(new_trigger_price),
         UUID4::new(),
         UnixNanos::default(),
-    );
-    engine_l2.process_modify(&modify_order_command.unwrap(), account_id);
+    )
+    .unwrap();
+    let cancel_2 = CancelOrder::new(
+        TraderId::from("TRADER-001"),
+        ClientId::from("CLIENT-001"),
+        StrategyId::from("STRATEGY-001"),
+        instrument_eth_usdt.id(),
+        client_order_id_2,
+        VenueOrderId::from("V2"),
+        UUID4::new(),
+        UnixNanos::default(),
+    )
+    .unwrap();
+    let batch_cancel_command = BatchCancelOrders::new(
+        TraderId::from("TRADER-001"),
+        ClientId::from("CLIENT-001"),
+        StrategyId::from("STRATEGY-001"),
+        instrument_eth_usdt.id(),
+        vec![cancel_1, cancel_2],
+        UUID4::new(),
+        UnixNanos::default(),
+    )
+    .unwrap();
 
-    // Check that we have received OrderAccepted and then OrderUpdated
+    engine_l2.process_batch_cancel(&batch_cancel_command, account_id);
+
+    // Check we have received 2 OrderAccepted and 2 OrderCanceled events
     let saved_messages = get_order_event_handler_messages(order_event_handler);
-    assert_eq!(saved_messages.len(), 2);
+    assert_eq!(saved_messages.len(), 4);
     let order_event_first = saved_messages.first().unwrap();
     let order_accepted = match order_event_first {
         OrderEventAny::Accepted(order_accepted) => order_accepted,
         _ => panic!("Expected OrderAccepted event in first message"),
     };
-    assert_eq!(order_accepted.client_order_id, client_order_id);
+    assert_eq!(order_accepted.client_order_id, client_order_id_1);
     let order_event_second = saved_messages.get(1).unwrap();
-    println!("{order_event_second:?}");
-    let order_updated = match order_event_second {
-        OrderEventAny::Updated(order_updated) => order_updated,
-        _ => panic!("Expected OrderUpdated event in second message"),
+    let order_accepted = match order_event_second {
+        OrderEventAny::Accepted(order_accepted) => order_accepted,
+        _ => panic!("Expected OrderAccepted event in second message"),
     };
-    assert_eq!(order_updated.client_order_id, client_order_id);
-    assert_eq!(order_updated.trigger_price.unwrap(), new_trigger_price);
+    assert_eq!(order_accepted.client_order_id, client_order_id_2);
+    let order_event_third = saved_messages.get(2).unwrap();
+    let order_canceled_1 = match order_event_third {
+        OrderEventAny::Canceled(order_canceled) => order_canceled,
+        _ => panic!("Expected OrderCanceled event in third message"),
+    };
+    let order_event_fourth = saved_messages.get(3).unwrap();
+    let _order_canceled_2 = match order_event_fourth {
+        OrderEventAny::Canceled(order_canceled) => order_canceled,
+        _ => panic!("Expected OrderCanceled event in fourth message"),
+    };
+    assert_eq!(order_canceled_1.client_order_id, client_order_id_1);
 }
 
 #[rstest]
-fn test_update_stop_limit_order_valid_update_not_triggered(
+fn test_expire_order(
     instrument_eth_usdt: InstrumentAny,
     mut msgbus: MessageBus,
     order_event_handler: ShareableMessageHandler,
@@ -2120,45 +3064,65 @@ fn test_update_stop_limit_order_valid_update_not_triggered(
         msgbus.switchboard.exec_engine_process,
         order_event_handler.clone(),
     );
+
+    // Create order matching engine with gtd support
+    let mut engine_config = OrderMatchingEngineConfig::default();
+    engine_config.support_gtd_orders = true;
     let mut engine_l2 = get_order_matching_engine_l2(
         instrument_eth_usdt.clone(),
         Rc::new(RefCell::new(msgbus)),
         None,
         None,
-        None,
+        Some(engine_config),
     );
 
-    // Create BUY STOP LIMIT order which is not activated as trigger price of 1505.00 is above current ask of 1500.00
+    // Add SELL limit orderbook delta to have ask initialized
+    let orderbook_delta_sell = OrderBookDeltaTestBuilder::new(instrument_eth_usdt.id())
+        .book_action(BookAction::Add)
+        .book_order(BookOrder::new(
+            OrderSide::Sell,
+            Price::from("1500.00"),
+            Quantity::from("1.000"),
+            1,
+        ))
+        .build();
+    engine_l2.process_order_book_delta(&orderbook_delta_sell);
+
+    // Create GTD LIMIT order which will expire after we process tick
+    // that has higher timestamp than expire_time.
     let client_order_id = ClientOrderId::from("O-19700101-000000-001-001-1");
-    let mut stop_market_order = OrderTestBuilder::new(OrderType::StopMarket)
+    let expire_time = DateTime::parse_from_rfc3339("2019-10-23T10:32:49.669Z")
+        .unwrap()
+        .with_timezone(&Utc)
+        .timestamp_nanos_opt()
+        .unwrap();
+    let tick_time = DateTime::parse_from_rfc3339("2025-10-23T10:32:50.000Z")
+        .unwrap()
+        .with_timezone(&Utc)
+        .timestamp_nanos_opt()
+        .unwrap();
+
+    let mut limit_order_expire = OrderTestBuilder::new(OrderType::Limit)
         .instrument_id(instrument_eth_usdt.id())
         .side(OrderSide::Buy)
-        .price(Price::from("1502.00"))
-        .trigger_price(Price::from("1505.00"))
+        .price(Price::from("1495.00"))
         .quantity(Quantity::from("1.000"))
+        .expire_time(UnixNanos::from(expire_time as u64))
         .client_order_id(client_order_id)
         .build();
-    engine_l2.process_order(&mut stop_market_order, account_id);
-
-    // Create modify command which moves trigger price to 1501.00 which won't trigger the stop price again
-    // as ask is at 1500.00 and order will be correctly updated
-    let new_trigger_price = Price::from("1501.00");
-    let modify_order_command = ModifyOrder::new(
-        TraderId::from("TRADER-001"),
-        ClientId::from("CLIENT-001"),
-        StrategyId::from("STRATEGY-001"),
+    let tick = TradeTick::new(
         instrument_eth_usdt.id(),
-        client_order_id,
-        VenueOrderId::from("V1"),
-        None,
-        None,
-        Some(new_trigger_price),
-        UUID4::new(),
-        UnixNanos::default(),
+        Price::from("1500.00"),
+        Quantity::from("1.000"),
+        AggressorSide::Buyer,
+        TradeId::new("1"),
+        UnixNanos::from(tick_time as u64),
+        UnixNanos::from(tick_time as u64),
     );
-    engine_l2.process_modify(&modify_order_command.unwrap(), account_id);
+    engine_l2.process_order(&mut limit_order_expire, account_id);
+    engine_l2.process_trade_tick(&tick);
 
-    // Check that we have received OrderAccepted and then OrderUpdated
+    // Check we have received OrderAccepted and then OrderExpired event
     let saved_messages = get_order_event_handler_messages(order_event_handler);
     assert_eq!(saved_messages.len(), 2);
     let order_event_first = saved_messages.first().unwrap();
@@ -2168,16 +3132,15 @@ fn test_update_stop_limit_order_valid_update_not_triggered(
     };
     assert_eq!(order_accepted.client_order_id, client_order_id);
     let order_event_second = saved_messages.get(1).unwrap();
-    let order_updated = match order_event_second {
-        OrderEventAny::Updated(order_updated) => order_updated,
-        _ => panic!("Expected OrderUpdated event in second message"),
+    let order_expired = match order_event_second {
+        OrderEventAny::Expired(order_expired) => order_expired,
+        _ => panic!("Expected OrderExpired event in second message"),
     };
-    assert_eq!(order_updated.client_order_id, client_order_id);
-    assert_eq!(order_updated.trigger_price.unwrap(), new_trigger_price);
+    assert_eq!(order_expired.client_order_id, client_order_id);
 }
 
 #[rstest]
-fn test_process_market_if_touched_order_already_triggered(
+fn test_gtd_stop_market_order_expires_before_triggering(
     instrument_eth_usdt: InstrumentAny,
     mut msgbus: MessageBus,
     order_event_handler: ShareableMessageHandler,
@@ -2187,83 +3150,151 @@ fn test_process_market_if_touched_order_already_triggered(
         msgbus.switchboard.exec_engine_process,
         order_event_handler.clone(),
     );
-    let mut engine_l2 = get_order_matching_engine_l2(
-        instrument_eth_usdt.clone(),
+    let instrument_id = instrument_eth_usdt.id();
+    let engine_config = OrderMatchingEngineConfig {
+        support_gtd_orders: true,
+        ..Default::default()
+    };
+    let mut engine = get_order_matching_engine(
+        instrument_eth_usdt,
         Rc::new(RefCell::new(msgbus)),
         None,
         None,
-        None,
+        Some(engine_config),
     );
+    // Static clock so events are stamped at the time of the market data
+    engine.set_clock(Rc::new(AtomicTime::new(false, UnixNanos::default())));
+    let quote = |bid: &str, ask: &str, ts: u64| {
+        QuoteTick::new(
+            instrument_id,
+            Price::from(bid),
+            Price::from(ask),
+            Quantity::from("10.000"),
+            Quantity::from("10.000"),
+            UnixNanos::from(ts),
+            UnixNanos::from(ts),
+        )
+    };
+    engine.process_quote_tick(&quote("1499.00", "1501.00", 1_000));
 
-    // Add SELL limit orderbook delta to have ask initialized
-    let orderbook_delta_sell = OrderBookDeltaTestBuilder::new(instrument_eth_usdt.id())
-        .book_action(BookAction::Add)
-        .book_order(BookOrder::new(
-            OrderSide::Sell,
-            Price::from("1500.00"),
-            Quantity::from("1.000"),
-            1,
-        ))
-        .build();
-    engine_l2.process_order_book_delta(&orderbook_delta_sell);
-
-    // Create MARKET IF TOUCHED order which is already activated as trigger price of 1500.00 is equal to current ask of 1500.00
     let client_order_id = ClientOrderId::from("O-19700101-000000-001-001-1");
-    let mut stop_market_order = OrderTestBuilder::new(OrderType::MarketIfTouched)
-        .instrument_id(instrument_eth_usdt.id())
+    let mut stop_order = OrderTestBuilder::new(OrderType::StopMarket)
+        .instrument_id(instrument_id)
+        .client_order_id(client_order_id)
         .side(OrderSide::Buy)
-        .trigger_price(Price::from("1500.00"))
+        .trigger_price(Price::from("1510.00"))
         .quantity(Quantity::from("1.000"))
-        .client_order_id(client_order_id)
+        .time_in_force(TimeInForce::Gtd)
+        .expire_time(UnixNanos::from(5_000))
         .build();
-    engine_l2.process_order(&mut stop_market_order, account_id);
+    engine.process_order(&mut stop_order, account_id);
+
+    // Market stays below the trigger until expiry
+    engine.process_quote_tick(&quote("1500.00", "1502.00", 4_000));
+    // Market moves through the trigger only once the order has expired
+    engine.process_quote_tick(&quote("1514.00", "1515.00", 5_000));
+    engine.process_quote_tick(&quote("1519.00", "1520.00", 6_000));
 
-    // Check that order was filled immediately with correct price and quantity
     let saved_messages = get_order_event_handler_messages(order_event_handler);
-    assert_eq!(saved_messages.len(), 1);
-    let order_event = saved_messages.first().unwrap();
-    let order_filled = match order_event {
-        OrderEventAny::Filled(order_filled) => order_filled,
-        _ => panic!("Expected OrderFilled event in first message"),
+    let event_types: Vec<OrderEventType> = saved_messages
+        .iter()
+        .map(OrderEventAny::event_type)
+        .collect();
+    assert_eq!(
+        event_types,
+        vec![OrderEventType::Accepted, OrderEventType::Expired]
+    );
+    let OrderEventAny::Expired(order_expired) = &saved_messages[1] else {
+        panic!("Expected `OrderExpired` event");
     };
-    assert_eq!(order_filled.client_order_id, client_order_id);
-    assert_eq!(order_filled.last_px, Price::from("1500.00"));
-    assert_eq!(order_filled.last_qty, Quantity::from("1.000"));
+    assert_eq!(order_expired.client_order_id, client_order_id);
+    assert_eq!(order_expired.ts_event, UnixNanos::from(5_000));
 }
 
 #[rstest]
-fn test_update_market_if_touched_order_valid(
+fn test_order_expired_callback_invoked_for_gtd_order(
     instrument_eth_usdt: InstrumentAny,
-    mut msgbus: MessageBus,
-    order_event_handler: ShareableMessageHandler,
+    msgbus: MessageBus,
     account_id: AccountId,
 ) {
-    msgbus.register(
-        msgbus.switchboard.exec_engine_process,
-        order_event_handler.clone(),
-    );
+    let engine_config = OrderMatchingEngineConfig {
+        support_gtd_orders: true,
+        ..Default::default()
+    };
     let mut engine_l2 = get_order_matching_engine_l2(
         instrument_eth_usdt.clone(),
         Rc::new(RefCell::new(msgbus)),
         None,
         None,
-        None,
+        Some(engine_config),
     );
+    let expired_ids: Rc<RefCell<Vec<ClientOrderId>>> = Rc::new(RefCell::new(Vec::new()));
 
-    // Create MARKET IF TOUCHED order which is not activated as trigger price of 1505.00 is above current ask of 1500.00
+    let expire_time = UnixNanos::from(1_000);
     let client_order_id = ClientOrderId::from("O-19700101-000000-001-001-1");
-    let mut stop_market_order = OrderTestBuilder::new(OrderType::MarketIfTouched)
+    let other_client_order_id = ClientOrderId::from("O-19700101-000000-001-001-2");
+    let mut limit_order_expire = OrderTestBuilder::new(OrderType::Limit)
         .instrument_id(instrument_eth_usdt.id())
         .side(OrderSide::Buy)
-        .trigger_price(Price::from("1505.00"))
+        .price(Price::from("1495.00"))
         .quantity(Quantity::from("1.000"))
+        .expire_time(expire_time)
         .client_order_id(client_order_id)
         .build();
-    engine_l2.process_order(&mut stop_market_order, account_id);
+    let mut limit_order_other = OrderTestBuilder::new(OrderType::Limit)
+        .instrument_id(instrument_eth_usdt.id())
+        .side(OrderSide::Buy)
+        .price(Price::from("1490.00"))
+        .quantity(Quantity::from("1.000"))
+        .expire_time(expire_time)
+        .client_order_id(other_client_order_id)
+        .build();
 
-    // Create modify command which moves trigger price to 1501.00 which won't trigger the stop price again
-    // as ask is at 1500.00 and order will be correctly updated
-    let new_trigger_price = Price::from("1501.00");
+    let expired_ids_clone = expired_ids.clone();
+    engine_l2.on_order_expired(
+        client_order_id,
+        Box::new(move |expired: &OrderExpired| {
+            expired_ids_clone.borrow_mut().push(expired.client_order_id);
+        }),
+    );
+    engine_l2.process_order(&mut limit_order_expire, account_id);
+    engine_l2.process_order(&mut limit_order_other, account_id);
+
+    let tick = TradeTick::new(
+        instrument_eth_usdt.id(),
+        Price::from("1500.00"),
+        Quantity::from("1.000"),
+        AggressorSide::Buyer,
+        TradeId::new("1"),
+        expire_time + 1,
+        expire_time + 1,
+    );
+    engine_l2.process_trade_tick(&tick);
+
+    assert_eq!(*expired_ids.borrow(), vec![client_order_id]);
+}
+
+#[rstest]
+fn test_process_modify_order_rejected_not_found(
+    instrument_eth_usdt: InstrumentAny,
+    mut msgbus: MessageBus,
+    order_event_handler: ShareableMessageHandler,
+    account_id: AccountId,
+) {
+    msgbus.register(
+        msgbus.switchboard.exec_engine_process,
+        order_event_handler.clone(),
+    );
+    let mut engine_l2 = get_order_matching_engine_l2(
+        instrument_eth_usdt.clone(),
+        Rc::new(RefCell::new(msgbus)),
+        None,
+        None,
+        None,
+    );
+
+    // Create modify order command with client order id that didn't pass through the engine
+    let client_order_id = ClientOrderId::from("O-19700101-000000-001-001-1");
     let modify_order_command = ModifyOrder::new(
         TraderId::from("TRADER-001"),
         ClientId::from("CLIENT-001"),
@@ -2273,32 +3304,101 @@ fn test_update_market_if_touched_order_valid(
         VenueOrderId::from("V1"),
         None,
         None,
-        Some(new_trigger_price),
+        None,
         UUID4::new(),
         UnixNanos::default(),
-    );
-    engine_l2.process_modify(&modify_order_command.unwrap(), account_id);
+    )
+    .unwrap();
+    engine_l2.process_modify(&modify_order_command, account_id);
 
-    // Check that we have received OrderAccepted and then OrderUpdated
+    // Check if we have received OrderModifyRejected event
     let saved_messages = get_order_event_handler_messages(order_event_handler);
-    assert_eq!(saved_messages.len(), 2);
-    let order_event_first = saved_messages.first().unwrap();
-    let order_accepted = match order_event_first {
-        OrderEventAny::Accepted(order_accepted) => order_accepted,
-        _ => panic!("Expected OrderAccepted event in first message"),
+    assert_eq!(saved_messages.len(), 1);
+    let order_event = saved_messages.first().unwrap();
+    let order_rejected = match order_event {
+        OrderEventAny::ModifyRejected(order_rejected) => order_rejected,
+        _ => panic!("Expected OrderRejected event in first message"),
     };
-    assert_eq!(order_accepted.client_order_id, client_order_id);
-    let order_event_second = saved_messages.get(1).unwrap();
-    let order_updated = match order_event_second {
-        OrderEventAny::Updated(order_updated) => order_updated,
-        _ => panic!("Expected OrderUpdated event in second message"),
+    assert_eq!(order_rejected.client_order_id, client_order_id);
+    assert_eq!(
+        order_rejected.reason_code,
+        Some(ModifyRejectReason::OrderNotFound)
+    );
+}
+
+#[rstest]
+fn test_process_modify_order_rejected_when_order_closed(
+    instrument_eth_usdt: InstrumentAny,
+    mut msgbus: MessageBus,
+    order_event_handler: ShareableMessageHandler,
+    account_id: AccountId,
+) {
+    msgbus.register(
+        msgbus.switchboard.exec_engine_process,
+        order_event_handler.clone(),
+    );
+    let cache = Rc::new(RefCell::new(Cache::default()));
+    let mut engine_l2 = get_order_matching_engine_l2(
+        instrument_eth_usdt.clone(),
+        Rc::new(RefCell::new(msgbus)),
+        Some(cache.clone()),
+        None,
+        None,
+    );
+
+    // Save a rejected (closed) order in the cache which never entered the matching core
+    let client_order_id = ClientOrderId::from("O-19700101-000000-001-001-1");
+    let mut limit_order = OrderTestBuilder::new(OrderType::Limit)
+        .instrument_id(instrument_eth_usdt.id())
+        .side(OrderSide::Buy)
+        .price(Price::from("1495.00"))
+        .quantity(Quantity::from("1.000"))
+        .client_order_id(client_order_id)
+        .build();
+    let rejected_event: OrderRejected = OrderRejectedBuilder::default()
+        .client_order_id(client_order_id)
+        .reason(Ustr::from("Rejected"))
+        .build()
+        .unwrap();
+    limit_order
+        .apply(OrderEventAny::Rejected(rejected_event))
+        .unwrap();
+    cache
+        .borrow_mut()
+        .add_order(limit_order, None, None, false)
+        .unwrap();
+
+    let modify_order_command = ModifyOrder::new(
+        TraderId::from("TRADER-001"),
+        ClientId::from("CLIENT-001"),
+        StrategyId::from("STRATEGY-001"),
+        instrument_eth_usdt.id(),
+        client_order_id,
+        VenueOrderId::from("V1"),
+        None,
+        Some(Price::from("1496.00")),
+        None,
+        UUID4::new(),
+        UnixNanos::default(),
+    )
+    .unwrap();
+    engine_l2.process_modify(&modify_order_command, account_id);
+
+    let saved_messages = get_order_event_handler_messages(order_event_handler);
+    assert_eq!(saved_messages.len(), 1);
+    let order_rejected = match saved_messages.first().unwrap() {
+        OrderEventAny::ModifyRejected(order_rejected) => order_rejected,
+        _ => panic!("Expected OrderModifyRejected event in first message"),
     };
-    assert_eq!(order_updated.client_order_id, client_order_id);
-    assert_eq!(order_updated.trigger_price.unwrap(), new_trigger_price);
+    assert_eq!(order_rejected.client_order_id, client_order_id);
+    assert_eq!(
+        order_rejected.reason_code,
+        Some(ModifyRejectReason::OrderClosed)
+    );
 }
 
 #[rstest]
-fn test_process_limit_if_touched_order_immediate_trigger_and_fill(
+fn test_process_modify_order_rejected_when_quantity_below_filled(
     instrument_eth_usdt: InstrumentAny,
     mut msgbus: MessageBus,
     order_event_handler: ShareableMessageHandler,
@@ -2316,7 +3416,7 @@ fn test_process_limit_if_touched_order_immediate_trigger_and_fill(
         None,
     );
 
-    // Add SELL limit orderbook delta to have ask initialized
+    // Add SELL limit orderbook delta with less size than the order to partially fill it
     let orderbook_delta_sell = OrderBookDeltaTestBuilder::new(instrument_eth_usdt.id())
         .book_action(BookAction::Add)
         .book_order(BookOrder::new(
@@ -2328,48 +3428,46 @@ fn test_process_limit_if_touched_order_immediate_trigger_and_fill(
         .build();
     engine_l2.process_order_book_delta(&orderbook_delta_sell);
 
-    // Create LIMIT IF TOUCHED order which is already activated as
-    // trigger price of 1500.00 is equal to current ask of 1500.00
-    // and limit price of 1505.00 is above current ask of 1500.00
     let client_order_id = ClientOrderId::from("O-19700101-000000-001-001-1");
-    let mut limit_if_touched_order = OrderTestBuilder::new(OrderType::LimitIfTouched)
+    let mut limit_order = OrderTestBuilder::new(OrderType::Limit)
         .instrument_id(instrument_eth_usdt.id())
         .side(OrderSide::Buy)
-        .trigger_price(Price::from("1500.00"))
-        .price(Price::from("1505.00"))
-        .quantity(Quantity::from("1.000"))
+        .price(Price::from("1500.00"))
+        .quantity(Quantity::from("2.000"))
         .client_order_id(client_order_id)
         .build();
-    engine_l2.process_order(&mut limit_if_touched_order, account_id);
+    engine_l2.process_order(&mut limit_order, account_id);
+
+    let modify_order_command = ModifyOrder::new(
+        TraderId::from("TRADER-001"),
+        ClientId::from("CLIENT-001"),
+        StrategyId::from("STRATEGY-001"),
+        instrument_eth_usdt.id(),
+        client_order_id,
+        VenueOrderId::from("V1"),
+        Some(Quantity::from("0.500")),
+        None,
+        None,
+        UUID4::new(),
+        UnixNanos::default(),
+    )
+    .unwrap();
+    engine_l2.process_modify(&modify_order_command, account_id);
 
-    // Check that order was filled immediately with correct price and quantity
-    // We should receive OrderAccepted, OrderTriggered and OrderFilled event
     let saved_messages = get_order_event_handler_messages(order_event_handler);
-    assert_eq!(saved_messages.len(), 3);
-    let order_event_first = saved_messages.first().unwrap();
-    let order_accepted = match order_event_first {
-        OrderEventAny::Accepted(order_accepted) => order_accepted,
-        _ => panic!("Expected OrderAccepted event in first message"),
-    };
-    assert_eq!(order_accepted.client_order_id, client_order_id);
-    let order_event_second = saved_messages.get(1).unwrap();
-    let order_triggered = match order_event_second {
-        OrderEventAny::Triggered(order_triggered) => order_triggered,
-        _ => panic!("Expected OrderTriggered event in second message"),
-    };
-    assert_eq!(order_triggered.client_order_id, client_order_id);
-    let order_event_third = saved_messages.get(2).unwrap();
-    let order_filled = match order_event_third {
-        OrderEventAny::Filled(order_filled) => order_filled,
-        _ => panic!("Expected OrderFilled event in third message"),
+    let order_rejected = match saved_messages.last().unwrap() {
+        OrderEventAny::ModifyRejected(order_rejected) => order_rejected,
+        _ => panic!("Expected OrderModifyRejected event in last message"),
     };
-    assert_eq!(order_filled.client_order_id, client_order_id);
-    assert_eq!(order_filled.last_px, Price::from("1500.00"));
-    assert_eq!(order_filled.last_qty, Quantity::from("1.000"));
+    assert_eq!(order_rejected.client_order_id, client_order_id);
+    assert_eq!(
+        order_rejected.reason_code,
+        Some(ModifyRejectReason::QuantityBelowFilled)
+    );
 }
 
 #[rstest]
-fn test_update_limit_if_touched_order_valid(
+fn test_update_stop_market_order_rejected_when_trigger_price_in_market(
     instrument_eth_usdt: InstrumentAny,
     mut msgbus: MessageBus,
     order_event_handler: ShareableMessageHandler,
@@ -2387,7 +3485,6 @@ fn test_update_limit_if_touched_order_valid(
         None,
     );
 
-    // Add SELL limit orderbook delta to have ask initialized
     let orderbook_delta_sell = OrderBookDeltaTestBuilder::new(instrument_eth_usdt.id())
         .book_action(BookAction::Add)
         .book_order(BookOrder::new(
@@ -2399,21 +3496,17 @@ fn test_update_limit_if_touched_order_valid(
         .build();
     engine_l2.process_order_book_delta(&orderbook_delta_sell);
 
-    // Create LIMIT IF TOUCHED order which is not activated as trigger price of 1498.00 is bellow current ask of 1500.00
-    let client_order_id = ClientOrderId::new("O-19700101-000000-001-001-1");
-    let mut limit_if_touched_order = OrderTestBuilder::new(OrderType::LimitIfTouched)
+    let client_order_id = ClientOrderId::from("O-19700101-000000-001-001-1");
+    let mut stop_order = OrderTestBuilder::new(OrderType::StopMarket)
         .instrument_id(instrument_eth_usdt.id())
         .side(OrderSide::Buy)
-        .trigger_price(Price::from("1498.00"))
-        .price(Price::from("1505.00"))
+        .trigger_price(Price::from("1510.00"))
         .quantity(Quantity::from("1.000"))
         .client_order_id(client_order_id)
         .build();
-    engine_l2.process_order(&mut limit_if_touched_order, account_id);
+    engine_l2.process_order(&mut stop_order, account_id);
 
-    // Create modify command which moves trigger price to 1499.00 which won't trigger the trigger price again
-    // as ask is at 1500.00 and order will be correctly updated
-    let new_trigger_price = Price::from("1499.00");
+    // Move the trigger price below the best ask so it would be in the market
     let modify_order_command = ModifyOrder::new(
         TraderId::from("TRADER-001"),
         ClientId::from("CLIENT-001"),
@@ -2423,26 +3516,5614 @@ fn test_update_limit_if_touched_order_valid(
         VenueOrderId::from("V1"),
         None,
         None,
-        Some(new_trigger_price),
+        Some(Price::from("1495.00")),
         UUID4::new(),
         UnixNanos::default(),
-    );
-    engine_l2.process_modify(&modify_order_command.unwrap(), account_id);
+    )
+    .unwrap();
+    engine_l2.process_modify(&modify_order_command, account_id);
 
-    // Check that we have received OrderAccepted and then OrderUpdated
     let saved_messages = get_order_event_handler_messages(order_event_handler);
     assert_eq!(saved_messages.len(), 2);
-    let order_event_first = saved_messages.first().unwrap();
-    let order_accepted = match order_event_first {
-        OrderEventAny::Accepted(order_accepted) => order_accepted,
-        _ => panic!("Expected OrderAccepted event in first message"),
-    };
-    assert_eq!(order_accepted.client_order_id, client_order_id);
-    let order_event_second = saved_messages.get(1).unwrap();
-    let order_updated = match order_event_second {
-        OrderEventAny::Updated(order_updated) => order_updated,
-        _ => panic!("Expected OrderUpdated event in second message"),
+    let order_rejected = match saved_messages.get(1).unwrap() {
+        OrderEventAny::ModifyRejected(order_rejected) => order_rejected,
+        _ => panic!("Expected OrderModifyRejected event in second message"),
     };
-    assert_eq!(order_updated.client_order_id, client_order_id);
-    assert_eq!(order_updated.trigger_price.unwrap(), new_trigger_price);
+    assert_eq!(order_rejected.client_order_id, client_order_id);
+    assert_eq!(
+        order_rejected.reason_code,
+        Some(ModifyRejectReason::TriggerPriceInMarket)
+    );
+}
+
+#[rstest]
+fn test_update_limit_order_post_only_matched(
+    instrument_eth_usdt: InstrumentAny,
+    mut msgbus: MessageBus,
+    order_event_handler: ShareableMessageHandler,
+    account_id: AccountId,
+) {
+    msgbus.register(
+        msgbus.switchboard.exec_engine_process,
+        order_event_handler.clone(),
+    );
+    let mut engine_l2 = get_order_matching_engine_l2(
+        instrument_eth_usdt.clone(),
+        Rc::new(RefCell::new(msgbus)),
+        None,
+        None,
+        None,
+    );
+
+    // Add SELL limit orderbook delta to have ask initialized
+    let orderbook_delta_sell = OrderBookDeltaTestBuilder::new(instrument_eth_usdt.id())
+        .book_action(BookAction::Add)
+        .book_order(BookOrder::new(
+            OrderSide::Sell,
+            Price::from("1500.00"),
+            Quantity::from("1.000"),
+            1,
+        ))
+        .build();
+    engine_l2.process_order_book_delta(&orderbook_delta_sell);
+
+    // Create BUY LIMIT order post only which will won't be filled
+    let client_order_id = ClientOrderId::from("O-19700101-000000-001-001-1");
+    let mut limit_order = OrderTestBuilder::new(OrderType::Limit)
+        .instrument_id(instrument_eth_usdt.id())
+        .side(OrderSide::Buy)
+        .price(Price::from("1495.00"))
+        .quantity(Quantity::from("1.000"))
+        .client_order_id(client_order_id)
+        .post_only(true)
+        .build();
+    engine_l2.process_order(&mut limit_order, account_id);
+
+    // Create ModifyOrder command to update price of the order to be matched
+    let modify_order_command = ModifyOrder::new(
+        TraderId::from("TRADER-001"),
+        ClientId::from("CLIENT-001"),
+        StrategyId::from("STRATEGY-001"),
+        instrument_eth_usdt.id(),
+        client_order_id,
+        VenueOrderId::from("V1"),
+        None,
+        Some(Price::from("1500.00")), // Set price which will be matched
+        None,
+        UUID4::new(),
+        UnixNanos::default(),
+    )
+    .unwrap();
+    engine_l2.process_modify(&modify_order_command, account_id);
+
+    // Check that we have received OrderAccepted and then OrderModifyRejected event because of post only
+    let saved_messages = get_order_event_handler_messages(order_event_handler);
+    assert_eq!(saved_messages.len(), 2);
+    let order_event_first = saved_messages.first().unwrap();
+    let order_accepted = match order_event_first {
+        OrderEventAny::Accepted(order_accepted) => order_accepted,
+        _ => panic!("Expected OrderAccepted event in first message"),
+    };
+    assert_eq!(order_accepted.client_order_id, client_order_id);
+    let order_event_second = saved_messages.get(1).unwrap();
+    let order_rejected = match order_event_second {
+        OrderEventAny::ModifyRejected(order_rejected) => order_rejected,
+        _ => panic!("Expected OrderModifyRejected event in second message"),
+    };
+    assert_eq!(order_rejected.client_order_id, client_order_id);
+    assert_eq!(
+        order_rejected.reason,
+        Ustr::from(
+            "POST_ONLY LIMIT BUY order with new limit px of 1500.00 would have been a TAKER: bid=None, ask=1500.00"
+        )
+    );
+    assert_eq!(
+        order_rejected.reason_code,
+        Some(ModifyRejectReason::PostOnlyWouldBeTaker)
+    );
+}
+
+#[rstest]
+fn test_update_limit_order_valid(
+    instrument_eth_usdt: InstrumentAny,
+    mut msgbus: MessageBus,
+    order_event_handler: ShareableMessageHandler,
+    account_id: AccountId,
+) {
+    msgbus.register(
+        msgbus.switchboard.exec_engine_process,
+        order_event_handler.clone(),
+    );
+    let mut engine_l2 = get_order_matching_engine_l2(
+        instrument_eth_usdt.clone(),
+        Rc::new(RefCell::new(msgbus)),
+        None,
+        None,
+        None,
+    );
+
+    // Add SELL limit orderbook delta to have ask initialized
+    let orderbook_delta_sell = OrderBookDeltaTestBuilder::new(instrument_eth_usdt.id())
+        .book_action(BookAction::Add)
+        .book_order(BookOrder::new(
+            OrderSide::Sell,
+            Price::from("1500.00"),
+            Quantity::from("1.000"),
+            1,
+        ))
+        .build();
+    engine_l2.process_order_book_delta(&orderbook_delta_sell);
+
+    // Create BUY LIMIT order post only which will won't be filled
+    let client_order_id = ClientOrderId::from("O-19700101-000000-001-001-1");
+    let mut limit_order = OrderTestBuilder::new(OrderType::Limit)
+        .instrument_id(instrument_eth_usdt.id())
+        .side(OrderSide::Buy)
+        .price(Price::from("1495.00"))
+        .quantity(Quantity::from("1.000"))
+        .client_order_id(client_order_id)
+        .build();
+    engine_l2.process_order(&mut limit_order, account_id);
+
+    // Create ModifyOrder command to update price to 1500.00 where it will be matched immediately
+    let new_limit_price = Price::from("1500.00");
+    let modify_order_command = ModifyOrder::new(
+        TraderId::from("TRADER-001"),
+        ClientId::from("CLIENT-001"),
+        StrategyId::from("STRATEGY-001"),
+        instrument_eth_usdt.id(),
+        client_order_id,
+        VenueOrderId::from("V1"),
+        None,
+        Some(new_limit_price),
+        None,
+        UUID4::new(),
+        UnixNanos::default(),
+    )
+    .unwrap();
+    engine_l2.process_modify(&modify_order_command, account_id);
+
+    // Check that we have received OrderAccepted and then OrderUpdated
+    // and then OrderFilled as limit order was matched immediately
+    let saved_messages = get_order_event_handler_messages(order_event_handler);
+    assert_eq!(saved_messages.len(), 3);
+    let order_event_first = saved_messages.first().unwrap();
+    let order_accepted = match order_event_first {
+        OrderEventAny::Accepted(order_accepted) => order_accepted,
+        _ => panic!("Expected OrderAccepted event in first message"),
+    };
+    assert_eq!(order_accepted.client_order_id, client_order_id);
+    let order_event_second = saved_messages.get(1).unwrap();
+    let order_updated = match order_event_second {
+        OrderEventAny::Updated(order_updated) => order_updated,
+        _ => panic!("Expected OrderUpdated event in second message"),
+    };
+    assert_eq!(order_updated.client_order_id, client_order_id);
+    assert_eq!(order_updated.price.unwrap(), new_limit_price);
+    let order_event_third = saved_messages.get(2).unwrap();
+    let order_filled = match order_event_third {
+        OrderEventAny::Filled(order_filled) => order_filled,
+        _ => panic!("Expected OrderFilled event in third message"),
+    };
+    assert_eq!(order_filled.client_order_id, client_order_id);
+    assert_eq!(order_filled.last_px, new_limit_price);
+    assert_eq!(order_filled.last_qty, Quantity::from("1.000"));
+}
+
+#[rstest]
+fn test_update_stop_market_order_valid(
+    instrument_eth_usdt: InstrumentAny,
+    mut msgbus: MessageBus,
+    order_event_handler: ShareableMessageHandler,
+    account_id: AccountId,
+) {
+    msgbus.register(
+        msgbus.switchboard.exec_engine_process,
+        order_event_handler.clone(),
+    );
+    let mut engine_l2 = get_order_matching_engine_l2(
+        instrument_eth_usdt.clone(),
+        Rc::new(RefCell::new(msgbus)),
+        None,
+        None,
+        None,
+    );
+
+    // Add SELL limit orderbook delta to have ask initialized
+    let orderbook_delta_sell = OrderBookDeltaTestBuilder::new(instrument_eth_usdt.id())
+        .book_action(BookAction::Add)
+        .book_order(BookOrder::new(
+            OrderSide::Sell,
+            Price::from("1500.00"),
+            Quantity::from("1.000"),
+            1,
+        ))
+        .build();
+    engine_l2.process_order_book_delta(&orderbook_delta_sell);
+
+    // Create BUY STOP MARKET order which is not activated as trigger price of 1505.00 is above current ask of 1500.00
+    let client_order_id = ClientOrderId::from("O-19700101-000000-001-001-1");
+    let mut stop_market_order = OrderTestBuilder::new(OrderType::StopMarket)
+        .instrument_id(instrument_eth_usdt.id())
+        .side(OrderSide::Buy)
+        .trigger_price(Price::from("1505.00"))
+        .quantity(Quantity::from("1.000"))
+        .client_order_id(client_order_id)
+        .build();
+    engine_l2.process_order(&mut stop_market_order, account_id);
+
+    // Create modify command which moves trigger price to 1501.00 which won't trigger the stop price
+    //  as ask is at 1500.00 and order will be correctly updated
+    let new_trigger_price = Price::from("1501.00");
+    let modify_order_command = ModifyOrder::new(
+        TraderId::from("TRADER-001"),
+        ClientId::from("CLIENT-001"),
+        StrategyId::from("STRATEGY-001"),
+        instrument_eth_usdt.id(),
+        client_order_id,
+        VenueOrderId::from("V1"),
+        None,
+        None,
+        Some(new_trigger_price),
+        UUID4::new(),
+        UnixNanos::default(),
+    );
+    engine_l2.process_modify(&modify_order_command.unwrap(), account_id);
+
+    // Check that we have received OrderAccepted and then OrderUpdated
+    let saved_messages = get_order_event_handler_messages(order_event_handler);
+    assert_eq!(saved_messages.len(), 2);
+    let order_event_first = saved_messages.first().unwrap();
+    let order_accepted = match order_event_first {
+        OrderEventAny::Accepted(order_accepted) => order_accepted,
+        _ => panic!("Expected OrderAccepted event in first message"),
+    };
+    assert_eq!(order_accepted.client_order_id, client_order_id);
+    let order_event_second = saved_messages.get(1).unwrap();
+    println!("{order_event_second:?}");
+    let order_updated = match order_event_second {
+        OrderEventAny::Updated(order_updated) => order_updated,
+        _ => panic!("Expected OrderUpdated event in second message"),
+    };
+    assert_eq!(order_updated.client_order_id, client_order_id);
+    assert_eq!(order_updated.trigger_price.unwrap(), new_trigger_price);
+}
+
+#[rstest]
+fn test_update_stop_limit_order_valid_update_not_triggered(
+    instrument_eth_usdt: InstrumentAny,
+    mut msgbus: MessageBus,
+    order_event_handler: ShareableMessageHandler,
+    account_id: AccountId,
+) {
+    msgbus.register(
+        msgbus.switchboard.exec_engine_process,
+        order_event_handler.clone(),
+    );
+    let mut engine_l2 = get_order_matching_engine_l2(
+        instrument_eth_usdt.clone(),
+        Rc::new(RefCell::new(msgbus)),
+        None,
+        None,
+        None,
+    );
+
+    // Create BUY STOP LIMIT order which is not activated as trigger price of 1505.00 is above current ask of 1500.00
+    let client_order_id = ClientOrderId::from("O-19700101-000000-001-001-1");
+    let mut stop_market_order = OrderTestBuilder::new(OrderType::StopMarket)
+        .instrument_id(instrument_eth_usdt.id())
+        .side(OrderSide::Buy)
+        .price(Price::from("1502.00"))
+        .trigger_price(Price::from("1505.00"))
+        .quantity(Quantity::from("1.000"))
+        .client_order_id(client_order_id)
+        .build();
+    engine_l2.process_order(&mut stop_market_order, account_id);
+
+    // Create modify command which moves trigger price to 1501.00 which won't trigger the stop price again
+    // as ask is at 1500.00 and order will be correctly updated
+    let new_trigger_price = Price::from("1501.00");
+    let modify_order_command = ModifyOrder::new(
+        TraderId::from("TRADER-001"),
+        ClientId::from("CLIENT-001"),
+        StrategyId::from("STRATEGY-001"),
+        instrument_eth_usdt.id(),
+        client_order_id,
+        VenueOrderId::from("V1"),
+        None,
+        None,
+        Some(new_trigger_price),
+        UUID4::new(),
+        UnixNanos::default(),
+    );
+    engine_l2.process_modify(&modify_order_command.unwrap(), account_id);
+
+    // Check that we have received OrderAccepted and then OrderUpdated
+    let saved_messages = get_order_event_handler_messages(order_event_handler);
+    assert_eq!(saved_messages.len(), 2);
+    let order_event_first = saved_messages.first().unwrap();
+    let order_accepted = match order_event_first {
+        OrderEventAny::Accepted(order_accepted) => order_accepted,
+        _ => panic!("Expected OrderAccepted event in first message"),
+    };
+    assert_eq!(order_accepted.client_order_id, client_order_id);
+    let order_event_second = saved_messages.get(1).unwrap();
+    let order_updated = match order_event_second {
+        OrderEventAny::Updated(order_updated) => order_updated,
+        _ => panic!("Expected OrderUpdated event in second message"),
+    };
+    assert_eq!(order_updated.client_order_id, client_order_id);
+    assert_eq!(order_updated.trigger_price.unwrap(), new_trigger_price);
+}
+
+#[rstest]
+fn test_process_market_if_touched_order_already_triggered(
+    instrument_eth_usdt: InstrumentAny,
+    mut msgbus: MessageBus,
+    order_event_handler: ShareableMessageHandler,
+    account_id: AccountId,
+) {
+    msgbus.register(
+        msgbus.switchboard.exec_engine_process,
+        order_event_handler.clone(),
+    );
+    let mut engine_l2 = get_order_matching_engine_l2(
+        instrument_eth_usdt.clone(),
+        Rc::new(RefCell::new(msgbus)),
+        None,
+        None,
+        None,
+    );
+
+    // Add SELL limit orderbook delta to have ask initialized
+    let orderbook_delta_sell = OrderBookDeltaTestBuilder::new(instrument_eth_usdt.id())
+        .book_action(BookAction::Add)
+        .book_order(BookOrder::new(
+            OrderSide::Sell,
+            Price::from("1500.00"),
+            Quantity::from("1.000"),
+            1,
+        ))
+        .build();
+    engine_l2.process_order_book_delta(&orderbook_delta_sell);
+
+    // Create MARKET IF TOUCHED order which is already activated as trigger price of 1500.00 is equal to current ask of 1500.00
+    let client_order_id = ClientOrderId::from("O-19700101-000000-001-001-1");
+    let mut stop_market_order = OrderTestBuilder::new(OrderType::MarketIfTouched)
+        .instrument_id(instrument_eth_usdt.id())
+        .side(OrderSide::Buy)
+        .trigger_price(Price::from("1500.00"))
+        .quantity(Quantity::from("1.000"))
+        .client_order_id(client_order_id)
+        .build();
+    engine_l2.process_order(&mut stop_market_order, account_id);
+
+    // Check that order was filled immediately with correct price and quantity
+    let saved_messages = get_order_event_handler_messages(order_event_handler);
+    assert_eq!(saved_messages.len(), 1);
+    let order_event = saved_messages.first().unwrap();
+    let order_filled = match order_event {
+        OrderEventAny::Filled(order_filled) => order_filled,
+        _ => panic!("Expected OrderFilled event in first message"),
+    };
+    assert_eq!(order_filled.client_order_id, client_order_id);
+    assert_eq!(order_filled.last_px, Price::from("1500.00"));
+    assert_eq!(order_filled.last_qty, Quantity::from("1.000"));
+}
+
+#[rstest]
+fn test_update_market_if_touched_order_valid(
+    instrument_eth_usdt: InstrumentAny,
+    mut msgbus: MessageBus,
+    order_event_handler: ShareableMessageHandler,
+    account_id: AccountId,
+) {
+    msgbus.register(
+        msgbus.switchboard.exec_engine_process,
+        order_event_handler.clone(),
+    );
+    let mut engine_l2 = get_order_matching_engine_l2(
+        instrument_eth_usdt.clone(),
+        Rc::new(RefCell::new(msgbus)),
+        None,
+        None,
+        None,
+    );
+
+    // Create MARKET IF TOUCHED order which is not activated as trigger price of 1505.00 is above current ask of 1500.00
+    let client_order_id = ClientOrderId::from("O-19700101-000000-001-001-1");
+    let mut stop_market_order = OrderTestBuilder::new(OrderType::MarketIfTouched)
+        .instrument_id(instrument_eth_usdt.id())
+        .side(OrderSide::Buy)
+        .trigger_price(Price::from("1505.00"))
+        .quantity(Quantity::from("1.000"))
+        .client_order_id(client_order_id)
+        .build();
+    engine_l2.process_order(&mut stop_market_order, account_id);
+
+    // Create modify command which moves trigger price to 1501.00 which won't trigger the stop price again
+    // as ask is at 1500.00 and order will be correctly updated
+    let new_trigger_price = Price::from("1501.00");
+    let modify_order_command = ModifyOrder::new(
+        TraderId::from("TRADER-001"),
+        ClientId::from("CLIENT-001"),
+        StrategyId::from("STRATEGY-001"),
+        instrument_eth_usdt.id(),
+        client_order_id,
+        VenueOrderId::from("V1"),
+        None,
+        None,
+        Some(new_trigger_price),
+        UUID4::new(),
+        UnixNanos::default(),
+    );
+    engine_l2.process_modify(&modify_order_command.unwrap(), account_id);
+
+    // Check that we have received OrderAccepted and then OrderUpdated
+    let saved_messages = get_order_event_handler_messages(order_event_handler);
+    assert_eq!(saved_messages.len(), 2);
+    let order_event_first = saved_messages.first().unwrap();
+    let order_accepted = match order_event_first {
+        OrderEventAny::Accepted(order_accepted) => order_accepted,
+        _ => panic!("Expected OrderAccepted event in first message"),
+    };
+    assert_eq!(order_accepted.client_order_id, client_order_id);
+    let order_event_second = saved_messages.get(1).unwrap();
+    let order_updated = match order_event_second {
+        OrderEventAny::Updated(order_updated) => order_updated,
+        _ => panic!("Expected OrderUpdated event in second message"),
+    };
+    assert_eq!(order_updated.client_order_id, client_order_id);
+    assert_eq!(order_updated.trigger_price.unwrap(), new_trigger_price);
+}
+
+#[rstest]
+fn test_process_limit_if_touched_order_immediate_trigger_and_fill(
+    instrument_eth_usdt: InstrumentAny,
+    mut msgbus: MessageBus,
+    order_event_handler: ShareableMessageHandler,
+    account_id: AccountId,
+) {
+    msgbus.register(
+        msgbus.switchboard.exec_engine_process,
+        order_event_handler.clone(),
+    );
+    let mut engine_l2 = get_order_matching_engine_l2(
+        instrument_eth_usdt.clone(),
+        Rc::new(RefCell::new(msgbus)),
+        None,
+        None,
+        None,
+    );
+
+    // Add SELL limit orderbook delta to have ask initialized
+    let orderbook_delta_sell = OrderBookDeltaTestBuilder::new(instrument_eth_usdt.id())
+        .book_action(BookAction::Add)
+        .book_order(BookOrder::new(
+            OrderSide::Sell,
+            Price::from("1500.00"),
+            Quantity::from("1.000"),
+            1,
+        ))
+        .build();
+    engine_l2.process_order_book_delta(&orderbook_delta_sell);
+
+    // Create LIMIT IF TOUCHED order which is already activated as
+    // trigger price of 1500.00 is equal to current ask of 1500.00
+    // and limit price of 1505.00 is above current ask of 1500.00
+    let client_order_id = ClientOrderId::from("O-19700101-000000-001-001-1");
+    let mut limit_if_touched_order = OrderTestBuilder::new(OrderType::LimitIfTouched)
+        .instrument_id(instrument_eth_usdt.id())
+        .side(OrderSide::Buy)
+        .trigger_price(Price::from("1500.00"))
+        .price(Price::from("1505.00"))
+        .quantity(Quantity::from("1.000"))
+        .client_order_id(client_order_id)
+        .build();
+    engine_l2.process_order(&mut limit_if_touched_order, account_id);
+
+    // Check that order was filled immediately with correct price and quantity
+    // We should receive OrderAccepted, OrderTriggered and OrderFilled event
+    let saved_messages = get_order_event_handler_messages(order_event_handler);
+    assert_eq!(saved_messages.len(), 3);
+    let order_event_first = saved_messages.first().unwrap();
+    let order_accepted = match order_event_first {
+        OrderEventAny::Accepted(order_accepted) => order_accepted,
+        _ => panic!("Expected OrderAccepted event in first message"),
+    };
+    assert_eq!(order_accepted.client_order_id, client_order_id);
+    let order_event_second = saved_messages.get(1).unwrap();
+    let order_triggered = match order_event_second {
+        OrderEventAny::Triggered(order_triggered) => order_triggered,
+        _ => panic!("Expected OrderTriggered event in second message"),
+    };
+    assert_eq!(order_triggered.client_order_id, client_order_id);
+    let order_event_third = saved_messages.get(2).unwrap();
+    let order_filled = match order_event_third {
+        OrderEventAny::Filled(order_filled) => order_filled,
+        _ => panic!("Expected OrderFilled event in third message"),
+    };
+    assert_eq!(order_filled.client_order_id, client_order_id);
+    assert_eq!(order_filled.last_px, Price::from("1500.00"));
+    assert_eq!(order_filled.last_qty, Quantity::from("1.000"));
+}
+
+#[rstest]
+fn test_update_limit_if_touched_order_valid(
+    instrument_eth_usdt: InstrumentAny,
+    mut msgbus: MessageBus,
+    order_event_handler: ShareableMessageHandler,
+    account_id: AccountId,
+) {
+    msgbus.register(
+        msgbus.switchboard.exec_engine_process,
+        order_event_handler.clone(),
+    );
+    let mut engine_l2 = get_order_matching_engine_l2(
+        instrument_eth_usdt.clone(),
+        Rc::new(RefCell::new(msgbus)),
+        None,
+        None,
+        None,
+    );
+
+    // Add SELL limit orderbook delta to have ask initialized
+    let orderbook_delta_sell = OrderBookDeltaTestBuilder::new(instrument_eth_usdt.id())
+        .book_action(BookAction::Add)
+        .book_order(BookOrder::new(
+            OrderSide::Sell,
+            Price::from("1500.00"),
+            Quantity::from("1.000"),
+            1,
+        ))
+        .build();
+    engine_l2.process_order_book_delta(&orderbook_delta_sell);
+
+    // Create LIMIT IF TOUCHED order which is not activated as trigger price of 1498.00 is bellow current ask of 1500.00
+    let client_order_id = ClientOrderId::new("O-19700101-000000-001-001-1");
+    let mut limit_if_touched_order = OrderTestBuilder::new(OrderType::LimitIfTouched)
+        .instrument_id(instrument_eth_usdt.id())
+        .side(OrderSide::Buy)
+        .trigger_price(Price::from("1498.00"))
+        .price(Price::from("1505.00"))
+        .quantity(Quantity::from("1.000"))
+        .client_order_id(client_order_id)
+        .build();
+    engine_l2.process_order(&mut limit_if_touched_order, account_id);
+
+    // Create modify command which moves trigger price to 1499.00 which won't trigger the trigger price again
+    // as ask is at 1500.00 and order will be correctly updated
+    let new_trigger_price = Price::from("1499.00");
+    let modify_order_command = ModifyOrder::new(
+        TraderId::from("TRADER-001"),
+        ClientId::from("CLIENT-001"),
+        StrategyId::from("STRATEGY-001"),
+        instrument_eth_usdt.id(),
+        client_order_id,
+        VenueOrderId::from("V1"),
+        None,
+        None,
+        Some(new_trigger_price),
+        UUID4::new(),
+        UnixNanos::default(),
+    );
+    engine_l2.process_modify(&modify_order_command.unwrap(), account_id);
+
+    // Check that we have received OrderAccepted and then OrderUpdated
+    let saved_messages = get_order_event_handler_messages(order_event_handler);
+    assert_eq!(saved_messages.len(), 2);
+    let order_event_first = saved_messages.first().unwrap();
+    let order_accepted = match order_event_first {
+        OrderEventAny::Accepted(order_accepted) => order_accepted,
+        _ => panic!("Expected OrderAccepted event in first message"),
+    };
+    assert_eq!(order_accepted.client_order_id, client_order_id);
+    let order_event_second = saved_messages.get(1).unwrap();
+    let order_updated = match order_event_second {
+        OrderEventAny::Updated(order_updated) => order_updated,
+        _ => panic!("Expected OrderUpdated event in second message"),
+    };
+    assert_eq!(order_updated.client_order_id, client_order_id);
+    assert_eq!(order_updated.trigger_price.unwrap(), new_trigger_price);
+}
+
+#[rstest]
+fn test_process_market_to_limit_order_partially_filled_and_rests_remainder(
+    instrument_eth_usdt: InstrumentAny,
+    mut msgbus: MessageBus,
+    order_event_handler: ShareableMessageHandler,
+    account_id: AccountId,
+) {
+    msgbus.register(
+        msgbus.switchboard.exec_engine_process,
+        order_event_handler.clone(),
+    );
+    let mut engine_l2 = get_order_matching_engine_l2(
+        instrument_eth_usdt.clone(),
+        Rc::new(RefCell::new(msgbus)),
+        None,
+        None,
+        None,
+    );
+
+    // Add two SELL levels to the book, only the first one should be consumed
+    let orderbook_delta_sell_1 = OrderBookDeltaTestBuilder::new(instrument_eth_usdt.id())
+        .book_action(BookAction::Add)
+        .book_order(BookOrder::new(
+            OrderSide::Sell,
+            Price::from("1500.00"),
+            Quantity::from("1.000"),
+            1,
+        ))
+        .build();
+    let orderbook_delta_sell_2 = OrderBookDeltaTestBuilder::new(instrument_eth_usdt.id())
+        .book_action(BookAction::Add)
+        .book_order(BookOrder::new(
+            OrderSide::Sell,
+            Price::from("1510.00"),
+            Quantity::from("1.000"),
+            2,
+        ))
+        .build();
+    engine_l2.process_order_book_delta(&orderbook_delta_sell_1);
+    engine_l2.process_order_book_delta(&orderbook_delta_sell_2);
+
+    let client_order_id = ClientOrderId::from("O-19700101-000000-001-001-1");
+    let mut market_to_limit_order = OrderTestBuilder::new(OrderType::MarketToLimit)
+        .instrument_id(instrument_eth_usdt.id())
+        .side(OrderSide::Buy)
+        .quantity(Quantity::from("2.000"))
+        .client_order_id(client_order_id)
+        .build();
+    let mut replayed_order = market_to_limit_order.clone();
+    engine_l2.process_order(&mut market_to_limit_order, account_id);
+
+    // Check that we have received OrderAccepted, then OrderFilled for the first level
+    // and OrderUpdated assigning the initial fill price as the limit price
+    let saved_messages = get_order_event_handler_messages(order_event_handler);
+    assert_eq!(saved_messages.len(), 3);
+    let order_accepted = match saved_messages.first().unwrap() {
+        OrderEventAny::Accepted(order_accepted) => order_accepted,
+        _ => panic!("Expected OrderAccepted event in first message"),
+    };
+    assert_eq!(order_accepted.client_order_id, client_order_id);
+    let order_filled = match saved_messages.get(1).unwrap() {
+        OrderEventAny::Filled(order_filled) => order_filled,
+        _ => panic!("Expected OrderFilled event in second message"),
+    };
+    assert_eq!(order_filled.last_px, Price::from("1500.00"));
+    assert_eq!(order_filled.last_qty, Quantity::from("1.000"));
+    let order_updated = match saved_messages.get(2).unwrap() {
+        OrderEventAny::Updated(order_updated) => order_updated,
+        _ => panic!("Expected OrderUpdated event in third message"),
+    };
+    assert_eq!(order_updated.price.unwrap(), Price::from("1500.00"));
+
+    // Check that the events are valid transitions when applied to the order in sequence
+    for event in saved_messages {
+        let event = match event {
+            OrderEventAny::Filled(filled) => OrderEventAny::PartiallyFilled(filled),
+            event => event,
+        };
+        replayed_order.apply(event).unwrap();
+    }
+    assert_eq!(replayed_order.status(), OrderStatus::PartiallyFilled);
+    assert_eq!(replayed_order.price(), Some(Price::from("1500.00")));
+    assert_eq!(replayed_order.leaves_qty(), Quantity::from("1.000"));
+
+    // Check that the remainder rests as a passive limit order at the fill price
+    let open_bid_orders = engine_l2.get_open_bid_orders();
+    assert_eq!(open_bid_orders.len(), 1);
+    assert_eq!(open_bid_orders[0].client_order_id(), client_order_id);
+    assert_eq!(
+        open_bid_orders[0].to_any().price(),
+        Some(Price::from("1500.00"))
+    );
+}
+
+#[rstest]
+fn test_process_market_to_limit_order_no_market_rejected(
+    instrument_eth_usdt: InstrumentAny,
+    mut msgbus: MessageBus,
+    order_event_handler: ShareableMessageHandler,
+    account_id: AccountId,
+) {
+    msgbus.register(
+        msgbus.switchboard.exec_engine_process,
+        order_event_handler.clone(),
+    );
+    let mut engine_l2 = get_order_matching_engine_l2(
+        instrument_eth_usdt.clone(),
+        Rc::new(RefCell::new(msgbus)),
+        None,
+        None,
+        None,
+    );
+
+    let mut market_to_limit_order = OrderTestBuilder::new(OrderType::MarketToLimit)
+        .instrument_id(instrument_eth_usdt.id())
+        .side(OrderSide::Buy)
+        .quantity(Quantity::from("1.000"))
+        .build();
+    engine_l2.process_order(&mut market_to_limit_order, account_id);
+
+    let saved_messages = get_order_event_handler_messages(order_event_handler);
+    assert_eq!(saved_messages.len(), 1);
+    let first = saved_messages.first().unwrap();
+    assert_eq!(first.event_type(), OrderEventType::Rejected);
+    assert_eq!(
+        first.message().unwrap(),
+        Ustr::from("No market for ETHUSDT-PERP.BINANCE")
+    );
+}
+
+#[rstest]
+#[case::limit_filled(1.0, true)]
+#[case::limit_not_filled(0.0, false)]
+fn test_fill_limit_order_after_queue_ahead_decayed(
+    instrument_eth_usdt: InstrumentAny,
+    mut msgbus: MessageBus,
+    order_event_handler: ShareableMessageHandler,
+    account_id: AccountId,
+    #[case] prob_fill_on_limit: f64,
+    #[case] expect_filled: bool,
+) {
+    msgbus.register(
+        msgbus.switchboard.exec_engine_process,
+        order_event_handler.clone(),
+    );
+    let mut engine_l2 = get_order_matching_engine_l2(
+        instrument_eth_usdt.clone(),
+        Rc::new(RefCell::new(msgbus)),
+        None,
+        None,
+        None,
+    );
+    // Queue ahead volume is cancelled at a rate of 1.000 per second
+    engine_l2.set_queue_model(QueueModelAny::Decay(DecayQueueModel::new(1.0).unwrap()));
+    // Once the queue ahead is depleted the fill model still determines the fill
+    engine_l2.set_fill_model(FillModel::new(prob_fill_on_limit, 1.0, 0.0, None).unwrap());
+
+    // Add BUY volume of 2.000 at 1495.00 which will be ahead of our order in the queue
+    let orderbook_delta_buy = OrderBookDeltaTestBuilder::new(instrument_eth_usdt.id())
+        .book_action(BookAction::Add)
+        .book_order(BookOrder::new(
+            OrderSide::Buy,
+            Price::from("1495.00"),
+            Quantity::from("2.000"),
+            1,
+        ))
+        .build();
+    let orderbook_delta_sell = OrderBookDeltaTestBuilder::new(instrument_eth_usdt.id())
+        .book_action(BookAction::Add)
+        .book_order(BookOrder::new(
+            OrderSide::Sell,
+            Price::from("1500.00"),
+            Quantity::from("1.000"),
+            2,
+        ))
+        .build();
+    engine_l2.process_order_book_delta(&orderbook_delta_buy);
+    engine_l2.process_order_book_delta(&orderbook_delta_sell);
+
+    let mut limit_order = OrderTestBuilder::new(OrderType::Limit)
+        .instrument_id(instrument_eth_usdt.id())
+        .side(OrderSide::Buy)
+        .price(Price::from("1495.00"))
+        .quantity(Quantity::from("1.000"))
+        .build();
+    engine_l2.process_order(&mut limit_order, account_id);
+
+    // Market rests on our limit price, but the volume ahead has not been depleted yet
+    let orderbook_delta_sell_touch = OrderBookDeltaTestBuilder::new(instrument_eth_usdt.id())
+        .book_action(BookAction::Add)
+        .book_order(BookOrder::new(
+            OrderSide::Sell,
+            Price::from("1495.00"),
+            Quantity::from("1.000"),
+            3,
+        ))
+        .build();
+    engine_l2.process_order_book_delta(&orderbook_delta_sell_touch);
+    limit_order.set_liquidity_side(LiquiditySide::Maker);
+    engine_l2.fill_limit_order(&mut limit_order);
+    engine_l2.iterate(UnixNanos::from(1_000_000_000));
+    engine_l2.fill_limit_order(&mut limit_order);
+    assert_eq!(
+        get_order_event_handler_messages(order_event_handler.clone()).len(),
+        1
+    );
+
+    // After three seconds the queue ahead has been fully cancelled and the order fills
+    engine_l2.iterate(UnixNanos::from(3_000_000_000));
+    engine_l2.fill_limit_order(&mut limit_order);
+
+    let saved_messages = get_order_event_handler_messages(order_event_handler);
+    if !expect_filled {
+        assert_eq!(saved_messages.len(), 1);
+        return;
+    }
+    assert_eq!(saved_messages.len(), 2);
+    let order_accepted = match saved_messages.first().unwrap() {
+        OrderEventAny::Accepted(order_accepted) => order_accepted,
+        _ => panic!("Expected OrderAccepted event in first message"),
+    };
+    assert_eq!(
+        order_accepted.client_order_id,
+        limit_order.client_order_id()
+    );
+    let order_filled = match saved_messages.get(1).unwrap() {
+        OrderEventAny::Filled(order_filled) => order_filled,
+        _ => panic!("Expected OrderFilled event in second message"),
+    };
+    assert_eq!(order_filled.last_px, Price::from("1495.00"));
+    assert_eq!(order_filled.last_qty, Quantity::from("1.000"));
+    assert_eq!(order_filled.liquidity_side, LiquiditySide::Maker);
+}
+
+#[rstest]
+fn test_process_order_book_depth10_triggers_resting_stop_market_order(
+    instrument_eth_usdt: InstrumentAny,
+    mut msgbus: MessageBus,
+    order_event_handler: ShareableMessageHandler,
+    account_id: AccountId,
+) {
+    msgbus.register(
+        msgbus.switchboard.exec_engine_process,
+        order_event_handler.clone(),
+    );
+    let mut engine_l2 = get_order_matching_engine_l2(
+        instrument_eth_usdt.clone(),
+        Rc::new(RefCell::new(msgbus)),
+        None,
+        None,
+        None,
+    );
+
+    // Add SELL limit orderbook delta to have ask initialized
+    let orderbook_delta_sell = OrderBookDeltaTestBuilder::new(instrument_eth_usdt.id())
+        .book_action(BookAction::Add)
+        .book_order(BookOrder::new(
+            OrderSide::Sell,
+            Price::from("1500.00"),
+            Quantity::from("1.000"),
+            1,
+        ))
+        .build();
+    engine_l2.process_order_book_delta(&orderbook_delta_sell);
+
+    // Create BUY STOP MARKET order which is not triggered as the ask is below the trigger price
+    let client_order_id = ClientOrderId::from("O-19700101-000000-001-001-1");
+    let mut stop_order = OrderTestBuilder::new(OrderType::StopMarket)
+        .instrument_id(instrument_eth_usdt.id())
+        .side(OrderSide::Buy)
+        .trigger_price(Price::from("1505.00"))
+        .quantity(Quantity::from("1.000"))
+        .client_order_id(client_order_id)
+        .build();
+    engine_l2.process_order(&mut stop_order, account_id);
+
+    // Replace the book with a snapshot where the market moved above the trigger price
+    let mut bids = [BookOrder::default(); DEPTH10_LEN];
+    let mut asks = [BookOrder::default(); DEPTH10_LEN];
+    for i in 0..DEPTH10_LEN {
+        let offset = i as f64;
+        bids[i] = BookOrder::new(
+            OrderSide::Buy,
+            Price::new(1509.0 - offset, 2),
+            Quantity::from("1.000"),
+            i as u64 + 1,
+        );
+        asks[i] = BookOrder::new(
+            OrderSide::Sell,
+            Price::new(1510.0 + offset, 2),
+            Quantity::from("1.000"),
+            i as u64 + 11,
+        );
+    }
+    let depth = OrderBookDepth10::new(
+        instrument_eth_usdt.id(),
+        bids,
+        asks,
+        [1; DEPTH10_LEN],
+        [1; DEPTH10_LEN],
+        0,
+        0,
+        UnixNanos::from(1),
+        UnixNanos::from(1),
+    );
+    engine_l2.process_order_book_depth10(&depth);
+
+    assert_eq!(engine_l2.best_bid_price(), Some(Price::from("1509.00")));
+    assert_eq!(engine_l2.best_ask_price(), Some(Price::from("1510.00")));
+    assert_eq!(engine_l2.get_book().bids(None).count(), DEPTH10_LEN);
+    assert_eq!(engine_l2.get_book().asks(None).count(), DEPTH10_LEN);
+
+    // Check that the stop order was accepted and then filled at the new best ask
+    let saved_messages = get_order_event_handler_messages(order_event_handler);
+    assert_eq!(saved_messages.len(), 2);
+    let order_accepted = match saved_messages.first().unwrap() {
+        OrderEventAny::Accepted(order_accepted) => order_accepted,
+        _ => panic!("Expected OrderAccepted event in first message"),
+    };
+    assert_eq!(order_accepted.client_order_id, client_order_id);
+    let order_filled = match saved_messages.get(1).unwrap() {
+        OrderEventAny::Filled(order_filled) => order_filled,
+        _ => panic!("Expected OrderFilled event in second message"),
+    };
+    assert_eq!(order_filled.client_order_id, client_order_id);
+    assert_eq!(order_filled.last_px, Price::from("1510.00"));
+    assert_eq!(order_filled.last_qty, Quantity::from("1.000"));
+}
+
+#[rstest]
+#[case(true)]
+#[case(false)]
+fn test_fill_ts_event_source(
+    instrument_eth_usdt: InstrumentAny,
+    mut msgbus: MessageBus,
+    order_event_handler: ShareableMessageHandler,
+    account_id: AccountId,
+    #[case] use_market_data_ts_for_fills: bool,
+) {
+    msgbus.register(
+        msgbus.switchboard.exec_engine_process,
+        order_event_handler.clone(),
+    );
+    let engine_config = OrderMatchingEngineConfig::default()
+        .with_use_market_data_ts_for_fills(use_market_data_ts_for_fills);
+    let mut engine = get_order_matching_engine(
+        instrument_eth_usdt.clone(),
+        Rc::new(RefCell::new(msgbus)),
+        None,
+        None,
+        Some(engine_config),
+    );
+
+    let mut limit_order = OrderTestBuilder::new(OrderType::Limit)
+        .instrument_id(instrument_eth_usdt.id())
+        .side(OrderSide::Buy)
+        .price(Price::from("1500.00"))
+        .quantity(Quantity::from("1.000"))
+        .build();
+    engine.process_order(&mut limit_order, account_id);
+
+    // Trade below the limit price fills the resting order
+    let ts_trade = UnixNanos::from(1_000);
+    let tick = TradeTick::new(
+        instrument_eth_usdt.id(),
+        Price::from("1495.00"),
+        Quantity::from("1.000"),
+        AggressorSide::Seller,
+        TradeId::new("1"),
+        ts_trade,
+        ts_trade,
+    );
+    engine.process_trade_tick(&tick);
+
+    let saved_messages = get_order_event_handler_messages(order_event_handler);
+    assert_eq!(saved_messages.len(), 2);
+    let order_filled = match saved_messages.get(1).unwrap() {
+        OrderEventAny::Filled(order_filled) => order_filled,
+        _ => panic!("Expected OrderFilled event in second message"),
+    };
+    if use_market_data_ts_for_fills {
+        assert_eq!(order_filled.ts_event, ts_trade);
+    } else {
+        assert_ne!(order_filled.ts_event, ts_trade);
+        assert_eq!(order_filled.ts_event, order_filled.ts_init);
+    }
+}
+
+#[rstest]
+#[case::market_data_ts(true)]
+#[case::engine_clock(false)]
+fn test_fill_ts_event_reflects_triggering_market_data(
+    instrument_eth_usdt: InstrumentAny,
+    mut msgbus: MessageBus,
+    order_event_handler: ShareableMessageHandler,
+    account_id: AccountId,
+    #[case] use_market_data_ts_for_fills: bool,
+) {
+    msgbus.register(
+        msgbus.switchboard.exec_engine_process,
+        order_event_handler.clone(),
+    );
+    let engine_config = OrderMatchingEngineConfig::default()
+        .with_use_market_data_ts_for_fills(use_market_data_ts_for_fills);
+    let mut engine = get_order_matching_engine(
+        instrument_eth_usdt.clone(),
+        Rc::new(RefCell::new(msgbus)),
+        None,
+        None,
+        Some(engine_config),
+    );
+
+    let mut limit_order = OrderTestBuilder::new(OrderType::Limit)
+        .instrument_id(instrument_eth_usdt.id())
+        .side(OrderSide::Sell)
+        .price(Price::from("1500.00"))
+        .quantity(Quantity::from("1.000"))
+        .build();
+    engine.process_order(&mut limit_order, account_id);
+
+    let ts_quote = UnixNanos::from(1_000);
+    engine.process_quote_tick(&QuoteTick::new(
+        instrument_eth_usdt.id(),
+        Price::from("1501.00"),
+        Price::from("1502.00"),
+        Quantity::from("10.000"),
+        Quantity::from("10.000"),
+        ts_quote,
+        ts_quote,
+    ));
+
+    let saved_messages = get_order_event_handler_messages(order_event_handler);
+    let order_filled = saved_messages
+        .iter()
+        .find_map(|event| match event {
+            OrderEventAny::Filled(order_filled) => Some(order_filled),
+            _ => None,
+        })
+        .expect("Expected `OrderFilled` event");
+    if use_market_data_ts_for_fills {
+        assert_eq!(order_filled.ts_event, ts_quote);
+    } else {
+        assert_eq!(order_filled.ts_event, order_filled.ts_init);
+    }
+    assert!(order_filled.ts_init >= order_filled.ts_event);
+}
+
+#[rstest]
+#[case(true)]
+#[case(false)]
+fn test_process_trade_tick_infers_aggressor_side_for_stop_triggers(
+    instrument_eth_usdt: InstrumentAny,
+    mut msgbus: MessageBus,
+    order_event_handler: ShareableMessageHandler,
+    account_id: AccountId,
+    #[case] trigger_stops_on_trades: bool,
+) {
+    msgbus.register(
+        msgbus.switchboard.exec_engine_process,
+        order_event_handler.clone(),
+    );
+    let config =
+        OrderMatchingEngineConfig::default().with_trigger_stops_on_trades(trigger_stops_on_trades);
+    let mut engine_l2 = get_order_matching_engine_l2(
+        instrument_eth_usdt.clone(),
+        Rc::new(RefCell::new(msgbus)),
+        None,
+        None,
+        Some(config),
+    );
+
+    for (side, price) in [(OrderSide::Buy, "1495.00"), (OrderSide::Sell, "1500.00")] {
+        let delta = OrderBookDeltaTestBuilder::new(instrument_eth_usdt.id())
+            .book_action(BookAction::Add)
+            .book_order(BookOrder::new(
+                side,
+                Price::from(price),
+                Quantity::from("1.000"),
+                side as u64,
+            ))
+            .build();
+        engine_l2.process_order_book_delta(&delta);
+    }
+
+    let client_order_id_buy = ClientOrderId::from("O-19700101-000000-001-001-1");
+    let mut buy_stop = OrderTestBuilder::new(OrderType::StopMarket)
+        .instrument_id(instrument_eth_usdt.id())
+        .client_order_id(client_order_id_buy)
+        .side(OrderSide::Buy)
+        .trigger_price(Price::from("1502.00"))
+        .quantity(Quantity::from("1.000"))
+        .build();
+    engine_l2.process_order(&mut buy_stop, account_id);
+    let client_order_id_sell = ClientOrderId::from("O-19700101-000000-001-001-2");
+    let mut sell_stop = OrderTestBuilder::new(OrderType::StopMarket)
+        .instrument_id(instrument_eth_usdt.id())
+        .client_order_id(client_order_id_sell)
+        .side(OrderSide::Sell)
+        .trigger_price(Price::from("1490.00"))
+        .quantity(Quantity::from("1.000"))
+        .build();
+    engine_l2.process_order(&mut sell_stop, account_id);
+
+    let trade_tick = |price: &str| {
+        TradeTick::new(
+            instrument_eth_usdt.id(),
+            Price::from(price),
+            Quantity::from("1.000"),
+            AggressorSide::NoAggressor,
+            TradeId::new("1"),
+            UnixNanos::default(),
+            UnixNanos::default(),
+        )
+    };
+    assert_eq!(
+        engine_l2.infer_aggressor_side(&trade_tick("1497.00")),
+        AggressorSide::NoAggressor
+    );
+    assert_eq!(
+        engine_l2.infer_aggressor_side(&trade_tick("1490.00")),
+        AggressorSide::Seller
+    );
+
+    // Trade through the ask is inferred as a buyer, triggering only the BUY stop (when enabled)
+    let trade = trade_tick("1503.00");
+    assert_eq!(engine_l2.infer_aggressor_side(&trade), AggressorSide::Buyer);
+    engine_l2.process_trade_tick(&trade);
+
+    let saved_messages = get_order_event_handler_messages(order_event_handler);
+    if !trigger_stops_on_trades {
+        assert_eq!(saved_messages.len(), 2);
+        assert!(engine_l2.core.order_exists(client_order_id_buy));
+        assert!(engine_l2.core.order_exists(client_order_id_sell));
+        return;
+    }
+    assert_eq!(saved_messages.len(), 3);
+    let order_filled = match saved_messages.last().unwrap() {
+        OrderEventAny::Filled(order_filled) => order_filled,
+        _ => panic!("Expected OrderFilled event in last message"),
+    };
+    assert_eq!(order_filled.client_order_id, client_order_id_buy);
+    assert_eq!(order_filled.last_px, Price::from("1500.00"));
+    assert!(engine_l2.core.order_exists(client_order_id_sell));
+}
+
+#[rstest]
+fn test_auction_uncross_fills_market_and_limit_orders_at_clearing_price(
+    instrument_eth_usdt: InstrumentAny,
+    order_event_handler: ShareableMessageHandler,
+    mut msgbus: MessageBus,
+    account_id: AccountId,
+) {
+    msgbus.register(
+        msgbus.switchboard.exec_engine_process,
+        order_event_handler.clone(),
+    );
+    let config = OrderMatchingEngineConfig::default().with_support_auctions(true);
+    let mut engine = get_order_matching_engine(
+        instrument_eth_usdt.clone(),
+        Rc::new(RefCell::new(msgbus)),
+        None,
+        None,
+        Some(config),
+    );
+
+    let auction_order = |client_order_id: &str, side: OrderSide, price: Option<&str>, qty: &str| {
+        let mut builder = OrderTestBuilder::new(if price.is_some() {
+            OrderType::Limit
+        } else {
+            OrderType::Market
+        });
+        builder
+            .instrument_id(instrument_eth_usdt.id())
+            .client_order_id(ClientOrderId::from(client_order_id))
+            .side(side)
+            .quantity(Quantity::from(qty));
+        if let Some(price) = price {
+            builder.price(Price::from(price));
+        }
+        builder.build()
+    };
+    let mut orders = vec![
+        auction_order("O-BUY-MKT", OrderSide::Buy, None, "3.000"),
+        auction_order("O-BUY-1500", OrderSide::Buy, Some("1500.00"), "2.000"),
+        auction_order("O-BUY-1490", OrderSide::Buy, Some("1490.00"), "2.000"),
+        auction_order("O-SELL-MKT", OrderSide::Sell, None, "1.000"),
+        auction_order("O-SELL-1500", OrderSide::Sell, Some("1500.00"), "4.000"),
+        auction_order("O-SELL-1520", OrderSide::Sell, Some("1520.00"), "2.000"),
+        auction_order("O-SELL-MKT-2", OrderSide::Sell, None, "1.000"),
+    ];
+
+    engine.process_status(MarketStatusAction::PreOpen);
+    for order in &mut orders {
+        engine.process_order(order, account_id);
+    }
+
+    // All orders are held during the collection phase
+    let saved_messages = get_order_event_handler_messages(order_event_handler.clone());
+    assert_eq!(saved_messages.len(), orders.len());
+    assert!(
+        saved_messages
+            .iter()
+            .all(|event| event.event_type() == OrderEventType::Accepted)
+    );
+    assert!(engine.get_open_orders().is_empty());
+
+    engine.process_status(MarketStatusAction::Trading);
+
+    // Clearing price of 1500.00 matches 5.000 (buys 3 + 2, sells 1 + 4 with the last
+    // market sell left unfilled as the limit sell at 1500.00 ranks behind market orders)
+    let saved_messages = get_order_event_handler_messages(order_event_handler);
+    let uncross_events = &saved_messages[orders.len()..];
+    let fills: Vec<&OrderFilled> = uncross_events
+        .iter()
+        .filter_map(|event| match event {
+            OrderEventAny::Filled(fill) => Some(fill),
+            _ => None,
+        })
+        .collect();
+    let filled_qty = |client_order_id: &str| {
+        fills
+            .iter()
+            .filter(|fill| fill.client_order_id == ClientOrderId::from(client_order_id))
+            .map(|fill| fill.last_qty)
+            .fold(Quantity::from("0.000"), |acc, qty| acc + qty)
+    };
+    assert!(
+        fills
+            .iter()
+            .all(|fill| fill.last_px == Price::from("1500.00"))
+    );
+    assert_eq!(filled_qty("O-BUY-MKT"), Quantity::from("3.000"));
+    assert_eq!(filled_qty("O-BUY-1500"), Quantity::from("2.000"));
+    assert_eq!(filled_qty("O-BUY-1490"), Quantity::from("0.000"));
+    assert_eq!(filled_qty("O-SELL-MKT"), Quantity::from("1.000"));
+    assert_eq!(filled_qty("O-SELL-MKT-2"), Quantity::from("1.000"));
+    assert_eq!(filled_qty("O-SELL-1500"), Quantity::from("3.000"));
+    assert_eq!(filled_qty("O-SELL-1520"), Quantity::from("0.000"));
+
+    // Limit orders not executable at the clearing price rest for continuous trading,
+    // as does the unfilled limit quantity
+    assert!(engine.order_exists(ClientOrderId::from("O-BUY-1490")));
+    assert!(engine.order_exists(ClientOrderId::from("O-SELL-1520")));
+    assert!(engine.order_exists(ClientOrderId::from("O-SELL-1500")));
+    assert!(!engine.order_exists(ClientOrderId::from("O-BUY-1500")));
+    assert!(
+        !uncross_events
+            .iter()
+            .any(|event| event.event_type() == OrderEventType::Canceled)
+    );
+}
+
+#[rstest]
+#[case(SelfTradePrevention::CancelNewest, vec!["O-SELL-1500"], vec!["O-BUY-1500"])]
+#[case(SelfTradePrevention::CancelOldest, vec!["O-BUY-1500"], vec!["O-SELL-1500"])]
+#[case(SelfTradePrevention::CancelBoth, vec!["O-SELL-1500", "O-BUY-1500"], vec![])]
+fn test_auction_uncross_prevents_self_trade(
+    instrument_eth_usdt: InstrumentAny,
+    order_event_handler: ShareableMessageHandler,
+    mut msgbus: MessageBus,
+    account_id: AccountId,
+    #[case] mode: SelfTradePrevention,
+    #[case] expected_canceled: Vec<&str>,
+    #[case] expected_resting: Vec<&str>,
+) {
+    msgbus.register(
+        msgbus.switchboard.exec_engine_process,
+        order_event_handler.clone(),
+    );
+    let config = OrderMatchingEngineConfig::default()
+        .with_support_auctions(true)
+        .with_self_trade_prevention(Some(mode));
+    let mut engine = get_order_matching_engine(
+        instrument_eth_usdt.clone(),
+        Rc::new(RefCell::new(msgbus)),
+        None,
+        None,
+        Some(config),
+    );
+
+    // Same strategy buy and sell crossing at the clearing price of 1500.00
+    let auction_order = |client_order_id: &str, side: OrderSide| {
+        OrderTestBuilder::new(OrderType::Limit)
+            .instrument_id(instrument_eth_usdt.id())
+            .client_order_id(ClientOrderId::from(client_order_id))
+            .side(side)
+            .price(Price::from("1500.00"))
+            .quantity(Quantity::from("1.000"))
+            .build()
+    };
+    let mut orders = vec![
+        auction_order("O-BUY-1500", OrderSide::Buy),
+        auction_order("O-SELL-1500", OrderSide::Sell),
+    ];
+
+    engine.process_status(MarketStatusAction::PreOpen);
+    for order in &mut orders {
+        engine.process_order(order, account_id);
+    }
+    engine.process_status(MarketStatusAction::Trading);
+
+    let saved_messages = get_order_event_handler_messages(order_event_handler);
+    let uncross_events = &saved_messages[orders.len()..];
+    assert!(
+        !uncross_events
+            .iter()
+            .any(|event| event.event_type() == OrderEventType::Filled)
+    );
+    let canceled: Vec<ClientOrderId> = uncross_events
+        .iter()
+        .filter(|event| event.event_type() == OrderEventType::Canceled)
+        .map(OrderEventAny::client_order_id)
+        .collect();
+    let expected_canceled: Vec<ClientOrderId> = expected_canceled
+        .into_iter()
+        .map(ClientOrderId::from)
+        .collect();
+    assert_eq!(canceled, expected_canceled);
+    assert!(uncross_events[0].message().unwrap().starts_with(
+        "Self-trade prevented at auction uncross: order O-SELL-1500 would match order O-BUY-1500"
+    ));
+    for client_order_id in expected_resting {
+        assert!(engine.order_exists(ClientOrderId::from(client_order_id)));
+    }
+}
+
+#[rstest]
+fn test_auction_uncross_cancels_unfilled_market_orders(
+    instrument_eth_usdt: InstrumentAny,
+    order_event_handler: ShareableMessageHandler,
+    mut msgbus: MessageBus,
+    account_id: AccountId,
+) {
+    msgbus.register(
+        msgbus.switchboard.exec_engine_process,
+        order_event_handler.clone(),
+    );
+    let config = OrderMatchingEngineConfig::default().with_support_auctions(true);
+    let mut engine = get_order_matching_engine(
+        instrument_eth_usdt.clone(),
+        Rc::new(RefCell::new(msgbus)),
+        None,
+        None,
+        Some(config),
+    );
+    let mut market_order = OrderTestBuilder::new(OrderType::Market)
+        .instrument_id(instrument_eth_usdt.id())
+        .client_order_id(ClientOrderId::from("O-BUY-MKT"))
+        .side(OrderSide::Buy)
+        .quantity(Quantity::from("3.000"))
+        .build();
+    let mut limit_order = OrderTestBuilder::new(OrderType::Limit)
+        .instrument_id(instrument_eth_usdt.id())
+        .client_order_id(ClientOrderId::from("O-SELL-1500"))
+        .side(OrderSide::Sell)
+        .price(Price::from("1500.00"))
+        .quantity(Quantity::from("1.000"))
+        .build();
+
+    engine.process_status(MarketStatusAction::PreOpen);
+    engine.process_order(&mut market_order, account_id);
+    engine.process_order(&mut limit_order, account_id);
+    engine.process_status(MarketStatusAction::Trading);
+
+    let saved_messages = get_order_event_handler_messages(order_event_handler);
+    let event_types: Vec<OrderEventType> = saved_messages
+        .iter()
+        .map(|event| event.event_type())
+        .collect();
+    assert_eq!(
+        event_types,
+        vec![
+            OrderEventType::Accepted,
+            OrderEventType::Accepted,
+            OrderEventType::Filled,
+            OrderEventType::Filled,
+            OrderEventType::Canceled,
+        ]
+    );
+    assert_eq!(
+        saved_messages[4].client_order_id(),
+        market_order.client_order_id()
+    );
+    assert!(engine.get_open_orders().is_empty());
+}
+
+#[rstest]
+fn test_process_order_rejected_when_max_open_orders_per_account_exceeded(
+    instrument_eth_usdt: InstrumentAny,
+    mut msgbus: MessageBus,
+    order_event_handler: ShareableMessageHandler,
+    account_id: AccountId,
+) {
+    msgbus.register(
+        msgbus.switchboard.exec_engine_process,
+        order_event_handler.clone(),
+    );
+    let config = OrderMatchingEngineConfig::default().with_max_open_orders_per_account(Some(2));
+    let mut engine = get_order_matching_engine(
+        instrument_eth_usdt.clone(),
+        Rc::new(RefCell::new(msgbus)),
+        None,
+        None,
+        Some(config),
+    );
+
+    let mut orders: Vec<OrderAny> = (1..=3)
+        .map(|i| {
+            OrderTestBuilder::new(OrderType::Limit)
+                .instrument_id(instrument_eth_usdt.id())
+                .client_order_id(ClientOrderId::from(format!(
+                    "O-19700101-000000-001-001-{i}"
+                )))
+                .side(OrderSide::Buy)
+                .price(Price::from("1495.00"))
+                .quantity(Quantity::from("1.000"))
+                .build()
+        })
+        .collect();
+    for order in &mut orders {
+        engine.process_order(order, account_id);
+    }
+
+    let saved_messages = get_order_event_handler_messages(order_event_handler);
+    assert_eq!(saved_messages.len(), 3);
+    assert_eq!(saved_messages[0].event_type(), OrderEventType::Accepted);
+    assert_eq!(saved_messages[1].event_type(), OrderEventType::Accepted);
+    let order_rejected = match &saved_messages[2] {
+        OrderEventAny::Rejected(order_rejected) => order_rejected,
+        _ => panic!("Expected OrderRejected event in third message"),
+    };
+    assert_eq!(order_rejected.client_order_id, orders[2].client_order_id());
+    assert!(
+        order_rejected
+            .reason
+            .as_str()
+            .starts_with("Max open orders exceeded")
+    );
+    assert_eq!(engine.get_open_orders().len(), 2);
+}
+
+#[rstest]
+fn test_market_order_fills_match_book_simulation_across_multi_level_sweeps(
+    instrument_eth_usdt: InstrumentAny,
+    msgbus: MessageBus,
+    account_id: AccountId,
+) {
+    let mut engine_l2 = get_order_matching_engine_l2(
+        instrument_eth_usdt.clone(),
+        Rc::new(RefCell::new(msgbus)),
+        None,
+        None,
+        None,
+    );
+    let fills: Rc<RefCell<Vec<OrderFilled>>> = Rc::new(RefCell::new(Vec::new()));
+    let fills_clone = fills.clone();
+    engine_l2.on_fill(Box::new(move |fill: &OrderFilled| {
+        fills_clone.borrow_mut().push(*fill);
+    }));
+
+    // Mirror the engine book to compute the expected fills with the allocating simulation
+    let mut book = OrderBook::new(instrument_eth_usdt.id(), BookType::L2_MBP);
+    let mut add_level = |engine: &mut OrderMatchingEngine, price: &str, size: &str, id: u64| {
+        let delta = OrderBookDeltaTestBuilder::new(instrument_eth_usdt.id())
+            .book_action(BookAction::Add)
+            .book_order(BookOrder::new(
+                OrderSide::Sell,
+                Price::from(price),
+                Quantity::from(size),
+                id,
+            ))
+            .build();
+        book.apply_delta(&delta);
+        engine.process_order_book_delta(&delta);
+    };
+    add_level(&mut engine_l2, "1500.00", "1.000", 1);
+    add_level(&mut engine_l2, "1501.00", "2.000", 2);
+    add_level(&mut engine_l2, "1502.00", "3.000", 3);
+
+    // Sweeps of increasing depth, each reusing the engine's fills buffer
+    for (i, size) in ["0.500", "2.500", "5.000"].iter().enumerate() {
+        let mut market_order = OrderTestBuilder::new(OrderType::Market)
+            .instrument_id(instrument_eth_usdt.id())
+            .client_order_id(ClientOrderId::from(format!(
+                "O-19700101-000000-001-001-{i}"
+            )))
+            .side(OrderSide::Buy)
+            .quantity(Quantity::from(*size))
+            .build();
+        let expected = book.simulate_fills(&BookOrder::new(
+            OrderSide::Buy,
+            Price::max(FIXED_PRECISION),
+            market_order.quantity(),
+            0,
+        ));
+
+        fills.borrow_mut().clear();
+        engine_l2.process_order(&mut market_order, account_id);
+
+        let actual: Vec<(Price, Quantity)> = fills
+            .borrow()
+            .iter()
+            .map(|fill| (fill.last_px, fill.last_qty))
+            .collect();
+        assert_eq!(expected.len(), i + 1); // Sweeps one more level each time
+        assert_eq!(actual, expected);
+    }
+}
+
+#[rstest]
+fn test_process_order_book_delta_defers_triggers_until_last_snapshot_delta(
+    instrument_eth_usdt: InstrumentAny,
+    mut msgbus: MessageBus,
+    order_event_handler: ShareableMessageHandler,
+    account_id: AccountId,
+) {
+    msgbus.register(
+        msgbus.switchboard.exec_engine_process,
+        order_event_handler.clone(),
+    );
+    let mut engine_l2 = get_order_matching_engine_l2(
+        instrument_eth_usdt.clone(),
+        Rc::new(RefCell::new(msgbus)),
+        None,
+        None,
+        None,
+    );
+    let ask_delta = |price: &str, order_id: u64, flags: u8| {
+        OrderBookDeltaTestBuilder::new(instrument_eth_usdt.id())
+            .book_action(BookAction::Add)
+            .book_order(BookOrder::new(
+                OrderSide::Sell,
+                Price::from(price),
+                Quantity::from("1.000"),
+                order_id,
+            ))
+            .flags(flags)
+            .build()
+    };
+    let clear_delta = OrderBookDelta::clear(
+        instrument_eth_usdt.id(),
+        0,
+        UnixNanos::default(),
+        UnixNanos::default(),
+    );
+    let snapshot = RecordFlag::F_SNAPSHOT as u8;
+    let snapshot_last = RecordFlag::F_SNAPSHOT as u8 | RecordFlag::F_LAST as u8;
+
+    engine_l2.process_order_book_delta(&ask_delta("1500.00", 1, RecordFlag::F_LAST as u8));
+    let client_order_id = ClientOrderId::from("O-19700101-000000-001-001-1");
+    let mut stop_order = OrderTestBuilder::new(OrderType::StopMarket)
+        .instrument_id(instrument_eth_usdt.id())
+        .side(OrderSide::Buy)
+        .trigger_price(Price::from("1505.00"))
+        .quantity(Quantity::from("1.000"))
+        .client_order_id(client_order_id)
+        .build();
+    engine_l2.process_order(&mut stop_order, account_id);
+    assert_eq!(
+        get_order_event_handler_messages(order_event_handler.clone()).len(),
+        1
+    );
+
+    // Snapshot passing through an intermediate book (best ask 1510.00) which would trigger
+    // the stop, but ending with a best ask of 1500.00 which does not
+    engine_l2.process_order_book_delta(&clear_delta);
+    engine_l2.process_order_book_delta(&ask_delta("1510.00", 2, snapshot));
+    engine_l2.process_order_book_delta(&ask_delta("1500.00", 3, snapshot_last));
+    assert_eq!(
+        get_order_event_handler_messages(order_event_handler.clone()).len(),
+        1
+    );
+
+    // Snapshot ending with a book which triggers the stop, only on the last delta
+    engine_l2.process_order_book_delta(&clear_delta);
+    engine_l2.process_order_book_delta(&ask_delta("1510.00", 4, snapshot));
+    assert_eq!(
+        get_order_event_handler_messages(order_event_handler.clone()).len(),
+        1
+    );
+    engine_l2.process_order_book_delta(&ask_delta("1511.00", 5, snapshot_last));
+
+    let saved_messages = get_order_event_handler_messages(order_event_handler);
+    assert_eq!(saved_messages.len(), 2);
+    let order_filled = match saved_messages.get(1).unwrap() {
+        OrderEventAny::Filled(order_filled) => order_filled,
+        _ => panic!("Expected OrderFilled event in second message"),
+    };
+    assert_eq!(order_filled.client_order_id, client_order_id);
+    assert_eq!(order_filled.last_px, Price::from("1510.00"));
+}
+
+#[rstest]
+#[case::reject(false)]
+#[case::queue(true)]
+fn test_process_market_order_before_market_data(
+    instrument_eth_usdt: InstrumentAny,
+    mut msgbus: MessageBus,
+    order_event_handler: ShareableMessageHandler,
+    account_id: AccountId,
+    #[case] queue_market_orders_until_data: bool,
+) {
+    msgbus.register(
+        msgbus.switchboard.exec_engine_process,
+        order_event_handler.clone(),
+    );
+    let config = OrderMatchingEngineConfig::default()
+        .with_queue_market_orders_until_data(queue_market_orders_until_data);
+    let mut engine_l2 = get_order_matching_engine_l2(
+        instrument_eth_usdt.clone(),
+        Rc::new(RefCell::new(msgbus)),
+        None,
+        None,
+        Some(config),
+    );
+    let market_client_order_id = ClientOrderId::from("O-19700101-000000-001-001-1");
+    let mut market_order = OrderTestBuilder::new(OrderType::Market)
+        .instrument_id(instrument_eth_usdt.id())
+        .client_order_id(market_client_order_id)
+        .side(OrderSide::Buy)
+        .quantity(Quantity::from("1.000"))
+        .build();
+    let limit_client_order_id = ClientOrderId::from("O-19700101-000000-001-001-2");
+    let mut limit_order = OrderTestBuilder::new(OrderType::Limit)
+        .instrument_id(instrument_eth_usdt.id())
+        .client_order_id(limit_client_order_id)
+        .side(OrderSide::Buy)
+        .price(Price::from("1495.00"))
+        .quantity(Quantity::from("1.000"))
+        .build();
+
+    // Submit both orders before any market data
+    engine_l2.process_order(&mut market_order, account_id);
+    engine_l2.process_order(&mut limit_order, account_id);
+
+    let saved_messages = get_order_event_handler_messages(order_event_handler.clone());
+    assert_eq!(saved_messages.len(), 2);
+    let expected_market_event = if queue_market_orders_until_data {
+        OrderEventType::Accepted
+    } else {
+        OrderEventType::Rejected
+    };
+    assert_eq!(saved_messages[0].event_type(), expected_market_event);
+    assert_eq!(saved_messages[0].client_order_id(), market_client_order_id);
+    // Limit orders always rest when there is no market to match
+    assert_eq!(saved_messages[1].event_type(), OrderEventType::Accepted);
+    assert!(engine_l2.order_exists(limit_client_order_id));
+
+    let orderbook_delta_sell = OrderBookDeltaTestBuilder::new(instrument_eth_usdt.id())
+        .book_action(BookAction::Add)
+        .book_order(BookOrder::new(
+            OrderSide::Sell,
+            Price::from("1500.00"),
+            Quantity::from("1.000"),
+            1,
+        ))
+        .build();
+    engine_l2.process_order_book_delta(&orderbook_delta_sell);
+
+    let saved_messages = get_order_event_handler_messages(order_event_handler);
+    if queue_market_orders_until_data {
+        assert_eq!(saved_messages.len(), 3);
+        let order_filled = match &saved_messages[2] {
+            OrderEventAny::Filled(order_filled) => order_filled,
+            _ => panic!("Expected OrderFilled event in third message"),
+        };
+        assert_eq!(order_filled.client_order_id, market_client_order_id);
+        assert_eq!(order_filled.last_px, Price::from("1500.00"));
+        assert_eq!(order_filled.last_qty, Quantity::from("1.000"));
+    } else {
+        assert_eq!(saved_messages.len(), 2);
+    }
+}
+
+#[rstest]
+#[case::recorded(true)]
+#[case::not_recorded(false)]
+fn test_trades_log_contains_fills_in_order(
+    instrument_eth_usdt: InstrumentAny,
+    msgbus: MessageBus,
+    account_id: AccountId,
+    #[case] record_trades: bool,
+) {
+    let config = OrderMatchingEngineConfig::default().with_record_trades(record_trades);
+    let mut engine_l2 = get_order_matching_engine_l2(
+        instrument_eth_usdt.clone(),
+        Rc::new(RefCell::new(msgbus)),
+        None,
+        None,
+        Some(config),
+    );
+    for (i, price) in ["1500.00", "1501.00"].iter().enumerate() {
+        let orderbook_delta_sell = OrderBookDeltaTestBuilder::new(instrument_eth_usdt.id())
+            .book_action(BookAction::Add)
+            .book_order(BookOrder::new(
+                OrderSide::Sell,
+                Price::from(*price),
+                Quantity::from("1.000"),
+                i as u64,
+            ))
+            .build();
+        engine_l2.process_order_book_delta(&orderbook_delta_sell);
+    }
+
+    // A two level sweep followed by a single level fill
+    let mut market_order_1 = OrderTestBuilder::new(OrderType::Market)
+        .instrument_id(instrument_eth_usdt.id())
+        .client_order_id(ClientOrderId::from("O-19700101-000000-001-001-1"))
+        .side(OrderSide::Buy)
+        .quantity(Quantity::from("1.500"))
+        .build();
+    let mut market_order_2 = OrderTestBuilder::new(OrderType::Market)
+        .instrument_id(instrument_eth_usdt.id())
+        .client_order_id(ClientOrderId::from("O-19700101-000000-001-001-2"))
+        .side(OrderSide::Buy)
+        .quantity(Quantity::from("0.250"))
+        .build();
+    engine_l2.process_order(&mut market_order_1, account_id);
+    engine_l2.process_order(&mut market_order_2, account_id);
+
+    let trades: Vec<(ClientOrderId, Price, Quantity)> = engine_l2
+        .trades()
+        .iter()
+        .map(|fill| (fill.client_order_id, fill.last_px, fill.last_qty))
+        .collect();
+    if record_trades {
+        assert_eq!(
+            trades,
+            vec![
+                (
+                    market_order_1.client_order_id(),
+                    Price::from("1500.00"),
+                    Quantity::from("1.000")
+                ),
+                (
+                    market_order_1.client_order_id(),
+                    Price::from("1501.00"),
+                    Quantity::from("0.500")
+                ),
+                (
+                    market_order_2.client_order_id(),
+                    Price::from("1500.00"),
+                    Quantity::from("0.250")
+                ),
+            ]
+        );
+    } else {
+        assert!(trades.is_empty());
+    }
+}
+
+#[rstest]
+fn test_process_order_validates_price_against_tiered_tick_scheme(
+    instrument_eth_usdt: InstrumentAny,
+    mut msgbus: MessageBus,
+    order_event_handler: ShareableMessageHandler,
+    account_id: AccountId,
+) {
+    msgbus.register(
+        msgbus.switchboard.exec_engine_process,
+        order_event_handler.clone(),
+    );
+    let mut engine = get_order_matching_engine(
+        instrument_eth_usdt.clone(),
+        Rc::new(RefCell::new(msgbus)),
+        None,
+        None,
+        None,
+    );
+    // Ticks of 0.01 below 1000.00 and 0.50 from 1000.00
+    let tick_scheme = TieredTickScheme::new(vec![
+        (Price::from("0.00"), Price::from("0.01")),
+        (Price::from("1000.00"), Price::from("0.50")),
+    ])
+    .unwrap();
+    engine.set_tick_scheme(TickSchemeAny::Tiered(tick_scheme));
+
+    let limit_order = |client_order_id: &str, price: &str| {
+        OrderTestBuilder::new(OrderType::Limit)
+            .instrument_id(instrument_eth_usdt.id())
+            .client_order_id(ClientOrderId::from(client_order_id))
+            .side(OrderSide::Buy)
+            .price(Price::from(price))
+            .quantity(Quantity::from("1.000"))
+            .build()
+    };
+    let mut order_lower_band = limit_order("O-19700101-000000-001-001-1", "999.99");
+    let mut order_upper_band = limit_order("O-19700101-000000-001-001-2", "1000.01");
+    let mut order_upper_band_on_tick = limit_order("O-19700101-000000-001-001-3", "1000.50");
+    engine.process_order(&mut order_lower_band, account_id);
+    engine.process_order(&mut order_upper_band, account_id);
+    engine.process_order(&mut order_upper_band_on_tick, account_id);
+
+    let saved_messages = get_order_event_handler_messages(order_event_handler);
+    assert_eq!(saved_messages.len(), 3);
+    assert_eq!(saved_messages[0].event_type(), OrderEventType::Accepted);
+    assert_eq!(saved_messages[1].event_type(), OrderEventType::Rejected);
+    assert_eq!(
+        saved_messages[1].message().unwrap(),
+        Ustr::from(
+            "Invalid order price 1000.01 for order O-19700101-000000-001-001-2, not a multiple of tick size 0.50 for ETHUSDT-PERP.BINANCE"
+        )
+    );
+    assert_eq!(saved_messages[2].event_type(), OrderEventType::Accepted);
+}
+
+#[rstest]
+#[case::book_price(false, "1500.00")]
+#[case::own_price(true, "1501.00")]
+fn test_resting_limit_order_fill_price_when_market_crosses_through(
+    instrument_eth_usdt: InstrumentAny,
+    mut msgbus: MessageBus,
+    order_event_handler: ShareableMessageHandler,
+    account_id: AccountId,
+    #[case] resting_fills_at_own_price: bool,
+    #[case] expected_last_px: &str,
+) {
+    msgbus.register(
+        msgbus.switchboard.exec_engine_process,
+        order_event_handler.clone(),
+    );
+    let config = OrderMatchingEngineConfig::default()
+        .with_resting_fills_at_own_price(resting_fills_at_own_price);
+    let mut engine_l2 = get_order_matching_engine_l2(
+        instrument_eth_usdt.clone(),
+        Rc::new(RefCell::new(msgbus)),
+        None,
+        None,
+        Some(config),
+    );
+    let ask_delta = |price: &str, order_id: u64| {
+        OrderBookDeltaTestBuilder::new(instrument_eth_usdt.id())
+            .book_action(BookAction::Add)
+            .book_order(BookOrder::new(
+                OrderSide::Sell,
+                Price::from(price),
+                Quantity::from("1.000"),
+                order_id,
+            ))
+            .build()
+    };
+    engine_l2.process_order_book_delta(&ask_delta("1505.00", 1));
+
+    // BUY LIMIT at 1501.00 rests below the ask as a maker
+    let client_order_id = ClientOrderId::from("O-19700101-000000-001-001-1");
+    let mut limit_order = OrderTestBuilder::new(OrderType::Limit)
+        .instrument_id(instrument_eth_usdt.id())
+        .client_order_id(client_order_id)
+        .side(OrderSide::Buy)
+        .price(Price::from("1501.00"))
+        .quantity(Quantity::from("1.000"))
+        .build();
+    engine_l2.process_order(&mut limit_order, account_id);
+
+    // Aggressive sell liquidity arrives at 1500.00, through the resting buy
+    engine_l2.process_order_book_delta(&ask_delta("1500.00", 2));
+
+    let saved_messages = get_order_event_handler_messages(order_event_handler);
+    assert_eq!(saved_messages.len(), 2);
+    let order_filled = match &saved_messages[1] {
+        OrderEventAny::Filled(order_filled) => order_filled,
+        _ => panic!("Expected OrderFilled event in second message"),
+    };
+    assert_eq!(order_filled.client_order_id, client_order_id);
+    assert_eq!(order_filled.liquidity_side, LiquiditySide::Maker);
+    assert_eq!(order_filled.last_px, Price::from(expected_last_px));
+    assert_eq!(order_filled.last_qty, Quantity::from("1.000"));
+}
+
+#[rstest]
+#[case::improved(false, "1495.00")]
+#[case::capped(true, "1500.00")]
+fn test_taker_limit_order_fill_price_when_book_is_better(
+    instrument_eth_usdt: InstrumentAny,
+    mut msgbus: MessageBus,
+    order_event_handler: ShareableMessageHandler,
+    account_id: AccountId,
+    #[case] taker_fills_at_limit_price: bool,
+    #[case] expected_last_px: &str,
+) {
+    msgbus.register(
+        msgbus.switchboard.exec_engine_process,
+        order_event_handler.clone(),
+    );
+    let config = OrderMatchingEngineConfig::default()
+        .with_taker_fills_at_limit_price(taker_fills_at_limit_price);
+    let mut engine_l2 = get_order_matching_engine_l2(
+        instrument_eth_usdt.clone(),
+        Rc::new(RefCell::new(msgbus)),
+        None,
+        None,
+        Some(config),
+    );
+    let orderbook_delta_sell = OrderBookDeltaTestBuilder::new(instrument_eth_usdt.id())
+        .book_action(BookAction::Add)
+        .book_order(BookOrder::new(
+            OrderSide::Sell,
+            Price::from("1495.00"),
+            Quantity::from("1.000"),
+            1,
+        ))
+        .build();
+    engine_l2.process_order_book_delta(&orderbook_delta_sell);
+
+    // BUY LIMIT at 1500.00 is marketable against the cheaper ask
+    let client_order_id = ClientOrderId::from("O-19700101-000000-001-001-1");
+    let mut limit_order = OrderTestBuilder::new(OrderType::Limit)
+        .instrument_id(instrument_eth_usdt.id())
+        .client_order_id(client_order_id)
+        .side(OrderSide::Buy)
+        .price(Price::from("1500.00"))
+        .quantity(Quantity::from("1.000"))
+        .build();
+    engine_l2.process_order(&mut limit_order, account_id);
+
+    let saved_messages = get_order_event_handler_messages(order_event_handler);
+    let order_filled = saved_messages
+        .iter()
+        .find_map(|event| match event {
+            OrderEventAny::Filled(order_filled) => Some(order_filled),
+            _ => None,
+        })
+        .expect("Expected OrderFilled event");
+    assert_eq!(order_filled.client_order_id, client_order_id);
+    assert_eq!(order_filled.liquidity_side, LiquiditySide::Taker);
+    assert_eq!(order_filled.last_px, Price::from(expected_last_px));
+    assert_eq!(order_filled.last_qty, Quantity::from("1.000"));
+}
+
+#[rstest]
+#[case::stop_on_touch(OrderType::StopMarket, ("1502.00", "1503.00"), true)]
+#[case::stop_through(OrderType::StopMarket, ("1502.00", "1503.00"), false)]
+#[case::mit_on_touch(OrderType::MarketIfTouched, ("1498.00", "1497.00"), true)]
+#[case::mit_through(OrderType::MarketIfTouched, ("1498.00", "1497.00"), false)]
+fn test_trigger_at_exact_touch(
+    instrument_eth_usdt: InstrumentAny,
+    mut msgbus: MessageBus,
+    order_event_handler: ShareableMessageHandler,
+    account_id: AccountId,
+    #[case] order_type: OrderType,
+    #[case] (trigger_price, through_price): (&str, &str),
+    #[case] trigger_on_touch: bool,
+) {
+    msgbus.register(
+        msgbus.switchboard.exec_engine_process,
+        order_event_handler.clone(),
+    );
+    let config = OrderMatchingEngineConfig::default().with_trigger_on_touch(trigger_on_touch);
+    // Stops at the trigger price always fill, to isolate the trigger rule
+    let fill_model = FillModel::new(1.0, 1.0, 0.0, None).unwrap();
+    let mut engine_l2 = OrderMatchingEngine::new(
+        instrument_eth_usdt.clone(),
+        1,
+        fill_model,
+        FeeModelAny::default(),
+        BookType::L2_MBP,
+        OmsType::Netting,
+        AccountType::Cash,
+        &ATOMIC_TIME,
+        Rc::new(RefCell::new(msgbus)),
+        Rc::new(RefCell::new(Cache::default())),
+        config,
+    );
+    let ask_delta = |action: BookAction, price: &str| {
+        OrderBookDeltaTestBuilder::new(instrument_eth_usdt.id())
+            .book_action(action)
+            .book_order(BookOrder::new(
+                OrderSide::Sell,
+                Price::from(price),
+                Quantity::from("1.000"),
+                1,
+            ))
+            .build()
+    };
+    engine_l2.process_order_book_delta(&ask_delta(BookAction::Add, "1500.00"));
+
+    let mut order = OrderTestBuilder::new(order_type)
+        .instrument_id(instrument_eth_usdt.id())
+        .side(OrderSide::Buy)
+        .trigger_price(Price::from(trigger_price))
+        .quantity(Quantity::from("1.000"))
+        .build();
+    engine_l2.process_order(&mut order, account_id);
+
+    // Ask moves to exactly the trigger price
+    engine_l2.process_order_book_delta(&ask_delta(BookAction::Delete, "1500.00"));
+    engine_l2.process_order_book_delta(&ask_delta(BookAction::Add, trigger_price));
+
+    let filled_count = |handler: &ShareableMessageHandler| {
+        get_order_event_handler_messages(handler.clone())
+            .iter()
+            .filter(|event| event.event_type() == OrderEventType::Filled)
+            .count()
+    };
+    assert_eq!(
+        filled_count(&order_event_handler),
+        usize::from(trigger_on_touch)
+    );
+
+    // Ask moves through the trigger price, which always triggers
+    if !trigger_on_touch {
+        engine_l2.process_order_book_delta(&ask_delta(BookAction::Delete, trigger_price));
+        engine_l2.process_order_book_delta(&ask_delta(BookAction::Add, through_price));
+        assert_eq!(filled_count(&order_event_handler), 1);
+    }
+}
+
+#[rstest]
+fn test_publish_top_of_book_quote_when_deltas_change_top_of_book(
+    instrument_eth_usdt: InstrumentAny,
+    mut msgbus: MessageBus,
+) {
+    let quote_handler = get_message_saving_handler::<QuoteTick>(None);
+    let topic = msgbus
+        .switchboard
+        .get_quotes_topic(instrument_eth_usdt.id());
+    msgbus.subscribe(topic, quote_handler.clone(), None);
+    let config = OrderMatchingEngineConfig::default().with_publish_top_of_book_quotes(true);
+    let mut engine_l2 = get_order_matching_engine_l2(
+        instrument_eth_usdt.clone(),
+        Rc::new(RefCell::new(msgbus)),
+        None,
+        None,
+        Some(config),
+    );
+    let delta = |side: OrderSide, price: &str, order_id: u64| {
+        OrderBookDeltaTestBuilder::new(instrument_eth_usdt.id())
+            .book_action(BookAction::Add)
+            .book_order(BookOrder::new(
+                side,
+                Price::from(price),
+                Quantity::from("1.000"),
+                order_id,
+            ))
+            .build()
+    };
+
+    // No quote until both sides of the book exist
+    engine_l2.process_order_book_delta(&delta(OrderSide::Buy, "1495.00", 1));
+    engine_l2.process_order_book_delta(&delta(OrderSide::Sell, "1500.00", 2));
+    // Deeper level leaves the top-of-book unchanged
+    engine_l2.process_order_book_delta(&delta(OrderSide::Sell, "1501.00", 3));
+    engine_l2.process_order_book_delta(&delta(OrderSide::Buy, "1496.00", 4));
+
+    let quotes = get_saved_messages::<QuoteTick>(quote_handler);
+    let top_of_books: Vec<(Price, Price)> = quotes
+        .iter()
+        .map(|quote| (quote.bid_price, quote.ask_price))
+        .collect();
+    assert_eq!(
+        top_of_books,
+        vec![
+            (Price::from("1495.00"), Price::from("1500.00")),
+            (Price::from("1496.00"), Price::from("1500.00")),
+        ]
+    );
+    assert!(
+        quotes
+            .iter()
+            .all(|quote| quote.instrument_id == instrument_eth_usdt.id())
+    );
+}
+
+#[rstest]
+fn test_book_changed_callback_fires_on_book_updates(
+    instrument_eth_usdt: InstrumentAny,
+    msgbus: MessageBus,
+) {
+    let mut engine_l2 = get_order_matching_engine_l2(
+        instrument_eth_usdt.clone(),
+        Rc::new(RefCell::new(msgbus)),
+        None,
+        None,
+        None,
+    );
+    let book_changes: Rc<RefCell<Vec<BookChanged>>> = Rc::new(RefCell::new(Vec::new()));
+    let book_changes_clone = book_changes.clone();
+    engine_l2.on_book_changed(Box::new(move |book_changed| {
+        book_changes_clone.borrow_mut().push(*book_changed);
+    }));
+    let delta = |side: OrderSide, price: &str, size: &str, order_id: u64| {
+        OrderBookDeltaTestBuilder::new(instrument_eth_usdt.id())
+            .book_action(BookAction::Add)
+            .book_order(BookOrder::new(
+                side,
+                Price::from(price),
+                Quantity::from(size),
+                order_id,
+            ))
+            .build()
+    };
+
+    engine_l2.process_order_book_delta(&delta(OrderSide::Buy, "1495.00", "1.000", 1));
+    engine_l2.process_order_book_delta(&delta(OrderSide::Sell, "1500.00", "2.000", 2));
+    // Deeper level changes the depth while leaving the top-of-book unchanged
+    engine_l2.process_order_book_delta(&delta(OrderSide::Sell, "1501.00", "3.000", 3));
+    engine_l2.process_order_book_delta(&delta(OrderSide::Buy, "1496.00", "4.000", 4));
+
+    let book_changes = book_changes.borrow();
+    let top_of_books: Vec<_> = book_changes
+        .iter()
+        .map(|book_changed| {
+            (
+                book_changed.bid_price,
+                book_changed.ask_price,
+                book_changed.bid_size,
+                book_changed.ask_size,
+            )
+        })
+        .collect();
+    assert_eq!(
+        top_of_books,
+        vec![
+            (
+                Some(Price::from("1495.00")),
+                None,
+                Some(Quantity::from("1.000")),
+                None
+            ),
+            (
+                Some(Price::from("1495.00")),
+                Some(Price::from("1500.00")),
+                Some(Quantity::from("1.000")),
+                Some(Quantity::from("2.000"))
+            ),
+            (
+                Some(Price::from("1495.00")),
+                Some(Price::from("1500.00")),
+                Some(Quantity::from("1.000")),
+                Some(Quantity::from("2.000"))
+            ),
+            (
+                Some(Price::from("1496.00")),
+                Some(Price::from("1500.00")),
+                Some(Quantity::from("4.000")),
+                Some(Quantity::from("2.000"))
+            ),
+        ]
+    );
+    assert!(
+        book_changes
+            .iter()
+            .all(|book_changed| book_changed.instrument_id == instrument_eth_usdt.id())
+    );
+}
+
+#[rstest]
+fn test_process_mark_price_update_triggers_mark_stop_and_marks_pnl(
+    instrument_eth_usdt: InstrumentAny,
+    mut msgbus: MessageBus,
+    order_event_handler: ShareableMessageHandler,
+    account_id: AccountId,
+    market_order_buy: OrderAny,
+) {
+    msgbus.register(
+        msgbus.switchboard.exec_engine_process,
+        order_event_handler.clone(),
+    );
+    let cache = Rc::new(RefCell::new(Cache::default()));
+    let mut engine_l2 = get_order_matching_engine_l2(
+        instrument_eth_usdt.clone(),
+        Rc::new(RefCell::new(msgbus)),
+        Some(cache.clone()),
+        None,
+        None,
+    );
+    let instrument_id = instrument_eth_usdt.id();
+
+    // Open a 1.000 ETH long position at 1500.00
+    let fill = TestOrderEventStubs::order_filled(
+        &market_order_buy,
+        &instrument_eth_usdt,
+        None,
+        None,
+        Some(Price::from("1500.00")),
+        Some(Quantity::from("1.000")),
+        None,
+        Some(Money::from("0 USDT")),
+        None,
+        None,
+        None,
+    );
+    let OrderEventAny::Filled(fill) = fill else {
+        panic!("Expected `OrderFilled` event");
+    };
+    cache
+        .borrow_mut()
+        .add_position(Position::new(&instrument_eth_usdt, fill), OmsType::Netting)
+        .unwrap();
+
+    for (side, price, order_id) in [
+        (OrderSide::Buy, "1495.00", 1),
+        (OrderSide::Sell, "1505.00", 2),
+    ] {
+        let delta = OrderBookDeltaTestBuilder::new(instrument_id)
+            .book_action(BookAction::Add)
+            .book_order(BookOrder::new(
+                side,
+                Price::from(price),
+                Quantity::from("1.000"),
+                order_id,
+            ))
+            .build();
+        engine_l2.process_order_book_delta(&delta);
+    }
+
+    // SELL STOP_MARKET triggered by the mark price, well below the bid
+    let client_order_id = ClientOrderId::from("O-19700101-000000-001-001-1");
+    let mut stop_order = OrderTestBuilder::new(OrderType::StopMarket)
+        .instrument_id(instrument_id)
+        .client_order_id(client_order_id)
+        .side(OrderSide::Sell)
+        .trigger_price(Price::from("1490.00"))
+        .trigger_type(TriggerType::MarkPrice)
+        .quantity(Quantity::from("1.000"))
+        .build();
+    engine_l2.process_order(&mut stop_order, account_id);
+
+    // Mark above the trigger, order keeps resting and PnL uses the mark
+    engine_l2.process_mark_price_update(Price::from("1491.00"));
+    assert!(engine_l2.order_exists(client_order_id));
+    assert_eq!(
+        engine_l2.unrealized_pnl(instrument_id),
+        Some(Money::from("-9 USDT"))
+    );
+
+    // Mark through the trigger fires the stop against the book
+    engine_l2.process_mark_price_update(Price::from("1489.00"));
+    assert_eq!(
+        engine_l2.unrealized_pnl(instrument_id),
+        Some(Money::from("-11 USDT"))
+    );
+
+    let saved_messages = get_order_event_handler_messages(order_event_handler);
+    assert_eq!(saved_messages.len(), 2);
+    let order_filled = match &saved_messages[1] {
+        OrderEventAny::Filled(order_filled) => order_filled,
+        _ => panic!("Expected OrderFilled event in second message"),
+    };
+    assert_eq!(order_filled.client_order_id, client_order_id);
+    assert_eq!(order_filled.last_px, Price::from("1495.00"));
+    assert_eq!(order_filled.last_qty, Quantity::from("1.000"));
+}
+
+#[rstest]
+#[case::mit(OrderType::MarketIfTouched, false)]
+#[case::mit_trigger_on_touch(OrderType::MarketIfTouched, true)]
+#[case::lit(OrderType::LimitIfTouched, false)]
+#[case::lit_trigger_on_touch(OrderType::LimitIfTouched, true)]
+fn test_process_mark_price_update_triggers_mark_if_touched_orders(
+    instrument_eth_usdt: InstrumentAny,
+    mut msgbus: MessageBus,
+    order_event_handler: ShareableMessageHandler,
+    account_id: AccountId,
+    #[case] order_type: OrderType,
+    #[case] trigger_on_touch: bool,
+) {
+    msgbus.register(
+        msgbus.switchboard.exec_engine_process,
+        order_event_handler.clone(),
+    );
+    let config = OrderMatchingEngineConfig::default().with_trigger_on_touch(trigger_on_touch);
+    let mut engine_l2 = get_order_matching_engine_l2(
+        instrument_eth_usdt.clone(),
+        Rc::new(RefCell::new(msgbus)),
+        None,
+        None,
+        Some(config),
+    );
+    // Stops at the trigger price always fill, to isolate the trigger rule
+    engine_l2.set_fill_model(FillModel::new(1.0, 1.0, 0.0, None).unwrap());
+    let instrument_id = instrument_eth_usdt.id();
+
+    for (side, price, order_id) in [
+        (OrderSide::Buy, "1495.00", 1),
+        (OrderSide::Sell, "1505.00", 2),
+    ] {
+        let delta = OrderBookDeltaTestBuilder::new(instrument_id)
+            .book_action(BookAction::Add)
+            .book_order(BookOrder::new(
+                side,
+                Price::from(price),
+                Quantity::from("1.000"),
+                order_id,
+            ))
+            .build();
+        engine_l2.process_order_book_delta(&delta);
+    }
+
+    // SELL if-touched order triggered by the mark price rising to 1510.00, well above the ask
+    let mut builder = OrderTestBuilder::new(order_type);
+    builder
+        .instrument_id(instrument_id)
+        .side(OrderSide::Sell)
+        .trigger_price(Price::from("1510.00"))
+        .trigger_type(TriggerType::MarkPrice)
+        .quantity(Quantity::from("1.000"));
+    if order_type == OrderType::LimitIfTouched {
+        builder.price(Price::from("1510.00"));
+    }
+    let mut order = builder.build();
+    engine_l2.process_order(&mut order, account_id);
+
+    // A triggered MIT fills against the book, a triggered LIT rests at its limit price
+    let triggered_event_type = match order_type {
+        OrderType::MarketIfTouched => OrderEventType::Filled,
+        _ => OrderEventType::Triggered,
+    };
+    let event_types = || -> Vec<OrderEventType> {
+        get_order_event_handler_messages(order_event_handler.clone())
+            .iter()
+            .map(OrderEventAny::event_type)
+            .collect()
+    };
+
+    // Mark below the trigger, the order keeps resting
+    engine_l2.process_mark_price_update(Price::from("1509.00"));
+    assert_eq!(event_types(), vec![OrderEventType::Accepted]);
+
+    // Mark at the trigger only triggers the order on touch
+    engine_l2.process_mark_price_update(Price::from("1510.00"));
+    assert_eq!(event_types().len(), 1 + usize::from(trigger_on_touch));
+
+    // Mark through the trigger
+    engine_l2.process_mark_price_update(Price::from("1511.00"));
+    assert_eq!(
+        event_types(),
+        vec![OrderEventType::Accepted, triggered_event_type]
+    );
+}
+
+#[rstest]
+fn test_process_modify_rejected_when_instrument_mismatch(
+    instrument_eth_usdt: InstrumentAny,
+    mut msgbus: MessageBus,
+    order_event_handler: ShareableMessageHandler,
+    account_id: AccountId,
+) {
+    msgbus.register(
+        msgbus.switchboard.exec_engine_process,
+        order_event_handler.clone(),
+    );
+    let mut engine_l2 = get_order_matching_engine_l2(
+        instrument_eth_usdt.clone(),
+        Rc::new(RefCell::new(msgbus)),
+        None,
+        None,
+        None,
+    );
+
+    let client_order_id = ClientOrderId::from("O-19700101-000000-001-001-1");
+    let mut limit_order = OrderTestBuilder::new(OrderType::Limit)
+        .instrument_id(instrument_eth_usdt.id())
+        .side(OrderSide::Buy)
+        .price(Price::from("1495.00"))
+        .quantity(Quantity::from("1.000"))
+        .client_order_id(client_order_id)
+        .build();
+    engine_l2.process_order(&mut limit_order, account_id);
+
+    let other_instrument_id = InstrumentId::from("BTCUSDT-PERP.BINANCE");
+    let modify_order_command = ModifyOrder::new(
+        TraderId::from("TRADER-001"),
+        ClientId::from("CLIENT-001"),
+        StrategyId::from("STRATEGY-001"),
+        other_instrument_id,
+        client_order_id,
+        VenueOrderId::from("V1"),
+        None,
+        Some(Price::from("1496.00")),
+        None,
+        UUID4::new(),
+        UnixNanos::default(),
+    )
+    .unwrap();
+    engine_l2.process_modify(&modify_order_command, account_id);
+
+    let saved_messages = get_order_event_handler_messages(order_event_handler);
+    assert_eq!(saved_messages.len(), 2);
+    let order_rejected = match &saved_messages[1] {
+        OrderEventAny::ModifyRejected(order_rejected) => order_rejected,
+        _ => panic!("Expected OrderModifyRejected event in second message"),
+    };
+    assert_eq!(order_rejected.client_order_id, client_order_id);
+    assert_eq!(order_rejected.instrument_id, other_instrument_id);
+    assert_eq!(
+        order_rejected.reason,
+        Ustr::from(
+            "Instrument ID mismatch: modify BTCUSDT-PERP.BINANCE for order O-19700101-000000-001-001-1, engine ETHUSDT-PERP.BINANCE"
+        )
+    );
+    assert_eq!(
+        order_rejected.reason_code,
+        Some(ModifyRejectReason::InstrumentMismatch)
+    );
+
+    // Resting order is unchanged
+    let resting_orders = engine_l2.get_open_bid_orders();
+    assert_eq!(resting_orders.len(), 1);
+    assert_eq!(
+        resting_orders[0].to_any().price(),
+        Some(Price::from("1495.00"))
+    );
+}
+
+#[rstest]
+fn test_process_modify_rejected_when_max_modifies_exceeded(
+    instrument_eth_usdt: InstrumentAny,
+    mut msgbus: MessageBus,
+    order_event_handler: ShareableMessageHandler,
+    account_id: AccountId,
+) {
+    msgbus.register(
+        msgbus.switchboard.exec_engine_process,
+        order_event_handler.clone(),
+    );
+    let config = OrderMatchingEngineConfig::default().with_max_modifies_per_order(Some(2));
+    let mut engine_l2 = get_order_matching_engine_l2(
+        instrument_eth_usdt.clone(),
+        Rc::new(RefCell::new(msgbus)),
+        None,
+        None,
+        Some(config),
+    );
+
+    let client_order_id = ClientOrderId::from("O-19700101-000000-001-001-1");
+    let mut limit_order = OrderTestBuilder::new(OrderType::Limit)
+        .instrument_id(instrument_eth_usdt.id())
+        .side(OrderSide::Buy)
+        .price(Price::from("1495.00"))
+        .quantity(Quantity::from("1.000"))
+        .client_order_id(client_order_id)
+        .build();
+    engine_l2.process_order(&mut limit_order, account_id);
+
+    for price in ["1494.00", "1493.00", "1492.00"] {
+        let modify_order_command = ModifyOrder::new(
+            TraderId::from("TRADER-001"),
+            ClientId::from("CLIENT-001"),
+            StrategyId::from("STRATEGY-001"),
+            instrument_eth_usdt.id(),
+            client_order_id,
+            VenueOrderId::from("V1"),
+            None,
+            Some(Price::from(price)),
+            None,
+            UUID4::new(),
+            UnixNanos::default(),
+        )
+        .unwrap();
+        engine_l2.process_modify(&modify_order_command, account_id);
+    }
+
+    let saved_messages = get_order_event_handler_messages(order_event_handler);
+    let expected_event_types = [
+        OrderEventType::Accepted,
+        OrderEventType::Updated,
+        OrderEventType::Updated,
+        OrderEventType::ModifyRejected,
+    ];
+    assert_eq!(saved_messages.len(), expected_event_types.len());
+    for (event, event_type) in saved_messages.iter().zip(expected_event_types.iter()) {
+        assert_eq!(&event.event_type(), event_type);
+    }
+    let order_rejected = match saved_messages.last().unwrap() {
+        OrderEventAny::ModifyRejected(order_rejected) => order_rejected,
+        _ => panic!("Expected OrderModifyRejected event in last message"),
+    };
+    assert_eq!(
+        order_rejected.reason,
+        Ustr::from(
+            "Modify rate exceeded: order O-19700101-000000-001-001-1 reached max modifies per order of 2"
+        )
+    );
+    assert_eq!(
+        order_rejected.reason_code,
+        Some(ModifyRejectReason::RateExceeded)
+    );
+
+    // Last accepted modification is the second one
+    let order_updated = match &saved_messages[2] {
+        OrderEventAny::Updated(order_updated) => order_updated,
+        _ => panic!("Expected OrderUpdated event in third message"),
+    };
+    assert_eq!(order_updated.price, Some(Price::from("1493.00")));
+}
+
+#[rstest]
+fn test_process_modify_stop_limit_rejected_when_price_incompatible_with_trigger(
+    instrument_eth_usdt: InstrumentAny,
+    mut msgbus: MessageBus,
+    order_event_handler: ShareableMessageHandler,
+    account_id: AccountId,
+) {
+    msgbus.register(
+        msgbus.switchboard.exec_engine_process,
+        order_event_handler.clone(),
+    );
+    let mut engine_l2 = get_order_matching_engine_l2(
+        instrument_eth_usdt.clone(),
+        Rc::new(RefCell::new(msgbus)),
+        None,
+        None,
+        None,
+    );
+
+    let orderbook_delta_sell = OrderBookDeltaTestBuilder::new(instrument_eth_usdt.id())
+        .book_action(BookAction::Add)
+        .book_order(BookOrder::new(
+            OrderSide::Sell,
+            Price::from("1500.00"),
+            Quantity::from("1.000"),
+            1,
+        ))
+        .build();
+    engine_l2.process_order_book_delta(&orderbook_delta_sell);
+
+    let client_order_id = ClientOrderId::from("O-19700101-000000-001-001-1");
+    let mut stop_order = OrderTestBuilder::new(OrderType::StopLimit)
+        .instrument_id(instrument_eth_usdt.id())
+        .side(OrderSide::Buy)
+        .trigger_price(Price::from("1505.00"))
+        .price(Price::from("1506.00"))
+        .quantity(Quantity::from("1.000"))
+        .client_order_id(client_order_id)
+        .build();
+    engine_l2.process_order(&mut stop_order, account_id);
+
+    // Modify limit price below the untriggered stop price of the buy order
+    let modify_order_command = ModifyOrder::new(
+        TraderId::from("TRADER-001"),
+        ClientId::from("CLIENT-001"),
+        StrategyId::from("STRATEGY-001"),
+        instrument_eth_usdt.id(),
+        client_order_id,
+        VenueOrderId::from("V1"),
+        None,
+        Some(Price::from("1504.00")),
+        None,
+        UUID4::new(),
+        UnixNanos::default(),
+    )
+    .unwrap();
+    engine_l2.process_modify(&modify_order_command, account_id);
+
+    let saved_messages = get_order_event_handler_messages(order_event_handler);
+    assert_eq!(saved_messages.len(), 2);
+    let order_rejected = match saved_messages.get(1).unwrap() {
+        OrderEventAny::ModifyRejected(order_rejected) => order_rejected,
+        _ => panic!("Expected OrderModifyRejected event in second message"),
+    };
+    assert_eq!(
+        order_rejected.reason,
+        Ustr::from(
+            "STOP_LIMIT BUY order new limit px of 1504.00 was incompatible with new stop px of 1505.00"
+        )
+    );
+    assert_eq!(
+        order_rejected.reason_code,
+        Some(ModifyRejectReason::InvalidPriceTrigger)
+    );
+}
+
+#[rstest]
+fn test_process_modify_stop_limit_quantity_only_accepted(
+    instrument_eth_usdt: InstrumentAny,
+    mut msgbus: MessageBus,
+    order_event_handler: ShareableMessageHandler,
+    account_id: AccountId,
+) {
+    msgbus.register(
+        msgbus.switchboard.exec_engine_process,
+        order_event_handler.clone(),
+    );
+    let mut engine_l2 = get_order_matching_engine_l2(
+        instrument_eth_usdt.clone(),
+        Rc::new(RefCell::new(msgbus)),
+        None,
+        None,
+        None,
+    );
+
+    let orderbook_delta_sell = OrderBookDeltaTestBuilder::new(instrument_eth_usdt.id())
+        .book_action(BookAction::Add)
+        .book_order(BookOrder::new(
+            OrderSide::Sell,
+            Price::from("1500.00"),
+            Quantity::from("1.000"),
+            1,
+        ))
+        .build();
+    engine_l2.process_order_book_delta(&orderbook_delta_sell);
+
+    let client_order_id = ClientOrderId::from("O-19700101-000000-001-001-1");
+    let mut stop_order = OrderTestBuilder::new(OrderType::StopLimit)
+        .instrument_id(instrument_eth_usdt.id())
+        .side(OrderSide::Buy)
+        .trigger_price(Price::from("1505.00"))
+        .price(Price::from("1506.00"))
+        .quantity(Quantity::from("1.000"))
+        .client_order_id(client_order_id)
+        .build();
+    engine_l2.process_order(&mut stop_order, account_id);
+
+    let modify_order_command = ModifyOrder::new(
+        TraderId::from("TRADER-001"),
+        ClientId::from("CLIENT-001"),
+        StrategyId::from("STRATEGY-001"),
+        instrument_eth_usdt.id(),
+        client_order_id,
+        VenueOrderId::from("V1"),
+        Some(Quantity::from("2.000")),
+        None,
+        None,
+        UUID4::new(),
+        UnixNanos::default(),
+    )
+    .unwrap();
+    engine_l2.process_modify(&modify_order_command, account_id);
+
+    let saved_messages = get_order_event_handler_messages(order_event_handler);
+    assert_eq!(saved_messages.len(), 2);
+    let order_updated = match saved_messages.get(1).unwrap() {
+        OrderEventAny::Updated(order_updated) => order_updated,
+        _ => panic!("Expected OrderUpdated event in second message"),
+    };
+    assert_eq!(order_updated.quantity, Quantity::from("2.000"));
+    assert_eq!(order_updated.price, Some(Price::from("1506.00")));
+    assert_eq!(order_updated.trigger_price, Some(Price::from("1505.00")));
+}
+
+#[rstest]
+fn test_fully_hidden_limit_order_fills_but_is_not_in_book_depth(
+    instrument_eth_usdt: InstrumentAny,
+    mut msgbus: MessageBus,
+    order_event_handler: ShareableMessageHandler,
+    account_id: AccountId,
+) {
+    msgbus.register(
+        msgbus.switchboard.exec_engine_process,
+        order_event_handler.clone(),
+    );
+    let mut engine_l2 = get_order_matching_engine_l2(
+        instrument_eth_usdt.clone(),
+        Rc::new(RefCell::new(msgbus)),
+        None,
+        None,
+        None,
+    );
+
+    let orderbook_delta_sell = OrderBookDeltaTestBuilder::new(instrument_eth_usdt.id())
+        .book_action(BookAction::Add)
+        .book_order(BookOrder::new(
+            OrderSide::Sell,
+            Price::from("1500.00"),
+            Quantity::from("1.000"),
+            1,
+        ))
+        .build();
+    engine_l2.process_order_book_delta(&orderbook_delta_sell);
+
+    let client_order_id_hidden = ClientOrderId::from("O-19700101-000000-001-001-1");
+    let mut hidden_order = OrderTestBuilder::new(OrderType::Limit)
+        .instrument_id(instrument_eth_usdt.id())
+        .client_order_id(client_order_id_hidden)
+        .side(OrderSide::Buy)
+        .price(Price::from("1495.00"))
+        .quantity(Quantity::from("1.000"))
+        .display_qty(Quantity::from("0.000"))
+        .build();
+    engine_l2.process_order(&mut hidden_order, account_id);
+    let client_order_id_visible = ClientOrderId::from("O-19700101-000000-001-001-2");
+    let mut visible_order = OrderTestBuilder::new(OrderType::Limit)
+        .instrument_id(instrument_eth_usdt.id())
+        .client_order_id(client_order_id_visible)
+        .side(OrderSide::Buy)
+        .price(Price::from("1490.00"))
+        .quantity(Quantity::from("2.000"))
+        .display_qty(Quantity::from("0.500"))
+        .build();
+    engine_l2.process_order(&mut visible_order, account_id);
+
+    // Only the displayed quantity of the visible order is exposed
+    assert_eq!(engine_l2.get_open_bid_orders().len(), 2);
+    assert_eq!(
+        engine_l2.book_depth(OrderSide::Buy),
+        vec![(Price::from("1490.00"), Quantity::from("0.500"))]
+    );
+
+    // Aggressive sell liquidity moves the ask through the hidden order
+    let orderbook_delta_sell = OrderBookDeltaTestBuilder::new(instrument_eth_usdt.id())
+        .book_action(BookAction::Add)
+        .book_order(BookOrder::new(
+            OrderSide::Sell,
+            Price::from("1494.00"),
+            Quantity::from("1.000"),
+            2,
+        ))
+        .build();
+    engine_l2.process_order_book_delta(&orderbook_delta_sell);
+
+    let saved_messages = get_order_event_handler_messages(order_event_handler);
+    assert_eq!(saved_messages.len(), 3);
+    let order_filled = match saved_messages.last().unwrap() {
+        OrderEventAny::Filled(order_filled) => order_filled,
+        _ => panic!("Expected OrderFilled event in last message"),
+    };
+    assert_eq!(order_filled.client_order_id, client_order_id_hidden);
+    assert_eq!(order_filled.last_qty, Quantity::from("1.000"));
+}
+
+#[rstest]
+fn test_visible_limit_order_fills_before_iceberg_hidden_quantity_at_same_price(
+    instrument_eth_usdt: InstrumentAny,
+    mut msgbus: MessageBus,
+    order_event_handler: ShareableMessageHandler,
+    account_id: AccountId,
+) {
+    msgbus.register(
+        msgbus.switchboard.exec_engine_process,
+        order_event_handler.clone(),
+    );
+    let mut engine_l2 = get_order_matching_engine_l2(
+        instrument_eth_usdt.clone(),
+        Rc::new(RefCell::new(msgbus)),
+        None,
+        None,
+        None,
+    );
+
+    // Iceberg BUY LIMIT arrives first, displaying only 0.500 of 2.000
+    let iceberg_client_order_id = ClientOrderId::from("O-19700101-000000-001-001-1");
+    let mut iceberg_order = OrderTestBuilder::new(OrderType::Limit)
+        .instrument_id(instrument_eth_usdt.id())
+        .client_order_id(iceberg_client_order_id)
+        .side(OrderSide::Buy)
+        .price(Price::from("1495.00"))
+        .quantity(Quantity::from("2.000"))
+        .display_qty(Quantity::from("0.500"))
+        .build();
+    engine_l2.process_order(&mut iceberg_order, account_id);
+
+    // Fully visible BUY LIMIT at the same price arrives later
+    let visible_client_order_id = ClientOrderId::from("O-19700101-000000-001-001-2");
+    let mut visible_order = OrderTestBuilder::new(OrderType::Limit)
+        .instrument_id(instrument_eth_usdt.id())
+        .client_order_id(visible_client_order_id)
+        .side(OrderSide::Buy)
+        .price(Price::from("1495.00"))
+        .quantity(Quantity::from("1.000"))
+        .build();
+    engine_l2.process_order(&mut visible_order, account_id);
+
+    // Sell liquidity arrives at the level
+    let orderbook_delta_sell = OrderBookDeltaTestBuilder::new(instrument_eth_usdt.id())
+        .book_action(BookAction::Add)
+        .book_order(BookOrder::new(
+            OrderSide::Sell,
+            Price::from("1495.00"),
+            Quantity::from("5.000"),
+            1,
+        ))
+        .build();
+    engine_l2.process_order_book_delta(&orderbook_delta_sell);
+
+    let saved_messages = get_order_event_handler_messages(order_event_handler);
+    let filled_order_ids: Vec<ClientOrderId> = saved_messages
+        .iter()
+        .filter_map(|event| match event {
+            OrderEventAny::Filled(order_filled) => Some(order_filled.client_order_id),
+            _ => None,
+        })
+        .collect();
+    assert_eq!(
+        filled_order_ids,
+        vec![visible_client_order_id, iceberg_client_order_id]
+    );
+}
+
+#[rstest]
+#[case::cancel_oldest(
+    SelfTradePrevention::CancelOldest,
+    &[
+        (OrderEventType::Accepted, "O-RESTING"),
+        (OrderEventType::Canceled, "O-RESTING"),
+        (OrderEventType::Accepted, "O-INCOMING"),
+    ],
+)]
+#[case::cancel_newest(
+    SelfTradePrevention::CancelNewest,
+    &[
+        (OrderEventType::Accepted, "O-RESTING"),
+        (OrderEventType::Rejected, "O-INCOMING"),
+    ],
+)]
+#[case::cancel_both(
+    SelfTradePrevention::CancelBoth,
+    &[
+        (OrderEventType::Accepted, "O-RESTING"),
+        (OrderEventType::Canceled, "O-RESTING"),
+        (OrderEventType::Rejected, "O-INCOMING"),
+    ],
+)]
+fn test_self_trade_prevention_against_resting_order(
+    instrument_eth_usdt: InstrumentAny,
+    mut msgbus: MessageBus,
+    order_event_handler: ShareableMessageHandler,
+    account_id: AccountId,
+    #[case] mode: SelfTradePrevention,
+    #[case] expected_events: &[(OrderEventType, &str)],
+) {
+    msgbus.register(
+        msgbus.switchboard.exec_engine_process,
+        order_event_handler.clone(),
+    );
+    let config = OrderMatchingEngineConfig::default().with_self_trade_prevention(Some(mode));
+    let mut engine_l2 = get_order_matching_engine_l2(
+        instrument_eth_usdt.clone(),
+        Rc::new(RefCell::new(msgbus)),
+        None,
+        None,
+        Some(config),
+    );
+
+    // Strategy rests a SELL LIMIT, then sends a BUY LIMIT which would trade with it
+    let mut resting_order = OrderTestBuilder::new(OrderType::Limit)
+        .instrument_id(instrument_eth_usdt.id())
+        .client_order_id(ClientOrderId::from("O-RESTING"))
+        .side(OrderSide::Sell)
+        .price(Price::from("1500.00"))
+        .quantity(Quantity::from("1.000"))
+        .build();
+    engine_l2.process_order(&mut resting_order, account_id);
+
+    let mut incoming_order = OrderTestBuilder::new(OrderType::Limit)
+        .instrument_id(instrument_eth_usdt.id())
+        .client_order_id(ClientOrderId::from("O-INCOMING"))
+        .side(OrderSide::Buy)
+        .price(Price::from("1505.00"))
+        .quantity(Quantity::from("1.000"))
+        .build();
+    engine_l2.process_order(&mut incoming_order, account_id);
+
+    let saved_messages = get_order_event_handler_messages(order_event_handler);
+    assert_eq!(saved_messages.len(), expected_events.len());
+    for (event, (event_type, client_order_id)) in saved_messages.iter().zip(expected_events) {
+        assert_eq!(&event.event_type(), event_type);
+        assert_eq!(
+            event.client_order_id(),
+            ClientOrderId::from(*client_order_id)
+        );
+    }
+
+    let resting_canceled = mode != SelfTradePrevention::CancelNewest;
+    assert_eq!(
+        engine_l2.order_exists(ClientOrderId::from("O-RESTING")),
+        !resting_canceled
+    );
+}
+
+#[rstest]
+fn test_self_trade_prevention_ignores_other_strategies(
+    instrument_eth_usdt: InstrumentAny,
+    mut msgbus: MessageBus,
+    order_event_handler: ShareableMessageHandler,
+    account_id: AccountId,
+) {
+    msgbus.register(
+        msgbus.switchboard.exec_engine_process,
+        order_event_handler.clone(),
+    );
+    let config = OrderMatchingEngineConfig::default()
+        .with_self_trade_prevention(Some(SelfTradePrevention::CancelOldest));
+    let mut engine_l2 = get_order_matching_engine_l2(
+        instrument_eth_usdt.clone(),
+        Rc::new(RefCell::new(msgbus)),
+        None,
+        None,
+        Some(config),
+    );
+
+    let mut resting_order = OrderTestBuilder::new(OrderType::Limit)
+        .instrument_id(instrument_eth_usdt.id())
+        .client_order_id(ClientOrderId::from("O-RESTING"))
+        .strategy_id(StrategyId::from("S-001"))
+        .side(OrderSide::Sell)
+        .price(Price::from("1500.00"))
+        .quantity(Quantity::from("1.000"))
+        .build();
+    engine_l2.process_order(&mut resting_order, account_id);
+
+    let mut incoming_order = OrderTestBuilder::new(OrderType::Limit)
+        .instrument_id(instrument_eth_usdt.id())
+        .client_order_id(ClientOrderId::from("O-INCOMING"))
+        .strategy_id(StrategyId::from("S-002"))
+        .side(OrderSide::Buy)
+        .price(Price::from("1505.00"))
+        .quantity(Quantity::from("1.000"))
+        .build();
+    engine_l2.process_order(&mut incoming_order, account_id);
+
+    let saved_messages = get_order_event_handler_messages(order_event_handler);
+    assert_eq!(saved_messages.len(), 2);
+    assert!(
+        saved_messages
+            .iter()
+            .all(|event| event.event_type() == OrderEventType::Accepted)
+    );
+    assert!(engine_l2.order_exists(ClientOrderId::from("O-RESTING")));
+}
+
+#[rstest]
+#[case::accepted(false, OrderEventType::Accepted)]
+#[case::rejected(true, OrderEventType::Rejected)]
+fn test_process_post_only_order_with_no_opposing_quote(
+    instrument_eth_usdt: InstrumentAny,
+    mut msgbus: MessageBus,
+    order_event_handler: ShareableMessageHandler,
+    account_id: AccountId,
+    #[case] post_only_requires_quote: bool,
+    #[case] expected_event_type: OrderEventType,
+) {
+    msgbus.register(
+        msgbus.switchboard.exec_engine_process,
+        order_event_handler.clone(),
+    );
+    let config = OrderMatchingEngineConfig::default()
+        .with_post_only_requires_quote(post_only_requires_quote);
+    let mut engine_l2 = get_order_matching_engine_l2(
+        instrument_eth_usdt.clone(),
+        Rc::new(RefCell::new(msgbus)),
+        None,
+        None,
+        Some(config),
+    );
+
+    // Only a bid is present, so there is no ask for a post-only buy to rest against
+    let orderbook_delta_buy = OrderBookDeltaTestBuilder::new(instrument_eth_usdt.id())
+        .book_action(BookAction::Add)
+        .book_order(BookOrder::new(
+            OrderSide::Buy,
+            Price::from("1495.00"),
+            Quantity::from("1.000"),
+            1,
+        ))
+        .build();
+    engine_l2.process_order_book_delta(&orderbook_delta_buy);
+
+    let mut post_only_limit_order = OrderTestBuilder::new(OrderType::Limit)
+        .instrument_id(instrument_eth_usdt.id())
+        .side(OrderSide::Buy)
+        .price(Price::from("1500.00"))
+        .quantity(Quantity::from("1.000"))
+        .post_only(true)
+        .client_order_id(ClientOrderId::from("O-19700101-000000-001-001-1"))
+        .build();
+    engine_l2.process_order(&mut post_only_limit_order, account_id);
+
+    let saved_messages = get_order_event_handler_messages(order_event_handler);
+    assert_eq!(saved_messages.len(), 1);
+    let first_message = saved_messages.first().unwrap();
+    assert_eq!(first_message.event_type(), expected_event_type);
+    if post_only_requires_quote {
+        assert_eq!(
+            first_message.message().unwrap(),
+            Ustr::from(
+                "POST_ONLY LIMIT BUY order limit px of 1500.00 has no opposing quote: bid=1495.00, ask=None"
+            )
+        );
+    } else {
+        assert!(engine_l2.order_exists(post_only_limit_order.client_order_id()));
+    }
+}
+
+#[rstest]
+#[case::on_tick_mid("1499.00", "0.50", "1.50")]
+#[case::sub_tick_mid("1499.99", "0.005", "1.005")]
+fn test_market_order_fills_report_slippage_from_arrival_mid(
+    instrument_eth_usdt: InstrumentAny,
+    mut msgbus: MessageBus,
+    order_event_handler: ShareableMessageHandler,
+    account_id: AccountId,
+    #[case] bid_price: &str,
+    #[case] first_slippage: &str,
+    #[case] second_slippage: &str,
+) {
+    msgbus.register(
+        msgbus.switchboard.exec_engine_process,
+        order_event_handler.clone(),
+    );
+    let mut engine_l2 = get_order_matching_engine_l2(
+        instrument_eth_usdt.clone(),
+        Rc::new(RefCell::new(msgbus)),
+        None,
+        None,
+        None,
+    );
+
+    // Asks at 1500.00 and 1501.00, the arrival mid falls between ticks for a 1499.99 bid
+    for (side, price, order_id) in [
+        (OrderSide::Buy, bid_price, 1),
+        (OrderSide::Sell, "1500.00", 2),
+        (OrderSide::Sell, "1501.00", 3),
+    ] {
+        let delta = OrderBookDeltaTestBuilder::new(instrument_eth_usdt.id())
+            .book_action(BookAction::Add)
+            .book_order(BookOrder::new(
+                side,
+                Price::from(price),
+                Quantity::from("1.000"),
+                order_id,
+            ))
+            .build();
+        engine_l2.process_order_book_delta(&delta);
+    }
+
+    let mut market_order = OrderTestBuilder::new(OrderType::Market)
+        .instrument_id(instrument_eth_usdt.id())
+        .side(OrderSide::Buy)
+        .quantity(Quantity::from("2.000"))
+        .build();
+    engine_l2.process_order(&mut market_order, account_id);
+
+    let saved_messages = get_order_event_handler_messages(order_event_handler);
+    let fills: Vec<(Price, Option<Price>)> = saved_messages
+        .iter()
+        .filter_map(|event| match event {
+            OrderEventAny::Filled(order_filled) => {
+                Some((order_filled.last_px, order_filled.slippage))
+            }
+            _ => None,
+        })
+        .collect();
+    let expected_slippages = [Price::from(first_slippage), Price::from(second_slippage)];
+    assert_eq!(
+        fills,
+        vec![
+            (Price::from("1500.00"), Some(expected_slippages[0])),
+            (Price::from("1501.00"), Some(expected_slippages[1])),
+        ]
+    );
+    // Slippage keeps the instrument price precision unless the mid is between ticks
+    for ((_, slippage), expected) in fills.iter().zip(expected_slippages) {
+        assert_eq!(slippage.unwrap().precision, expected.precision);
+    }
+}
+
+#[rstest]
+#[case::converted(false, OrderEventType::Accepted)]
+#[case::rejected(true, OrderEventType::Rejected)]
+fn test_process_order_with_settlement_currency_not_held_by_account(
+    mut msgbus: MessageBus,
+    order_event_handler: ShareableMessageHandler,
+    account_id: AccountId,
+    #[case] reject_settlement_currency_mismatch: bool,
+    #[case] expected_event_type: OrderEventType,
+) {
+    msgbus.register(
+        msgbus.switchboard.exec_engine_process,
+        order_event_handler.clone(),
+    );
+
+    // USD cash account trading an instrument which settles in EUR
+    let cache = Rc::new(RefCell::new(Cache::default()));
+    let account = cash_account_million_usd(cash_account_state_million_usd(
+        "1000000 USD",
+        "0 USD",
+        "1000000 USD",
+    ));
+    cache
+        .borrow_mut()
+        .add_account(AccountAny::Cash(account))
+        .unwrap();
+    let instrument = InstrumentAny::CurrencyPair(default_fx_ccy(
+        Symbol::from("GBP/EUR"),
+        Some(Venue::from("SIM")),
+    ));
+    let config = OrderMatchingEngineConfig::default()
+        .with_reject_settlement_currency_mismatch(reject_settlement_currency_mismatch);
+    let mut engine = get_order_matching_engine(
+        instrument.clone(),
+        Rc::new(RefCell::new(msgbus)),
+        Some(cache),
+        Some(AccountType::Cash),
+        Some(config),
+    );
+
+    let mut limit_order = OrderTestBuilder::new(OrderType::Limit)
+        .instrument_id(instrument.id())
+        .side(OrderSide::Buy)
+        .price(Price::from("1.15000"))
+        .quantity(Quantity::from("10000"))
+        .build();
+    engine.process_order(&mut limit_order, account_id);
+
+    let saved_messages = get_order_event_handler_messages(order_event_handler);
+    assert_eq!(saved_messages.len(), 1);
+    let first_message = saved_messages.first().unwrap();
+    assert_eq!(first_message.event_type(), expected_event_type);
+    if reject_settlement_currency_mismatch {
+        assert_eq!(
+            first_message.message().unwrap(),
+            Ustr::from("Account SIM-001 holds no EUR balance for GBP/EUR.SIM settlement")
+        );
+    }
+}
+
+#[rstest]
+fn test_process_order_unchecked_fills_identically_to_process_order(
+    instrument_eth_usdt: InstrumentAny,
+    account_id: AccountId,
+) {
+    let run = |unchecked: bool| {
+        let mut msgbus = MessageBus::default();
+        let handler = order_event_handler();
+        msgbus.register(msgbus.switchboard.exec_engine_process, handler.clone());
+        let mut engine_l2 = get_order_matching_engine_l2(
+            instrument_eth_usdt.clone(),
+            Rc::new(RefCell::new(msgbus)),
+            None,
+            None,
+            None,
+        );
+        for (price, order_id) in [("1500.00", 1), ("1501.00", 2)] {
+            let delta = OrderBookDeltaTestBuilder::new(instrument_eth_usdt.id())
+                .book_action(BookAction::Add)
+                .book_order(BookOrder::new(
+                    OrderSide::Sell,
+                    Price::from(price),
+                    Quantity::from("1.000"),
+                    order_id,
+                ))
+                .build();
+            engine_l2.process_order_book_delta(&delta);
+        }
+
+        let mut limit_order = OrderTestBuilder::new(OrderType::Limit)
+            .instrument_id(instrument_eth_usdt.id())
+            .client_order_id(ClientOrderId::from("O-19700101-000000-001-001-1"))
+            .side(OrderSide::Buy)
+            .price(Price::from("1501.00"))
+            .quantity(Quantity::from("1.500"))
+            .build();
+        if unchecked {
+            engine_l2.process_order_unchecked(&mut limit_order, account_id);
+        } else {
+            engine_l2.process_order(&mut limit_order, account_id);
+        }
+
+        get_order_event_handler_messages(handler)
+            .iter()
+            .map(|event| match event {
+                OrderEventAny::Filled(fill) => (
+                    event.event_type(),
+                    Some((fill.last_px, fill.last_qty, fill.liquidity_side)),
+                ),
+                _ => (event.event_type(), None),
+            })
+            .collect::<Vec<_>>()
+    };
+
+    let checked_events = run(false);
+    let unchecked_events = run(true);
+
+    assert_eq!(checked_events.len(), 3);
+    assert_eq!(unchecked_events, checked_events);
+}
+
+#[rstest]
+fn test_recent_rejections_retained_up_to_capacity(
+    instrument_eth_usdt: InstrumentAny,
+    msgbus: MessageBus,
+    account_id: AccountId,
+) {
+    let config = OrderMatchingEngineConfig::default().with_recent_rejections_capacity(2);
+    let mut engine_l2 = get_order_matching_engine_l2(
+        instrument_eth_usdt.clone(),
+        Rc::new(RefCell::new(msgbus)),
+        None,
+        None,
+        Some(config),
+    );
+    assert!(engine_l2.recent_rejections().is_empty());
+
+    // Market orders are rejected as there is no market yet
+    for client_order_id in ["O-1", "O-2", "O-3"] {
+        let mut market_order = OrderTestBuilder::new(OrderType::Market)
+            .instrument_id(instrument_eth_usdt.id())
+            .client_order_id(ClientOrderId::from(client_order_id))
+            .side(OrderSide::Buy)
+            .quantity(Quantity::from("1.000"))
+            .build();
+        engine_l2.process_order(&mut market_order, account_id);
+    }
+
+    let recent_rejections = engine_l2.recent_rejections();
+    let client_order_ids: Vec<ClientOrderId> = recent_rejections
+        .iter()
+        .map(|rejected| rejected.client_order_id)
+        .collect();
+    assert_eq!(
+        client_order_ids,
+        vec![ClientOrderId::from("O-2"), ClientOrderId::from("O-3")]
+    );
+    assert!(
+        recent_rejections
+            .iter()
+            .all(|rejected| rejected.reason == Ustr::from("No market for ETHUSDT-PERP.BINANCE"))
+    );
+
+    engine_l2.reset();
+    assert!(engine_l2.recent_rejections().is_empty());
+}
+
+#[rstest]
+fn test_emit_updated_on_fill_interleaves_order_updates(
+    instrument_eth_usdt: InstrumentAny,
+    mut msgbus: MessageBus,
+    order_event_handler: ShareableMessageHandler,
+    account_id: AccountId,
+) {
+    msgbus.register(
+        msgbus.switchboard.exec_engine_process,
+        order_event_handler.clone(),
+    );
+    let config = OrderMatchingEngineConfig::default().with_emit_updated_on_fill(true);
+    let mut engine_l2 = get_order_matching_engine_l2(
+        instrument_eth_usdt.clone(),
+        Rc::new(RefCell::new(msgbus)),
+        None,
+        None,
+        Some(config),
+    );
+
+    let mut limit_order = OrderTestBuilder::new(OrderType::Limit)
+        .instrument_id(instrument_eth_usdt.id())
+        .client_order_id(ClientOrderId::from("O-19700101-000000-001-001-1"))
+        .side(OrderSide::Buy)
+        .price(Price::from("1500.00"))
+        .quantity(Quantity::from("3.000"))
+        .build();
+    engine_l2.process_order(&mut limit_order, account_id);
+
+    // Each book update matches the 1.000 available at 1500.00, partially filling twice
+    for (price, order_id) in [("1500.00", 1), ("1501.00", 2)] {
+        let delta = OrderBookDeltaTestBuilder::new(instrument_eth_usdt.id())
+            .book_action(BookAction::Add)
+            .book_order(BookOrder::new(
+                OrderSide::Sell,
+                Price::from(price),
+                Quantity::from("1.000"),
+                order_id,
+            ))
+            .build();
+        engine_l2.process_order_book_delta(&delta);
+    }
+
+    // Apply each event to the order, tracking its quantity, filled and leaves quantities
+    let saved_messages = get_order_event_handler_messages(order_event_handler);
+    let mut events = Vec::new();
+    for event in saved_messages {
+        let event_type = event.event_type();
+        if event_type == OrderEventType::Accepted {
+            continue; // Already applied on processing
+        }
+        let event = match event {
+            OrderEventAny::Filled(filled) if filled.last_qty < limit_order.leaves_qty() => {
+                OrderEventAny::PartiallyFilled(filled)
+            }
+            event => event,
+        };
+        limit_order.apply(event).unwrap();
+        events.push((
+            event_type,
+            limit_order.quantity(),
+            limit_order.filled_qty(),
+            limit_order.leaves_qty(),
+        ));
+    }
+    let qty = Quantity::from;
+    assert_eq!(
+        events,
+        vec![
+            (
+                OrderEventType::Filled,
+                qty("3.000"),
+                qty("1.000"),
+                qty("2.000")
+            ),
+            (
+                OrderEventType::Updated,
+                qty("3.000"),
+                qty("1.000"),
+                qty("2.000")
+            ),
+            (
+                OrderEventType::Filled,
+                qty("3.000"),
+                qty("2.000"),
+                qty("1.000")
+            ),
+            (
+                OrderEventType::Updated,
+                qty("3.000"),
+                qty("2.000"),
+                qty("1.000")
+            ),
+        ]
+    );
+    assert!(!limit_order.is_closed());
+}
+
+#[rstest]
+fn test_max_fills_per_order_cancels_remainder(
+    instrument_eth_usdt: InstrumentAny,
+    mut msgbus: MessageBus,
+    order_event_handler: ShareableMessageHandler,
+    account_id: AccountId,
+) {
+    msgbus.register(
+        msgbus.switchboard.exec_engine_process,
+        order_event_handler.clone(),
+    );
+    let config = OrderMatchingEngineConfig::default().with_max_fills_per_order(Some(2));
+    let mut engine_l2 = get_order_matching_engine_l2(
+        instrument_eth_usdt.clone(),
+        Rc::new(RefCell::new(msgbus)),
+        None,
+        None,
+        Some(config),
+    );
+
+    // Deep book of five 1.000 ask levels
+    for (i, price) in ["1500.00", "1501.00", "1502.00", "1503.00", "1504.00"]
+        .into_iter()
+        .enumerate()
+    {
+        let delta = OrderBookDeltaTestBuilder::new(instrument_eth_usdt.id())
+            .book_action(BookAction::Add)
+            .book_order(BookOrder::new(
+                OrderSide::Sell,
+                Price::from(price),
+                Quantity::from("1.000"),
+                i as u64,
+            ))
+            .build();
+        engine_l2.process_order_book_delta(&delta);
+    }
+
+    let client_order_id = ClientOrderId::from("O-19700101-000000-001-001-1");
+    let mut market_order = OrderTestBuilder::new(OrderType::Market)
+        .instrument_id(instrument_eth_usdt.id())
+        .client_order_id(client_order_id)
+        .side(OrderSide::Buy)
+        .quantity(Quantity::from("5.000"))
+        .build();
+    engine_l2.process_order(&mut market_order, account_id);
+
+    let saved_messages = get_order_event_handler_messages(order_event_handler);
+    assert_eq!(saved_messages.len(), 3);
+    let fill_prices: Vec<Price> = saved_messages
+        .iter()
+        .filter_map(|event| match event {
+            OrderEventAny::Filled(filled) => Some(filled.last_px),
+            _ => None,
+        })
+        .collect();
+    assert_eq!(
+        fill_prices,
+        vec![Price::from("1500.00"), Price::from("1501.00")]
+    );
+    let last_message = saved_messages.last().unwrap();
+    assert_eq!(last_message.event_type(), OrderEventType::Canceled);
+    assert_eq!(last_message.client_order_id(), client_order_id);
+    assert_eq!(
+        last_message.message().unwrap(),
+        Ustr::from("MAX_FILLS_PER_ORDER")
+    );
+}
+
+#[rstest]
+fn test_max_sweep_depth_pct_cancels_remainder(
+    instrument_eth_usdt: InstrumentAny,
+    mut msgbus: MessageBus,
+    order_event_handler: ShareableMessageHandler,
+    account_id: AccountId,
+) {
+    msgbus.register(
+        msgbus.switchboard.exec_engine_process,
+        order_event_handler.clone(),
+    );
+    // 0.2% of 1500.00 allows sweeping up to 1503.00
+    let config = OrderMatchingEngineConfig::default().with_max_sweep_depth_pct(Some(0.2));
+    let mut engine_l2 = get_order_matching_engine_l2(
+        instrument_eth_usdt.clone(),
+        Rc::new(RefCell::new(msgbus)),
+        None,
+        None,
+        Some(config),
+    );
+
+    // Deep book of ask levels 1.000 each, spaced 2.00 apart
+    for (i, price) in ["1500.00", "1502.00", "1504.00", "1506.00", "1508.00"]
+        .into_iter()
+        .enumerate()
+    {
+        let delta = OrderBookDeltaTestBuilder::new(instrument_eth_usdt.id())
+            .book_action(BookAction::Add)
+            .book_order(BookOrder::new(
+                OrderSide::Sell,
+                Price::from(price),
+                Quantity::from("1.000"),
+                i as u64,
+            ))
+            .build();
+        engine_l2.process_order_book_delta(&delta);
+    }
+
+    let client_order_id = ClientOrderId::from("O-19700101-000000-001-001-1");
+    let mut market_order = OrderTestBuilder::new(OrderType::Market)
+        .instrument_id(instrument_eth_usdt.id())
+        .client_order_id(client_order_id)
+        .side(OrderSide::Buy)
+        .quantity(Quantity::from("5.000"))
+        .build();
+    engine_l2.process_order(&mut market_order, account_id);
+
+    let saved_messages = get_order_event_handler_messages(order_event_handler);
+    assert_eq!(saved_messages.len(), 3);
+    let fill_prices: Vec<Price> = saved_messages
+        .iter()
+        .filter_map(|event| match event {
+            OrderEventAny::Filled(filled) => Some(filled.last_px),
+            _ => None,
+        })
+        .collect();
+    assert_eq!(
+        fill_prices,
+        vec![Price::from("1500.00"), Price::from("1502.00")]
+    );
+    let last_message = saved_messages.last().unwrap();
+    assert_eq!(last_message.event_type(), OrderEventType::Canceled);
+    assert_eq!(last_message.client_order_id(), client_order_id);
+    assert_eq!(
+        last_message.message().unwrap(),
+        Ustr::from("MAX_SWEEP_DEPTH")
+    );
+}
+
+#[rstest]
+fn test_fill_commission_for_inverse_perpetual_in_base_currency(
+    mut msgbus: MessageBus,
+    order_event_handler: ShareableMessageHandler,
+    account_id: AccountId,
+) {
+    msgbus.register(
+        msgbus.switchboard.exec_engine_process,
+        order_event_handler.clone(),
+    );
+    let instrument = InstrumentAny::CryptoPerpetual(xbtusd_bitmex());
+    assert!(instrument.is_inverse());
+    let mut engine_l2 = get_order_matching_engine_l2(
+        instrument.clone(),
+        Rc::new(RefCell::new(msgbus)),
+        None,
+        Some(AccountType::Margin),
+        None,
+    );
+
+    let orderbook_delta_sell = OrderBookDeltaTestBuilder::new(instrument.id())
+        .book_action(BookAction::Add)
+        .book_order(BookOrder::new(
+            OrderSide::Sell,
+            Price::from("10000.0"),
+            Quantity::from("100000"),
+            1,
+        ))
+        .build();
+    engine_l2.process_order_book_delta(&orderbook_delta_sell);
+
+    let mut market_order = OrderTestBuilder::new(OrderType::Market)
+        .instrument_id(instrument.id())
+        .side(OrderSide::Buy)
+        .quantity(Quantity::from("10000"))
+        .build();
+    engine_l2.process_order(&mut market_order, account_id);
+
+    let saved_messages = get_order_event_handler_messages(order_event_handler);
+    assert_eq!(saved_messages.len(), 1);
+    let order_filled = match saved_messages.first().unwrap() {
+        OrderEventAny::Filled(order_filled) => order_filled,
+        _ => panic!("Expected OrderFilled event in first message"),
+    };
+
+    // 10,000 USD contracts at 10,000.0 is a notional of 1 BTC, taker fee 0.075%
+    let notional =
+        instrument.calculate_notional_value(order_filled.last_qty, order_filled.last_px, None);
+    assert_eq!(notional, Money::from("1 BTC"));
+    assert_eq!(order_filled.currency, Currency::USD());
+    assert_eq!(order_filled.commission, Some(Money::from("0.00075 BTC")));
+}
+
+#[rstest]
+fn test_generated_position_ids_follow_configured_pattern(
+    instrument_eth_usdt: InstrumentAny,
+    mut msgbus: MessageBus,
+    order_event_handler: ShareableMessageHandler,
+    account_id: AccountId,
+) {
+    msgbus.register(
+        msgbus.switchboard.exec_engine_process,
+        order_event_handler.clone(),
+    );
+    let config = OrderMatchingEngineConfig {
+        use_position_ids: true,
+        ..Default::default()
+    }
+    .with_position_id_pattern(Some(Ustr::from("P-{instrument}-{counter}")));
+    let mut engine_l2 = OrderMatchingEngine::new(
+        instrument_eth_usdt.clone(),
+        1,
+        FillModel::default(),
+        FeeModelAny::default(),
+        BookType::L2_MBP,
+        OmsType::Hedging,
+        AccountType::Cash,
+        &ATOMIC_TIME,
+        Rc::new(RefCell::new(msgbus)),
+        Rc::new(RefCell::new(Cache::default())),
+        config,
+    );
+
+    let orderbook_delta_sell = OrderBookDeltaTestBuilder::new(instrument_eth_usdt.id())
+        .book_action(BookAction::Add)
+        .book_order(BookOrder::new(
+            OrderSide::Sell,
+            Price::from("1500.00"),
+            Quantity::from("5.000"),
+            1,
+        ))
+        .build();
+    engine_l2.process_order_book_delta(&orderbook_delta_sell);
+
+    // Each opening fill on a hedging venue starts a new position
+    for client_order_id in ["O-19700101-000000-001-001-1", "O-19700101-000000-001-001-2"] {
+        let mut market_order = OrderTestBuilder::new(OrderType::Market)
+            .instrument_id(instrument_eth_usdt.id())
+            .client_order_id(ClientOrderId::from(client_order_id))
+            .side(OrderSide::Buy)
+            .quantity(Quantity::from("1.000"))
+            .build();
+        engine_l2.process_order(&mut market_order, account_id);
+    }
+
+    let saved_messages = get_order_event_handler_messages(order_event_handler);
+    let position_ids: Vec<Option<PositionId>> = saved_messages
+        .iter()
+        .filter_map(|event| match event {
+            OrderEventAny::Filled(filled) => Some(filled.position_id),
+            _ => None,
+        })
+        .collect();
+    assert_eq!(
+        position_ids,
+        vec![
+            Some(PositionId::from("P-ETHUSDT-PERP.BINANCE-1")),
+            Some(PositionId::from("P-ETHUSDT-PERP.BINANCE-2")),
+        ]
+    );
+}
+
+#[rstest]
+#[case::crossing_buy(OrderType::Limit, OrderSide::Buy, Some("1500.00"), Some(true))]
+#[case::passive_buy(OrderType::Limit, OrderSide::Buy, Some("1499.00"), Some(false))]
+#[case::crossing_sell(OrderType::Limit, OrderSide::Sell, Some("1495.00"), Some(true))]
+#[case::passive_sell(OrderType::Limit, OrderSide::Sell, Some("1496.00"), Some(false))]
+#[case::market(OrderType::Market, OrderSide::Buy, None, None)]
+fn test_would_be_taker(
+    instrument_eth_usdt: InstrumentAny,
+    msgbus: MessageBus,
+    #[case] order_type: OrderType,
+    #[case] side: OrderSide,
+    #[case] price: Option<&str>,
+    #[case] expected: Option<bool>,
+) {
+    let mut engine_l2 = get_order_matching_engine_l2(
+        instrument_eth_usdt.clone(),
+        Rc::new(RefCell::new(msgbus)),
+        None,
+        None,
+        None,
+    );
+    for (book_side, book_px) in [(OrderSide::Sell, "1500.00"), (OrderSide::Buy, "1495.00")] {
+        let delta = OrderBookDeltaTestBuilder::new(instrument_eth_usdt.id())
+            .book_action(BookAction::Add)
+            .book_order(BookOrder::new(
+                book_side,
+                Price::from(book_px),
+                Quantity::from("1.000"),
+                1,
+            ))
+            .build();
+        engine_l2.process_order_book_delta(&delta);
+    }
+
+    let mut builder = OrderTestBuilder::new(order_type);
+    builder
+        .instrument_id(instrument_eth_usdt.id())
+        .side(side)
+        .quantity(Quantity::from("1.000"));
+    if let Some(price) = price {
+        builder.price(Price::from(price));
+    }
+    let order = builder.build();
+
+    assert_eq!(engine_l2.would_be_taker(&order), expected);
+}
+
+#[rstest]
+fn test_batch_order_events_publishes_sweep_fills_as_single_batch(
+    instrument_eth_usdt: InstrumentAny,
+    mut msgbus: MessageBus,
+    account_id: AccountId,
+) {
+    let batch_handler = get_message_saving_handler::<Vec<OrderEventAny>>(None);
+    msgbus.register(
+        msgbus.switchboard.exec_engine_process,
+        batch_handler.clone(),
+    );
+    let config = OrderMatchingEngineConfig::default().with_batch_order_events(true);
+    let mut engine_l2 = get_order_matching_engine_l2(
+        instrument_eth_usdt.clone(),
+        Rc::new(RefCell::new(msgbus)),
+        None,
+        None,
+        Some(config),
+    );
+
+    for (i, price) in ["1500.00", "1501.00", "1502.00"].into_iter().enumerate() {
+        let delta = OrderBookDeltaTestBuilder::new(instrument_eth_usdt.id())
+            .book_action(BookAction::Add)
+            .book_order(BookOrder::new(
+                OrderSide::Sell,
+                Price::from(price),
+                Quantity::from("1.000"),
+                i as u64,
+            ))
+            .build();
+        engine_l2.process_order_book_delta(&delta);
+    }
+
+    let mut market_order = OrderTestBuilder::new(OrderType::Market)
+        .instrument_id(instrument_eth_usdt.id())
+        .side(OrderSide::Buy)
+        .quantity(Quantity::from("3.000"))
+        .build();
+    engine_l2.process_order(&mut market_order, account_id);
+
+    let batches = get_saved_messages::<Vec<OrderEventAny>>(batch_handler);
+    assert_eq!(batches.len(), 1);
+    let fill_prices: Vec<Price> = batches[0]
+        .iter()
+        .map(|event| match event {
+            OrderEventAny::Filled(filled) => filled.last_px,
+            _ => panic!("Expected only OrderFilled events in batch"),
+        })
+        .collect();
+    assert_eq!(
+        fill_prices,
+        vec![
+            Price::from("1500.00"),
+            Price::from("1501.00"),
+            Price::from("1502.00"),
+        ]
+    );
+}
+
+#[rstest]
+fn test_order_rejected_after_acceptance_includes_venue_order_id(
+    instrument_eth_usdt: InstrumentAny,
+    mut msgbus: MessageBus,
+    order_event_handler: ShareableMessageHandler,
+    account_id: AccountId,
+) {
+    msgbus.register(
+        msgbus.switchboard.exec_engine_process,
+        order_event_handler.clone(),
+    );
+    let mut engine_l2 = get_order_matching_engine_l2(
+        instrument_eth_usdt.clone(),
+        Rc::new(RefCell::new(msgbus)),
+        None,
+        None,
+        None,
+    );
+    let ask_delta = |action: BookAction, price: &str| {
+        OrderBookDeltaTestBuilder::new(instrument_eth_usdt.id())
+            .book_action(action)
+            .book_order(BookOrder::new(
+                OrderSide::Sell,
+                Price::from(price),
+                Quantity::from("1.000"),
+                1,
+            ))
+            .build()
+    };
+    engine_l2.process_order_book_delta(&ask_delta(BookAction::Add, "1500.00"));
+
+    // Post-only stop-limit which is accepted, then rejected when triggered into a crossing limit
+    let client_order_id = ClientOrderId::from("O-19700101-000000-001-001-1");
+    let mut stop_order = OrderTestBuilder::new(OrderType::StopLimit)
+        .instrument_id(instrument_eth_usdt.id())
+        .side(OrderSide::Buy)
+        .trigger_price(Price::from("1505.00"))
+        .price(Price::from("1506.00"))
+        .quantity(Quantity::from("1.000"))
+        .post_only(true)
+        .client_order_id(client_order_id)
+        .build();
+    engine_l2.process_order(&mut stop_order, account_id);
+    engine_l2.process_order_book_delta(&ask_delta(BookAction::Delete, "1500.00"));
+    engine_l2.process_order_book_delta(&ask_delta(BookAction::Add, "1506.00"));
+
+    let saved_messages = get_order_event_handler_messages(order_event_handler);
+    let order_accepted = match saved_messages.first().unwrap() {
+        OrderEventAny::Accepted(order_accepted) => order_accepted,
+        _ => panic!("Expected OrderAccepted event in first message"),
+    };
+    let order_rejected = match saved_messages.last().unwrap() {
+        OrderEventAny::Rejected(order_rejected) => order_rejected,
+        _ => panic!("Expected OrderRejected event in last message"),
+    };
+    assert_eq!(order_rejected.client_order_id, client_order_id);
+    assert_eq!(
+        order_rejected.venue_order_id,
+        Some(order_accepted.venue_order_id)
+    );
+}
+
+#[rstest]
+fn test_process_modify_reduced_quantity_limits_resting_fill(
+    instrument_eth_usdt: InstrumentAny,
+    mut msgbus: MessageBus,
+    order_event_handler: ShareableMessageHandler,
+    account_id: AccountId,
+) {
+    msgbus.register(
+        msgbus.switchboard.exec_engine_process,
+        order_event_handler.clone(),
+    );
+    let mut engine_l2 = get_order_matching_engine_l2(
+        instrument_eth_usdt.clone(),
+        Rc::new(RefCell::new(msgbus)),
+        None,
+        None,
+        None,
+    );
+
+    let client_order_id = ClientOrderId::from("O-19700101-000000-001-001-1");
+    let mut limit_order = OrderTestBuilder::new(OrderType::Limit)
+        .instrument_id(instrument_eth_usdt.id())
+        .side(OrderSide::Buy)
+        .price(Price::from("1495.00"))
+        .quantity(Quantity::from("2.000"))
+        .client_order_id(client_order_id)
+        .build();
+    engine_l2.process_order(&mut limit_order, account_id);
+
+    let modify_order_command = ModifyOrder::new(
+        TraderId::from("TRADER-001"),
+        ClientId::from("CLIENT-001"),
+        StrategyId::from("STRATEGY-001"),
+        instrument_eth_usdt.id(),
+        client_order_id,
+        VenueOrderId::from("V1"),
+        Some(Quantity::from("1.000")),
+        None,
+        None,
+        UUID4::new(),
+        UnixNanos::default(),
+    )
+    .unwrap();
+    engine_l2.process_modify(&modify_order_command, account_id);
+
+    // Sell side crosses the resting order with 2.000
+    let orderbook_delta_sell = OrderBookDeltaTestBuilder::new(instrument_eth_usdt.id())
+        .book_action(BookAction::Add)
+        .book_order(BookOrder::new(
+            OrderSide::Sell,
+            Price::from("1494.00"),
+            Quantity::from("2.000"),
+            1,
+        ))
+        .build();
+    engine_l2.process_order_book_delta(&orderbook_delta_sell);
+
+    let saved_messages = get_order_event_handler_messages(order_event_handler);
+    let order_updated = match saved_messages.get(1).unwrap() {
+        OrderEventAny::Updated(order_updated) => order_updated,
+        _ => panic!("Expected OrderUpdated event in second message"),
+    };
+    assert_eq!(order_updated.quantity, Quantity::from("1.000"));
+    let fill_qtys: Vec<Quantity> = saved_messages
+        .iter()
+        .filter_map(|event| match event {
+            OrderEventAny::Filled(filled) => Some(filled.last_qty),
+            _ => None,
+        })
+        .collect();
+    assert_eq!(fill_qtys, vec![Quantity::from("1.000")]);
+}
+
+#[rstest]
+fn test_stop_fill_latency_distinct_from_regular_fill_latency(
+    instrument_eth_usdt: InstrumentAny,
+    mut msgbus: MessageBus,
+    order_event_handler: ShareableMessageHandler,
+    account_id: AccountId,
+) {
+    msgbus.register(
+        msgbus.switchboard.exec_engine_process,
+        order_event_handler.clone(),
+    );
+    let mut engine_l2 = get_order_matching_engine_l2(
+        instrument_eth_usdt.clone(),
+        Rc::new(RefCell::new(msgbus)),
+        None,
+        None,
+        None,
+    );
+    engine_l2.set_latency_model(LatencyModel::new(1_000, 5_000, 0, None));
+    // Static clock so fills on submission and on market data share the same base time
+    engine_l2.set_clock(Rc::new(AtomicTime::new(false, UnixNanos::default())));
+    let ask_delta = |action: BookAction, price: &str| {
+        OrderBookDeltaTestBuilder::new(instrument_eth_usdt.id())
+            .book_action(action)
+            .book_order(BookOrder::new(
+                OrderSide::Sell,
+                Price::from(price),
+                Quantity::from("10.000"),
+                1,
+            ))
+            .build()
+    };
+    engine_l2.process_order_book_delta(&ask_delta(BookAction::Add, "1500.00"));
+    let ts_base = ask_delta(BookAction::Add, "1500.00").ts_event.as_u64();
+
+    let market_order_id = ClientOrderId::from("O-19700101-000000-001-001-1");
+    let mut market_order = OrderTestBuilder::new(OrderType::Market)
+        .instrument_id(instrument_eth_usdt.id())
+        .client_order_id(market_order_id)
+        .side(OrderSide::Buy)
+        .quantity(Quantity::from("1.000"))
+        .build();
+    engine_l2.process_order(&mut market_order, account_id);
+
+    let stop_order_id = ClientOrderId::from("O-19700101-000000-001-001-2");
+    let mut stop_order = OrderTestBuilder::new(OrderType::StopMarket)
+        .instrument_id(instrument_eth_usdt.id())
+        .client_order_id(stop_order_id)
+        .side(OrderSide::Buy)
+        .trigger_price(Price::from("1505.00"))
+        .quantity(Quantity::from("1.000"))
+        .build();
+    engine_l2.process_order(&mut stop_order, account_id);
+
+    // Market moves through the stop price
+    engine_l2.process_order_book_delta(&ask_delta(BookAction::Delete, "1500.00"));
+    engine_l2.process_order_book_delta(&ask_delta(BookAction::Add, "1506.00"));
+
+    // Fills occur after their latency from the base time, and are never initialized before
+    let saved_messages = get_order_event_handler_messages(order_event_handler);
+    let fills: Vec<&OrderFilled> = saved_messages
+        .iter()
+        .filter_map(|event| match event {
+            OrderEventAny::Filled(filled) => Some(filled),
+            _ => None,
+        })
+        .collect();
+    let fill_latencies: Vec<(ClientOrderId, u64)> = fills
+        .iter()
+        .map(|filled| (filled.client_order_id, filled.ts_event.as_u64() - ts_base))
+        .collect();
+    assert_eq!(
+        fill_latencies,
+        vec![(market_order_id, 1_000), (stop_order_id, 5_000)]
+    );
+    assert!(fills.iter().all(|filled| filled.ts_init >= filled.ts_event));
+}
+
+#[rstest]
+#[case(false)]
+#[case(true)]
+fn test_modify_of_inflight_order(
+    instrument_eth_usdt: InstrumentAny,
+    mut msgbus: MessageBus,
+    order_event_handler: ShareableMessageHandler,
+    account_id: AccountId,
+    #[case] queue_modifies_until_accepted: bool,
+) {
+    msgbus.register(
+        msgbus.switchboard.exec_engine_process,
+        order_event_handler.clone(),
+    );
+    let config = OrderMatchingEngineConfig::default()
+        .with_queue_modifies_until_accepted(queue_modifies_until_accepted);
+    let mut engine_l2 = get_order_matching_engine_l2(
+        instrument_eth_usdt.clone(),
+        Rc::new(RefCell::new(msgbus)),
+        None,
+        None,
+        Some(config),
+    );
+    engine_l2.set_latency_model(LatencyModel::new(0, 0, 0, None).with_insert_latency(1_000));
+    engine_l2.set_clock(Rc::new(AtomicTime::new(false, UnixNanos::default())));
+
+    let client_order_id = ClientOrderId::from("O-19700101-000000-001-001-1");
+    let mut limit_order = OrderTestBuilder::new(OrderType::Limit)
+        .instrument_id(instrument_eth_usdt.id())
+        .client_order_id(client_order_id)
+        .side(OrderSide::Buy)
+        .price(Price::from("1495.00"))
+        .quantity(Quantity::from("1.000"))
+        .build();
+    engine_l2.process_order(&mut limit_order, account_id);
+
+    // Modify immediately, while the order is still in flight to the venue
+    let modify_order_command = ModifyOrder::new(
+        TraderId::from("TRADER-001"),
+        ClientId::from("CLIENT-001"),
+        StrategyId::from("STRATEGY-001"),
+        instrument_eth_usdt.id(),
+        client_order_id,
+        VenueOrderId::from("V1"),
+        None,
+        Some(Price::from("1496.00")),
+        None,
+        UUID4::new(),
+        UnixNanos::default(),
+    )
+    .unwrap();
+    engine_l2.process_modify(&modify_order_command, account_id);
+
+    // Order arrives at the venue
+    engine_l2.iterate(UnixNanos::from(1_000));
+
+    let saved_messages = get_order_event_handler_messages(order_event_handler);
+    let event_types: Vec<OrderEventType> = saved_messages
+        .iter()
+        .map(OrderEventAny::event_type)
+        .collect();
+    if queue_modifies_until_accepted {
+        assert_eq!(
+            event_types,
+            vec![OrderEventType::Accepted, OrderEventType::Updated]
+        );
+        let OrderEventAny::Updated(order_updated) = &saved_messages[1] else {
+            panic!("Expected `OrderUpdated` event");
+        };
+        assert_eq!(order_updated.price, Some(Price::from("1496.00")));
+    } else {
+        assert_eq!(
+            event_types,
+            vec![OrderEventType::ModifyRejected, OrderEventType::Accepted]
+        );
+        assert_eq!(
+            saved_messages[0].message().unwrap(),
+            Ustr::from(&format!("Order {client_order_id} not found"))
+        );
+    }
+}
+
+#[rstest]
+fn test_time_to_fill(
+    instrument_eth_usdt: InstrumentAny,
+    mut msgbus: MessageBus,
+    order_event_handler: ShareableMessageHandler,
+    account_id: AccountId,
+) {
+    msgbus.register(
+        msgbus.switchboard.exec_engine_process,
+        order_event_handler.clone(),
+    );
+    let instrument_id = instrument_eth_usdt.id();
+    let mut engine = get_order_matching_engine(
+        instrument_eth_usdt,
+        Rc::new(RefCell::new(msgbus)),
+        None,
+        None,
+        None,
+    );
+    engine.set_latency_model(LatencyModel::new(500, 0, 0, None));
+    engine.set_clock(Rc::new(AtomicTime::new(false, UnixNanos::from(1_000))));
+    let quote = |bid: &str, ask: &str, ts: u64| {
+        QuoteTick::new(
+            instrument_id,
+            Price::from(bid),
+            Price::from(ask),
+            Quantity::from("10.000"),
+            Quantity::from("10.000"),
+            UnixNanos::from(ts),
+            UnixNanos::from(ts),
+        )
+    };
+    engine.process_quote_tick(&quote("1499.00", "1501.00", 1_000));
+
+    let client_order_id = ClientOrderId::from("O-19700101-000000-001-001-1");
+    let mut limit_order = OrderTestBuilder::new(OrderType::Limit)
+        .instrument_id(instrument_id)
+        .client_order_id(client_order_id)
+        .side(OrderSide::Buy)
+        .price(Price::from("1500.00"))
+        .quantity(Quantity::from("1.000"))
+        .build();
+    engine.process_order(&mut limit_order, account_id);
+    assert_eq!(engine.time_to_fill(client_order_id), None);
+
+    // Market moves to the limit price, filling the order after the fill latency
+    engine.process_quote_tick(&quote("1499.00", "1500.00", 6_000));
+
+    let saved_messages = get_order_event_handler_messages(order_event_handler);
+    assert!(matches!(
+        saved_messages.last(),
+        Some(OrderEventAny::Filled(_))
+    ));
+    assert_eq!(engine.time_to_fill(client_order_id), Some(5_500));
+    assert_eq!(
+        engine.time_to_fill(ClientOrderId::from("O-19700101-000000-001-001-2")),
+        None
+    );
+}
+
+#[rstest]
+#[case(-500)]
+#[case(500)]
+fn test_venue_clock_offset_applied_to_event_timestamps(
+    instrument_eth_usdt: InstrumentAny,
+    mut msgbus: MessageBus,
+    order_event_handler: ShareableMessageHandler,
+    account_id: AccountId,
+    #[case] venue_clock_offset_nanos: i64,
+) {
+    msgbus.register(
+        msgbus.switchboard.exec_engine_process,
+        order_event_handler.clone(),
+    );
+    let config = OrderMatchingEngineConfig::default()
+        .with_venue_clock_offset_nanos(venue_clock_offset_nanos);
+    let mut engine_l2 = get_order_matching_engine_l2(
+        instrument_eth_usdt.clone(),
+        Rc::new(RefCell::new(msgbus)),
+        None,
+        None,
+        Some(config),
+    );
+    let orderbook_delta_sell = OrderBookDeltaTestBuilder::new(instrument_eth_usdt.id())
+        .book_action(BookAction::Add)
+        .book_order(BookOrder::new(
+            OrderSide::Sell,
+            Price::from("1500.00"),
+            Quantity::from("10.000"),
+            1,
+        ))
+        .build();
+    engine_l2.process_order_book_delta(&orderbook_delta_sell);
+
+    let mut limit_order = OrderTestBuilder::new(OrderType::Limit)
+        .instrument_id(instrument_eth_usdt.id())
+        .client_order_id(ClientOrderId::from("O-19700101-000000-001-001-1"))
+        .side(OrderSide::Buy)
+        .price(Price::from("1490.00"))
+        .quantity(Quantity::from("1.000"))
+        .build();
+    engine_l2.process_order(&mut limit_order, account_id);
+
+    let mut market_order = OrderTestBuilder::new(OrderType::Market)
+        .instrument_id(instrument_eth_usdt.id())
+        .client_order_id(ClientOrderId::from("O-19700101-000000-001-001-2"))
+        .side(OrderSide::Buy)
+        .quantity(Quantity::from("1.000"))
+        .build();
+    engine_l2.process_order(&mut market_order, account_id);
+
+    let saved_messages = get_order_event_handler_messages(order_event_handler);
+    let offsets: Vec<(OrderEventType, i64)> = saved_messages
+        .iter()
+        .filter_map(|event| {
+            let (ts_event, ts_init) = match event {
+                OrderEventAny::Accepted(accepted) => (accepted.ts_event, accepted.ts_init),
+                OrderEventAny::Filled(filled) => (filled.ts_event, filled.ts_init),
+                _ => return None,
+            };
+            Some((
+                event.event_type(),
+                ts_event.as_u64() as i64 - ts_init.as_u64() as i64,
+            ))
+        })
+        .collect();
+    // Only `ts_event` is shifted to venue time, `ts_init` stays on engine time
+    assert!(offsets.contains(&(OrderEventType::Accepted, venue_clock_offset_nanos)));
+    assert!(offsets.contains(&(OrderEventType::Filled, venue_clock_offset_nanos)));
+    assert!(
+        offsets
+            .iter()
+            .all(|(_, offset)| *offset == venue_clock_offset_nanos)
+    );
+}
+
+#[rstest]
+fn test_realized_pnl_from_closing_fill(
+    instrument_eth_usdt: InstrumentAny,
+    mut msgbus: MessageBus,
+    order_event_handler: ShareableMessageHandler,
+    account_id: AccountId,
+) {
+    msgbus.register(
+        msgbus.switchboard.exec_engine_process,
+        order_event_handler.clone(),
+    );
+    let mut engine_l2 = get_order_matching_engine_l2(
+        instrument_eth_usdt.clone(),
+        Rc::new(RefCell::new(msgbus)),
+        None,
+        None,
+        None,
+    );
+    let instrument_id = instrument_eth_usdt.id();
+    let book_delta = |side: OrderSide, price: &str| {
+        OrderBookDeltaTestBuilder::new(instrument_id)
+            .book_action(BookAction::Add)
+            .book_order(BookOrder::new(
+                side,
+                Price::from(price),
+                Quantity::from("10.000"),
+                1,
+            ))
+            .build()
+    };
+    engine_l2.process_order_book_delta(&book_delta(OrderSide::Sell, "1500.00"));
+    assert_eq!(engine_l2.realized_pnl(instrument_id), Money::from("0 USDT"));
+
+    // Open a 1.000 ETH long position at 1500.00
+    let mut open_order = OrderTestBuilder::new(OrderType::Market)
+        .instrument_id(instrument_id)
+        .client_order_id(ClientOrderId::from("O-19700101-000000-001-001-1"))
+        .side(OrderSide::Buy)
+        .quantity(Quantity::from("1.000"))
+        .build();
+    engine_l2.process_order(&mut open_order, account_id);
+
+    // Close the position at 1510.00
+    engine_l2.process_order_book_delta(&book_delta(OrderSide::Buy, "1510.00"));
+    let mut close_order = OrderTestBuilder::new(OrderType::Market)
+        .instrument_id(instrument_id)
+        .client_order_id(ClientOrderId::from("O-19700101-000000-001-001-2"))
+        .side(OrderSide::Sell)
+        .quantity(Quantity::from("1.000"))
+        .build();
+    engine_l2.process_order(&mut close_order, account_id);
+
+    let saved_messages = get_order_event_handler_messages(order_event_handler);
+    let commissions: f64 = saved_messages
+        .iter()
+        .filter_map(|event| match event {
+            OrderEventAny::Filled(filled) => filled.commission.map(|c| c.as_f64()),
+            _ => None,
+        })
+        .sum();
+    assert_eq!(
+        engine_l2.realized_pnl(instrument_id),
+        Money::new(
+            10.0 - commissions,
+            instrument_eth_usdt.settlement_currency()
+        )
+    );
+    assert_eq!(
+        engine_l2.realized_pnl(InstrumentId::from("AAPL.XNAS")),
+        Money::from("0 USDT")
+    );
+}
+
+#[rstest]
+fn test_max_drawdown_from_fills_and_mark_updates(
+    instrument_eth_usdt: InstrumentAny,
+    msgbus: MessageBus,
+    account_id: AccountId,
+) {
+    let mut engine_l2 = get_order_matching_engine_l2(
+        instrument_eth_usdt.clone(),
+        Rc::new(RefCell::new(msgbus)),
+        None,
+        None,
+        None,
+    );
+    let orderbook_delta_sell = OrderBookDeltaTestBuilder::new(instrument_eth_usdt.id())
+        .book_action(BookAction::Add)
+        .book_order(BookOrder::new(
+            OrderSide::Sell,
+            Price::from("1500.00"),
+            Quantity::from("10.000"),
+            1,
+        ))
+        .build();
+    engine_l2.process_order_book_delta(&orderbook_delta_sell);
+    assert_eq!(engine_l2.max_drawdown(), 0.0);
+
+    // Open a 1.000 ETH long position at 1500.00
+    let mut market_order = OrderTestBuilder::new(OrderType::Market)
+        .instrument_id(instrument_eth_usdt.id())
+        .side(OrderSide::Buy)
+        .quantity(Quantity::from("1.000"))
+        .build();
+    engine_l2.process_order(&mut market_order, account_id);
+
+    // Equity peaks at 1520.00 then falls 30 to 1490.00, before recovering
+    for mark_price in ["1520.00", "1490.00", "1530.00"] {
+        engine_l2.process_mark_price_update(Price::from(mark_price));
+    }
+
+    assert!((engine_l2.max_drawdown() - 30.0).abs() < 1e-9);
+}
+
+#[rstest]
+#[case(LockBehavior::Match, vec![OrderEventType::Accepted, OrderEventType::Filled])]
+#[case(LockBehavior::Rest, vec![OrderEventType::Accepted])]
+#[case(LockBehavior::Reject, vec![OrderEventType::Rejected])]
+fn test_process_limit_order_locking_market(
+    instrument_eth_usdt: InstrumentAny,
+    mut msgbus: MessageBus,
+    order_event_handler: ShareableMessageHandler,
+    account_id: AccountId,
+    #[case] lock_behavior: LockBehavior,
+    #[case] expected_events: Vec<OrderEventType>,
+) {
+    msgbus.register(
+        msgbus.switchboard.exec_engine_process,
+        order_event_handler.clone(),
+    );
+    let config = OrderMatchingEngineConfig::default().with_lock_behavior(lock_behavior);
+    let mut engine_l2 = get_order_matching_engine_l2(
+        instrument_eth_usdt.clone(),
+        Rc::new(RefCell::new(msgbus)),
+        None,
+        None,
+        Some(config),
+    );
+    let orderbook_delta_sell = OrderBookDeltaTestBuilder::new(instrument_eth_usdt.id())
+        .book_action(BookAction::Add)
+        .book_order(BookOrder::new(
+            OrderSide::Sell,
+            Price::from("1500.00"),
+            Quantity::from("10.000"),
+            1,
+        ))
+        .build();
+    engine_l2.process_order_book_delta(&orderbook_delta_sell);
+
+    let client_order_id = ClientOrderId::from("O-19700101-000000-001-001-1");
+    let mut limit_order = OrderTestBuilder::new(OrderType::Limit)
+        .instrument_id(instrument_eth_usdt.id())
+        .client_order_id(client_order_id)
+        .side(OrderSide::Buy)
+        .price(Price::from("1500.00"))
+        .quantity(Quantity::from("1.000"))
+        .build();
+    engine_l2.process_order(&mut limit_order, account_id);
+
+    let saved_messages = get_order_event_handler_messages(order_event_handler);
+    let event_types: Vec<OrderEventType> = saved_messages
+        .iter()
+        .map(OrderEventAny::event_type)
+        .collect();
+    assert_eq!(event_types, expected_events);
+}
+
+#[rstest]
+fn test_injected_clock_drives_gtd_expiry(
+    instrument_eth_usdt: InstrumentAny,
+    mut msgbus: MessageBus,
+    order_event_handler: ShareableMessageHandler,
+    account_id: AccountId,
+) {
+    msgbus.register(
+        msgbus.switchboard.exec_engine_process,
+        order_event_handler.clone(),
+    );
+    let engine_config = OrderMatchingEngineConfig {
+        support_gtd_orders: true,
+        ..Default::default()
+    };
+    let mut engine_l2 = get_order_matching_engine_l2(
+        instrument_eth_usdt.clone(),
+        Rc::new(RefCell::new(msgbus)),
+        None,
+        None,
+        Some(engine_config),
+    );
+    let clock = Rc::new(AtomicTime::new(false, UnixNanos::from(1_000)));
+    engine_l2.set_clock(clock.clone());
+
+    let client_order_id = ClientOrderId::from("O-19700101-000000-001-001-1");
+    let mut limit_order = OrderTestBuilder::new(OrderType::Limit)
+        .instrument_id(instrument_eth_usdt.id())
+        .side(OrderSide::Buy)
+        .price(Price::from("1495.00"))
+        .quantity(Quantity::from("1.000"))
+        .expire_time(UnixNanos::from(5_000))
+        .client_order_id(client_order_id)
+        .build();
+    engine_l2.process_order(&mut limit_order, account_id);
+
+    // Not yet expired
+    clock.set_time(UnixNanos::from(4_999));
+    engine_l2.iterate(clock.get_time_ns());
+
+    // Advance the clock to the expire time
+    clock.set_time(UnixNanos::from(5_000));
+    engine_l2.iterate(clock.get_time_ns());
+
+    let saved_messages = get_order_event_handler_messages(order_event_handler);
+    let events: Vec<(OrderEventType, UnixNanos)> = saved_messages
+        .iter()
+        .map(|event| (event.event_type(), event.ts_event()))
+        .collect();
+    assert_eq!(
+        events,
+        vec![
+            (OrderEventType::Accepted, UnixNanos::from(1_000)),
+            (OrderEventType::Expired, UnixNanos::from(5_000)),
+        ]
+    );
+}
+
+#[rstest]
+fn test_process_order_book_delta_skips_duplicate_sequence(
+    instrument_eth_usdt: InstrumentAny,
+    msgbus: MessageBus,
+) {
+    let config = OrderMatchingEngineConfig::default().with_skip_duplicate_deltas(true);
+    let mut engine_l2 = get_order_matching_engine_l2(
+        instrument_eth_usdt.clone(),
+        Rc::new(RefCell::new(msgbus)),
+        None,
+        None,
+        Some(config),
+    );
+    let orderbook_delta_sell = OrderBookDeltaTestBuilder::new(instrument_eth_usdt.id())
+        .book_action(BookAction::Add)
+        .book_order(BookOrder::new(
+            OrderSide::Sell,
+            Price::from("1500.00"),
+            Quantity::from("10.000"),
+            1,
+        ))
+        .sequence(1)
+        .build();
+
+    engine_l2.process_order_book_delta(&orderbook_delta_sell);
+    engine_l2.process_order_book_delta(&orderbook_delta_sell);
+
+    let book = engine_l2.get_book();
+    assert_eq!(book.best_ask_size(), Some(Quantity::from("10.000")));
+    assert_eq!(book.sequence, 1);
+    assert_eq!(book.count, 1);
+}
+
+#[rstest]
+fn test_process_order_book_deltas_skips_duplicate_sequences(
+    instrument_eth_usdt: InstrumentAny,
+    msgbus: MessageBus,
+) {
+    let config = OrderMatchingEngineConfig::default().with_skip_duplicate_deltas(true);
+    let mut engine_l2 = get_order_matching_engine_l2(
+        instrument_eth_usdt.clone(),
+        Rc::new(RefCell::new(msgbus)),
+        None,
+        None,
+        Some(config),
+    );
+    let ask_delta = |action: BookAction, price: &str, sequence: u64| {
+        OrderBookDeltaTestBuilder::new(instrument_eth_usdt.id())
+            .book_action(action)
+            .book_order(BookOrder::new(
+                OrderSide::Sell,
+                Price::from(price),
+                Quantity::from("10.000"),
+                1,
+            ))
+            .sequence(sequence)
+            .build()
+    };
+
+    engine_l2.process_order_book_deltas(&OrderBookDeltas::new(
+        instrument_eth_usdt.id(),
+        vec![
+            ask_delta(BookAction::Add, "1500.00", 1),
+            ask_delta(BookAction::Delete, "1500.00", 2),
+        ],
+    ));
+    // Replayed batch overlapping the deltas already applied, where only the last is new
+    engine_l2.process_order_book_deltas(&OrderBookDeltas::new(
+        instrument_eth_usdt.id(),
+        vec![
+            ask_delta(BookAction::Add, "1500.00", 1),
+            ask_delta(BookAction::Add, "1502.00", 3),
+        ],
+    ));
+
+    let book = engine_l2.get_book();
+    assert_eq!(book.best_ask_price(), Some(Price::from("1502.00")));
+    assert_eq!(book.asks(None).count(), 1);
+    assert_eq!(book.sequence, 3);
+}
+
+#[rstest]
+fn test_process_order_book_deltas_skips_sequence_repeated_within_batch(
+    instrument_eth_usdt: InstrumentAny,
+    msgbus: MessageBus,
+) {
+    let config = OrderMatchingEngineConfig::default().with_skip_duplicate_deltas(true);
+    let mut engine_l2 = get_order_matching_engine_l2(
+        instrument_eth_usdt.clone(),
+        Rc::new(RefCell::new(msgbus)),
+        None,
+        None,
+        Some(config),
+    );
+    let ask_delta = |action: BookAction, sequence: u64| {
+        OrderBookDeltaTestBuilder::new(instrument_eth_usdt.id())
+            .book_action(action)
+            .book_order(BookOrder::new(
+                OrderSide::Sell,
+                Price::from("1500.00"),
+                Quantity::from("10.000"),
+                1,
+            ))
+            .sequence(sequence)
+            .build()
+    };
+
+    engine_l2.process_order_book_deltas(&OrderBookDeltas::new(
+        instrument_eth_usdt.id(),
+        vec![
+            ask_delta(BookAction::Add, 1),
+            ask_delta(BookAction::Delete, 2),
+            ask_delta(BookAction::Add, 2),
+        ],
+    ));
+
+    let book = engine_l2.get_book();
+    assert_eq!(book.best_ask_price(), None);
+    assert_eq!(book.sequence, 2);
+}
+
+#[rstest]
+fn test_session_vwap_from_trade_ticks(instrument_eth_usdt: InstrumentAny, msgbus: MessageBus) {
+    let mut engine = get_order_matching_engine(
+        instrument_eth_usdt.clone(),
+        Rc::new(RefCell::new(msgbus)),
+        None,
+        None,
+        None,
+    );
+    assert_eq!(engine.session_vwap(), None);
+    assert_eq!(engine.last_trade(), None);
+
+    let trades = [
+        ("1500.00", "1.000", "1"),
+        ("1510.00", "3.000", "2"),
+        ("1490.00", "4.000", "3"),
+    ];
+    for (i, (price, size, trade_id)) in trades.into_iter().enumerate() {
+        engine.process_trade_tick(&TradeTick::new(
+            instrument_eth_usdt.id(),
+            Price::from(price),
+            Quantity::from(size),
+            AggressorSide::Buyer,
+            TradeId::new(trade_id),
+            UnixNanos::from(i as u64 + 1),
+            UnixNanos::from(i as u64 + 1),
+        ));
+    }
+
+    // (1500 * 1 + 1510 * 3 + 1490 * 4) / 8 = 1498.75
+    assert_eq!(engine.session_vwap(), Some(Price::from("1498.75")));
+    let last_trade = engine.last_trade().unwrap();
+    assert_eq!(last_trade.price, Price::from("1490.00"));
+    assert_eq!(last_trade.trade_id, TradeId::new("3"));
+
+    engine.reset();
+    assert_eq!(engine.session_vwap(), None);
+    assert_eq!(engine.last_trade(), None);
+}
+
+#[rstest]
+#[case(None, OrderEventType::Accepted)]
+#[case(Some(TraderId::from("TRADER-001")), OrderEventType::Rejected)]
+#[case(Some(TraderId::from("TRADER-002")), OrderEventType::Accepted)]
+fn test_process_order_with_expected_trader_id(
+    instrument_eth_usdt: InstrumentAny,
+    mut msgbus: MessageBus,
+    order_event_handler: ShareableMessageHandler,
+    account_id: AccountId,
+    #[case] expected_trader_id: Option<TraderId>,
+    #[case] expected_event: OrderEventType,
+) {
+    msgbus.register(
+        msgbus.switchboard.exec_engine_process,
+        order_event_handler.clone(),
+    );
+    let config = OrderMatchingEngineConfig::default().with_expected_trader_id(expected_trader_id);
+    let mut engine_l2 = get_order_matching_engine_l2(
+        instrument_eth_usdt.clone(),
+        Rc::new(RefCell::new(msgbus)),
+        None,
+        None,
+        Some(config),
+    );
+
+    let mut limit_order = OrderTestBuilder::new(OrderType::Limit)
+        .trader_id(TraderId::from("TRADER-002"))
+        .instrument_id(instrument_eth_usdt.id())
+        .side(OrderSide::Buy)
+        .price(Price::from("1495.00"))
+        .quantity(Quantity::from("1.000"))
+        .build();
+    engine_l2.process_order(&mut limit_order, account_id);
+
+    let saved_messages = get_order_event_handler_messages(order_event_handler);
+    assert_eq!(saved_messages.len(), 1);
+    assert_eq!(saved_messages[0].event_type(), expected_event);
+    if let OrderEventAny::Rejected(rejected) = &saved_messages[0] {
+        assert_eq!(
+            rejected.reason,
+            Ustr::from("Order trader ID TRADER-002 does not match expected trader ID TRADER-001")
+        );
+    }
+}
+
+#[rstest]
+fn test_paper_fill_at_mid(
+    instrument_eth_usdt: InstrumentAny,
+    mut msgbus: MessageBus,
+    order_event_handler: ShareableMessageHandler,
+    account_id: AccountId,
+) {
+    msgbus.register(
+        msgbus.switchboard.exec_engine_process,
+        order_event_handler.clone(),
+    );
+    let config = OrderMatchingEngineConfig::default().with_paper_fill_at_mid(true);
+    let mut engine = get_order_matching_engine(
+        instrument_eth_usdt.clone(),
+        Rc::new(RefCell::new(msgbus)),
+        None,
+        None,
+        Some(config),
+    );
+    engine.process_quote_tick(&QuoteTick::new(
+        instrument_eth_usdt.id(),
+        Price::from("1499.00"),
+        Price::from("1501.00"),
+        Quantity::from("1.000"),
+        Quantity::from("1.000"),
+        UnixNanos::from(1),
+        UnixNanos::from(1),
+    ));
+
+    // Quantity exceeds the top-of-book size, but fills in full at the mid
+    let mut market_order = OrderTestBuilder::new(OrderType::Market)
+        .instrument_id(instrument_eth_usdt.id())
+        .side(OrderSide::Buy)
+        .quantity(Quantity::from("5.000"))
+        .build();
+    engine.process_order(&mut market_order, account_id);
+
+    let saved_messages = get_order_event_handler_messages(order_event_handler);
+    assert_eq!(saved_messages.len(), 1);
+    let OrderEventAny::Filled(order_filled) = &saved_messages[0] else {
+        panic!("Expected `OrderFilled` event");
+    };
+    assert_eq!(order_filled.last_px, Price::from("1500.00"));
+    assert_eq!(order_filled.last_qty, Quantity::from("5.000"));
+    assert_eq!(order_filled.liquidity_side, LiquiditySide::Taker);
+}
+
+#[rstest]
+fn test_process_cancel_order_list(
+    instrument_eth_usdt: InstrumentAny,
+    mut msgbus: MessageBus,
+    order_event_handler: ShareableMessageHandler,
+    account_id: AccountId,
+) {
+    msgbus.register(
+        msgbus.switchboard.exec_engine_process,
+        order_event_handler.clone(),
+    );
+    let mut engine_l2 = get_order_matching_engine_l2(
+        instrument_eth_usdt.clone(),
+        Rc::new(RefCell::new(msgbus)),
+        None,
+        None,
+        None,
+    );
+    let order_list_id = OrderListId::from("OL-001");
+    let orders = [
+        ("O-19700101-000000-001-001-1", Some(order_list_id)),
+        ("O-19700101-000000-001-001-2", Some(order_list_id)),
+        ("O-19700101-000000-001-001-3", None),
+    ];
+    for (client_order_id, order_list_id) in orders {
+        let mut builder = OrderTestBuilder::new(OrderType::Limit);
+        builder
+            .instrument_id(instrument_eth_usdt.id())
+            .client_order_id(ClientOrderId::from(client_order_id))
+            .side(OrderSide::Buy)
+            .price(Price::from("1495.00"))
+            .quantity(Quantity::from("1.000"));
+        if let Some(order_list_id) = order_list_id {
+            builder.order_list_id(order_list_id);
+        }
+        let mut order = builder.build();
+        engine_l2.process_order(&mut order, account_id);
+    }
+
+    engine_l2.process_cancel_order_list(order_list_id, account_id);
+
+    let saved_messages = get_order_event_handler_messages(order_event_handler);
+    let canceled: Vec<ClientOrderId> = saved_messages
+        .iter()
+        .filter_map(|event| match event {
+            OrderEventAny::Canceled(canceled) => Some(canceled.client_order_id),
+            _ => None,
+        })
+        .collect();
+    assert_eq!(
+        canceled,
+        vec![
+            ClientOrderId::from("O-19700101-000000-001-001-1"),
+            ClientOrderId::from("O-19700101-000000-001-001-2"),
+        ]
+    );
+    assert_eq!(engine_l2.get_open_orders().len(), 1);
+}
+
+#[rstest]
+fn test_cancel_order_list_on_fill(
+    instrument_eth_usdt: InstrumentAny,
+    mut msgbus: MessageBus,
+    order_event_handler: ShareableMessageHandler,
+    account_id: AccountId,
+) {
+    msgbus.register(
+        msgbus.switchboard.exec_engine_process,
+        order_event_handler.clone(),
+    );
+    let config = OrderMatchingEngineConfig::default().with_cancel_order_list_on_fill(true);
+    let mut engine_l2 = get_order_matching_engine_l2(
+        instrument_eth_usdt.clone(),
+        Rc::new(RefCell::new(msgbus)),
+        None,
+        None,
+        Some(config),
+    );
+    let order_list_id = OrderListId::from("OL-001");
+    for (client_order_id, price) in [
+        ("O-19700101-000000-001-001-1", "1495.00"),
+        ("O-19700101-000000-001-001-2", "1490.00"),
+    ] {
+        let mut order = OrderTestBuilder::new(OrderType::Limit)
+            .instrument_id(instrument_eth_usdt.id())
+            .client_order_id(ClientOrderId::from(client_order_id))
+            .order_list_id(order_list_id)
+            .side(OrderSide::Buy)
+            .price(Price::from(price))
+            .quantity(Quantity::from("1.000"))
+            .build();
+        engine_l2.process_order(&mut order, account_id);
+    }
+
+    // Market moves down to fill the first order of the list only
+    let orderbook_delta_sell = OrderBookDeltaTestBuilder::new(instrument_eth_usdt.id())
+        .book_action(BookAction::Add)
+        .book_order(BookOrder::new(
+            OrderSide::Sell,
+            Price::from("1495.00"),
+            Quantity::from("10.000"),
+            1,
+        ))
+        .build();
+    engine_l2.process_order_book_delta(&orderbook_delta_sell);
+
+    let saved_messages = get_order_event_handler_messages(order_event_handler);
+    let events: Vec<(OrderEventType, ClientOrderId)> = saved_messages
+        .iter()
+        .map(|event| (event.event_type(), event.client_order_id()))
+        .filter(|(event_type, _)| *event_type != OrderEventType::Accepted)
+        .collect();
+    assert_eq!(
+        events,
+        vec![
+            (
+                OrderEventType::Filled,
+                ClientOrderId::from("O-19700101-000000-001-001-1")
+            ),
+            (
+                OrderEventType::Canceled,
+                ClientOrderId::from("O-19700101-000000-001-001-2")
+            ),
+        ]
+    );
+}
+
+#[rstest]
+fn test_fill_and_rejection_ratios(
+    instrument_eth_usdt: InstrumentAny,
+    msgbus: MessageBus,
+    account_id: AccountId,
+) {
+    let mut engine_l2 = get_order_matching_engine_l2(
+        instrument_eth_usdt.clone(),
+        Rc::new(RefCell::new(msgbus)),
+        None,
+        None,
+        None,
+    );
+    assert_eq!(engine_l2.fill_ratio(), 0.0);
+    assert_eq!(engine_l2.rejection_ratio(), 0.0);
+
+    let orderbook_delta_sell = OrderBookDeltaTestBuilder::new(instrument_eth_usdt.id())
+        .book_action(BookAction::Add)
+        .book_order(BookOrder::new(
+            OrderSide::Sell,
+            Price::from("1500.00"),
+            Quantity::from("10.000"),
+            1,
+        ))
+        .build();
+    engine_l2.process_order_book_delta(&orderbook_delta_sell);
+
+    let mut orders = [
+        // Fills
+        OrderTestBuilder::new(OrderType::Market)
+            .instrument_id(instrument_eth_usdt.id())
+            .client_order_id(ClientOrderId::from("O-19700101-000000-001-001-1"))
+            .side(OrderSide::Buy)
+            .quantity(Quantity::from("1.000"))
+            .build(),
+        // Rests
+        OrderTestBuilder::new(OrderType::Limit)
+            .instrument_id(instrument_eth_usdt.id())
+            .client_order_id(ClientOrderId::from("O-19700101-000000-001-001-2"))
+            .side(OrderSide::Buy)
+            .price(Price::from("1495.00"))
+            .quantity(Quantity::from("1.000"))
+            .build(),
+        // Rejected as it would be a taker
+        OrderTestBuilder::new(OrderType::Limit)
+            .instrument_id(instrument_eth_usdt.id())
+            .client_order_id(ClientOrderId::from("O-19700101-000000-001-001-3"))
+            .side(OrderSide::Buy)
+            .price(Price::from("1500.00"))
+            .quantity(Quantity::from("1.000"))
+            .post_only(true)
+            .build(),
+        // Fills
+        OrderTestBuilder::new(OrderType::Market)
+            .instrument_id(instrument_eth_usdt.id())
+            .client_order_id(ClientOrderId::from("O-19700101-000000-001-001-4"))
+            .side(OrderSide::Buy)
+            .quantity(Quantity::from("2.000"))
+            .build(),
+    ];
+    for order in &mut orders {
+        engine_l2.process_order(order, account_id);
+    }
+
+    assert_eq!(engine_l2.fill_ratio(), 0.5);
+    assert_eq!(engine_l2.rejection_ratio(), 0.25);
+}
+
+#[rstest]
+fn test_process_stop_limit_order_triggered_repriced_with_protect(
+    instrument_eth_usdt: InstrumentAny,
+    mut msgbus: MessageBus,
+    order_event_handler: ShareableMessageHandler,
+    account_id: AccountId,
+) {
+    msgbus.register(
+        msgbus.switchboard.exec_engine_process,
+        order_event_handler.clone(),
+    );
+    let config = OrderMatchingEngineConfig::default().with_stop_limit_protect(true);
+    let mut engine_l2 = get_order_matching_engine_l2(
+        instrument_eth_usdt.clone(),
+        Rc::new(RefCell::new(msgbus)),
+        None,
+        None,
+        Some(config),
+    );
+
+    let orderbook_delta_sell = OrderBookDeltaTestBuilder::new(instrument_eth_usdt.id())
+        .book_action(BookAction::Add)
+        .book_order(BookOrder::new(
+            OrderSide::Sell,
+            Price::from("1500.00"),
+            Quantity::from("1.000"),
+            1,
+        ))
+        .build();
+    engine_l2.process_order_book_delta(&orderbook_delta_sell);
+
+    // Triggered on submission, with a limit of 1490 below the ask of 1500
+    let mut stop_order = OrderTestBuilder::new(OrderType::StopLimit)
+        .instrument_id(instrument_eth_usdt.id())
+        .side(OrderSide::Buy)
+        .trigger_price(Price::from("1495.00"))
+        .price(Price::from("1490.00"))
+        .quantity(Quantity::from("1.000"))
+        .build();
+    engine_l2.process_order(&mut stop_order, account_id);
+
+    let saved_messages = get_order_event_handler_messages(order_event_handler);
+    let event_types: Vec<OrderEventType> = saved_messages
+        .iter()
+        .map(OrderEventAny::event_type)
+        .collect();
+    assert_eq!(
+        event_types,
+        vec![
+            OrderEventType::Accepted,
+            OrderEventType::Triggered,
+            OrderEventType::Updated,
+            OrderEventType::Filled,
+        ]
+    );
+    let OrderEventAny::Updated(order_updated) = &saved_messages[2] else {
+        panic!("Expected `OrderUpdated` event");
+    };
+    assert_eq!(order_updated.price, Some(Price::from("1500.00")));
+    let OrderEventAny::Filled(order_filled) = &saved_messages[3] else {
+        panic!("Expected `OrderFilled` event");
+    };
+    assert_eq!(order_filled.last_px, Price::from("1500.00"));
+    assert_eq!(order_filled.liquidity_side, LiquiditySide::Taker);
+}
+
+#[rstest]
+fn test_order_triggered_reports_trigger_and_market_prices(
+    instrument_eth_usdt: InstrumentAny,
+    mut msgbus: MessageBus,
+    order_event_handler: ShareableMessageHandler,
+    account_id: AccountId,
+) {
+    msgbus.register(
+        msgbus.switchboard.exec_engine_process,
+        order_event_handler.clone(),
+    );
+    let mut engine_l2 = get_order_matching_engine_l2(
+        instrument_eth_usdt.clone(),
+        Rc::new(RefCell::new(msgbus)),
+        None,
+        None,
+        None,
+    );
+    let book_delta = |action: BookAction, price: &str, order_id: u64| {
+        OrderBookDeltaTestBuilder::new(instrument_eth_usdt.id())
+            .book_action(action)
+            .book_order(BookOrder::new(
+                OrderSide::Sell,
+                Price::from(price),
+                Quantity::from("1.000"),
+                order_id,
+            ))
+            .build()
+    };
+    engine_l2.process_order_book_delta(&book_delta(BookAction::Add, "1500.00", 1));
+
+    let mut stop_order = OrderTestBuilder::new(OrderType::StopLimit)
+        .instrument_id(instrument_eth_usdt.id())
+        .side(OrderSide::Buy)
+        .trigger_price(Price::from("1505.00"))
+        .price(Price::from("1502.00"))
+        .quantity(Quantity::from("1.000"))
+        .build();
+    engine_l2.process_order(&mut stop_order, account_id);
+
+    // Ask gaps through the trigger price from 1500 to 1506
+    engine_l2.process_order_book_delta(&book_delta(BookAction::Add, "1506.00", 2));
+    engine_l2.process_order_book_delta(&book_delta(BookAction::Delete, "1500.00", 1));
+
+    let saved_messages = get_order_event_handler_messages(order_event_handler);
+    let order_triggered = saved_messages
+        .iter()
+        .find_map(|event| match event {
+            OrderEventAny::Triggered(triggered) => Some(triggered),
+            _ => None,
+        })
+        .expect("Expected `OrderTriggered` event");
+    assert_eq!(order_triggered.trigger_price, Some(Price::from("1505.00")));
+    assert_eq!(order_triggered.market_price, Some(Price::from("1506.00")));
+}
+
+#[rstest]
+fn test_is_ready_after_both_sides_initialized(
+    instrument_eth_usdt: InstrumentAny,
+    msgbus: MessageBus,
+) {
+    let mut engine_l2 = get_order_matching_engine_l2(
+        instrument_eth_usdt.clone(),
+        Rc::new(RefCell::new(msgbus)),
+        None,
+        None,
+        None,
+    );
+    let ready_count = Rc::new(RefCell::new(0));
+    let ready_count_clone = ready_count.clone();
+    engine_l2.on_ready(Box::new(move || {
+        *ready_count_clone.borrow_mut() += 1;
+    }));
+    let book_delta = |side: OrderSide, price: &str| {
+        OrderBookDeltaTestBuilder::new(instrument_eth_usdt.id())
+            .book_action(BookAction::Add)
+            .book_order(BookOrder::new(
+                side,
+                Price::from(price),
+                Quantity::from("10.000"),
+                1,
+            ))
+            .build()
+    };
+    assert!(!engine_l2.is_ready());
+
+    engine_l2.process_order_book_delta(&book_delta(OrderSide::Sell, "1500.00"));
+    assert!(!engine_l2.is_ready());
+    assert_eq!(*ready_count.borrow(), 0);
+
+    engine_l2.process_order_book_delta(&book_delta(OrderSide::Buy, "1490.00"));
+    assert!(engine_l2.is_ready());
+    assert_eq!(*ready_count.borrow(), 1);
+
+    // Remains ready without signaling again
+    engine_l2.process_order_book_delta(&book_delta(OrderSide::Buy, "1491.00"));
+    assert!(engine_l2.is_ready());
+    assert_eq!(*ready_count.borrow(), 1);
+}
+
+#[rstest]
+fn test_process_gtd_order_without_expire_time_rejected(
+    instrument_eth_usdt: InstrumentAny,
+    mut msgbus: MessageBus,
+    order_event_handler: ShareableMessageHandler,
+    account_id: AccountId,
+) {
+    msgbus.register(
+        msgbus.switchboard.exec_engine_process,
+        order_event_handler.clone(),
+    );
+    let mut engine_l2 = get_order_matching_engine_l2(
+        instrument_eth_usdt.clone(),
+        Rc::new(RefCell::new(msgbus)),
+        None,
+        None,
+        None,
+    );
+
+    let mut stop_order = OrderTestBuilder::new(OrderType::StopLimit)
+        .instrument_id(instrument_eth_usdt.id())
+        .side(OrderSide::Buy)
+        .trigger_price(Price::from("1505.00"))
+        .price(Price::from("1506.00"))
+        .quantity(Quantity::from("1.000"))
+        .time_in_force(TimeInForce::Gtd)
+        .build();
+    assert_eq!(stop_order.expire_time(), None);
+    engine_l2.process_order(&mut stop_order, account_id);
+
+    let saved_messages = get_order_event_handler_messages(order_event_handler);
+    assert_eq!(saved_messages.len(), 1);
+    let OrderEventAny::Rejected(order_rejected) = &saved_messages[0] else {
+        panic!("Expected `OrderRejected` event");
+    };
+    assert_eq!(
+        order_rejected.reason,
+        Ustr::from("GTD order requires expire time")
+    );
+    assert!(engine_l2.get_open_orders().is_empty());
+}
+
+#[rstest]
+#[case(PastExpireTimeHandling::Accept, vec![OrderEventType::Accepted])]
+#[case(PastExpireTimeHandling::Reject, vec![OrderEventType::Rejected])]
+#[case(PastExpireTimeHandling::Expire, vec![OrderEventType::Accepted, OrderEventType::Expired])]
+fn test_process_gtd_order_with_past_expire_time(
+    instrument_eth_usdt: InstrumentAny,
+    mut msgbus: MessageBus,
+    order_event_handler: ShareableMessageHandler,
+    account_id: AccountId,
+    #[case] handling: PastExpireTimeHandling,
+    #[case] expected_events: Vec<OrderEventType>,
+) {
+    msgbus.register(
+        msgbus.switchboard.exec_engine_process,
+        order_event_handler.clone(),
+    );
+    let config = OrderMatchingEngineConfig {
+        support_gtd_orders: true,
+        ..Default::default()
+    }
+    .with_past_expire_time_handling(handling);
+    let mut engine_l2 = get_order_matching_engine_l2(
+        instrument_eth_usdt.clone(),
+        Rc::new(RefCell::new(msgbus)),
+        None,
+        None,
+        Some(config),
+    );
+    engine_l2.set_clock(Rc::new(AtomicTime::new(false, UnixNanos::from(10_000))));
+
+    let mut limit_order = OrderTestBuilder::new(OrderType::Limit)
+        .instrument_id(instrument_eth_usdt.id())
+        .side(OrderSide::Buy)
+        .price(Price::from("1495.00"))
+        .quantity(Quantity::from("1.000"))
+        .expire_time(UnixNanos::from(5_000))
+        .build();
+    engine_l2.process_order(&mut limit_order, account_id);
+
+    let saved_messages = get_order_event_handler_messages(order_event_handler);
+    let event_types: Vec<OrderEventType> = saved_messages
+        .iter()
+        .map(OrderEventAny::event_type)
+        .collect();
+    assert_eq!(event_types, expected_events);
+    assert_eq!(
+        engine_l2.get_open_orders().len(),
+        usize::from(handling == PastExpireTimeHandling::Accept)
+    );
+}
+
+#[rstest]
+fn test_min_fill_report_qty_aggregates_dust_fills(
+    instrument_eth_usdt: InstrumentAny,
+    mut msgbus: MessageBus,
+    order_event_handler: ShareableMessageHandler,
+    account_id: AccountId,
+) {
+    msgbus.register(
+        msgbus.switchboard.exec_engine_process,
+        order_event_handler.clone(),
+    );
+    let config = OrderMatchingEngineConfig::default()
+        .with_min_fill_report_qty(Some(Quantity::from("0.003")));
+    let mut engine_l2 = get_order_matching_engine_l2(
+        instrument_eth_usdt.clone(),
+        Rc::new(RefCell::new(msgbus)),
+        None,
+        None,
+        Some(config),
+    );
+
+    // Three levels of tiny resting quantity ahead of a deeper level
+    for (i, (price, quantity)) in [
+        ("1500.00", "0.001"),
+        ("1501.00", "0.001"),
+        ("1502.00", "0.001"),
+        ("1503.00", "1.000"),
+    ]
+    .into_iter()
+    .enumerate()
+    {
+        let delta = OrderBookDeltaTestBuilder::new(instrument_eth_usdt.id())
+            .book_action(BookAction::Add)
+            .book_order(BookOrder::new(
+                OrderSide::Sell,
+                Price::from(price),
+                Quantity::from(quantity),
+                i as u64,
+            ))
+            .build();
+        engine_l2.process_order_book_delta(&delta);
+    }
+
+    let mut market_order = OrderTestBuilder::new(OrderType::Market)
+        .instrument_id(instrument_eth_usdt.id())
+        .side(OrderSide::Buy)
+        .quantity(Quantity::from("0.004"))
+        .build();
+    engine_l2.process_order(&mut market_order, account_id);
+
+    let saved_messages = get_order_event_handler_messages(order_event_handler);
+    let fills: Vec<(Price, Quantity)> = saved_messages
+        .iter()
+        .filter_map(|event| match event {
+            OrderEventAny::Filled(filled) => Some((filled.last_px, filled.last_qty)),
+            _ => None,
+        })
+        .collect();
+    // Dust is aggregated at its average price, with the remaining dust reported at the end
+    assert_eq!(
+        fills,
+        vec![
+            (Price::from("1501.00"), Quantity::from("0.003")),
+            (Price::from("1503.00"), Quantity::from("0.001")),
+        ]
+    );
+}
+
+#[rstest]
+fn test_reject_takers(
+    instrument_eth_usdt: InstrumentAny,
+    mut msgbus: MessageBus,
+    order_event_handler: ShareableMessageHandler,
+    account_id: AccountId,
+) {
+    msgbus.register(
+        msgbus.switchboard.exec_engine_process,
+        order_event_handler.clone(),
+    );
+    let config = OrderMatchingEngineConfig::default().with_reject_takers(true);
+    let mut engine_l2 = get_order_matching_engine_l2(
+        instrument_eth_usdt.clone(),
+        Rc::new(RefCell::new(msgbus)),
+        None,
+        None,
+        Some(config),
+    );
+    let orderbook_delta_sell = OrderBookDeltaTestBuilder::new(instrument_eth_usdt.id())
+        .book_action(BookAction::Add)
+        .book_order(BookOrder::new(
+            OrderSide::Sell,
+            Price::from("1500.00"),
+            Quantity::from("1.000"),
+            1,
+        ))
+        .build();
+    engine_l2.process_order_book_delta(&orderbook_delta_sell);
+
+    let mut market_order = OrderTestBuilder::new(OrderType::Market)
+        .instrument_id(instrument_eth_usdt.id())
+        .client_order_id(ClientOrderId::from("O-19700101-000000-001-001-1"))
+        .side(OrderSide::Buy)
+        .quantity(Quantity::from("1.000"))
+        .build();
+    engine_l2.process_order(&mut market_order, account_id);
+    let mut crossing_limit_order = OrderTestBuilder::new(OrderType::Limit)
+        .instrument_id(instrument_eth_usdt.id())
+        .client_order_id(ClientOrderId::from("O-19700101-000000-001-001-2"))
+        .side(OrderSide::Buy)
+        .price(Price::from("1501.00"))
+        .quantity(Quantity::from("1.000"))
+        .build();
+    engine_l2.process_order(&mut crossing_limit_order, account_id);
+    let mut passive_limit_order = OrderTestBuilder::new(OrderType::Limit)
+        .instrument_id(instrument_eth_usdt.id())
+        .client_order_id(ClientOrderId::from("O-19700101-000000-001-001-3"))
+        .side(OrderSide::Buy)
+        .price(Price::from("1499.00"))
+        .quantity(Quantity::from("1.000"))
+        .build();
+    engine_l2.process_order(&mut passive_limit_order, account_id);
+
+    let saved_messages = get_order_event_handler_messages(order_event_handler);
+    assert_eq!(saved_messages.len(), 3);
+    for rejected in &saved_messages[..2] {
+        assert_eq!(rejected.event_type(), OrderEventType::Rejected);
+        assert_eq!(
+            rejected.message().unwrap(),
+            Ustr::from("Taker orders not permitted")
+        );
+    }
+    assert_eq!(saved_messages[2].event_type(), OrderEventType::Accepted);
+    assert_eq!(
+        saved_messages[2].client_order_id(),
+        passive_limit_order.client_order_id()
+    );
+}
+
+#[rstest]
+fn test_process_limit_order_resolves_quote_quantity(
+    instrument_eth_usdt: InstrumentAny,
+    mut msgbus: MessageBus,
+    order_event_handler: ShareableMessageHandler,
+    account_id: AccountId,
+) {
+    msgbus.register(
+        msgbus.switchboard.exec_engine_process,
+        order_event_handler.clone(),
+    );
+    let config = OrderMatchingEngineConfig::default().with_resolve_limit_quote_quantity(true);
+    let mut engine_l2 = get_order_matching_engine_l2(
+        instrument_eth_usdt.clone(),
+        Rc::new(RefCell::new(msgbus)),
+        None,
+        None,
+        Some(config),
+    );
+
+    // Quote quantity of 3000 USDT at a limit price of 1500 is 2 ETH
+    let mut limit_order = OrderTestBuilder::new(OrderType::Limit)
+        .instrument_id(instrument_eth_usdt.id())
+        .side(OrderSide::Buy)
+        .price(Price::from("1500.00"))
+        .quantity(Quantity::from("3000.000"))
+        .quote_quantity(true)
+        .build();
+    engine_l2.process_order(&mut limit_order, account_id);
+
+    let saved_messages = get_order_event_handler_messages(order_event_handler);
+    let event_types: Vec<OrderEventType> = saved_messages
+        .iter()
+        .map(OrderEventAny::event_type)
+        .collect();
+    assert_eq!(
+        event_types,
+        vec![OrderEventType::Updated, OrderEventType::Accepted]
+    );
+    let OrderEventAny::Updated(order_updated) = &saved_messages[0] else {
+        panic!("Expected `OrderUpdated` event");
+    };
+    assert_eq!(order_updated.quantity, Quantity::from("2.000"));
+    let open_bid_orders = engine_l2.get_open_bid_orders();
+    assert_eq!(open_bid_orders.len(), 1);
+    let resting_order = open_bid_orders[0].to_any();
+    assert_eq!(resting_order.quantity(), Quantity::from("2.000"));
+    assert!(!resting_order.is_quote_quantity());
+}
+
+#[rstest]
+#[case(false, vec![OrderEventType::Accepted])]
+#[case(true, vec![OrderEventType::Submitted, OrderEventType::Accepted])]
+fn test_process_order_emit_submitted(
+    instrument_eth_usdt: InstrumentAny,
+    mut msgbus: MessageBus,
+    order_event_handler: ShareableMessageHandler,
+    account_id: AccountId,
+    #[case] emit_submitted: bool,
+    #[case] expected_event_types: Vec<OrderEventType>,
+) {
+    msgbus.register(
+        msgbus.switchboard.exec_engine_process,
+        order_event_handler.clone(),
+    );
+    let config = OrderMatchingEngineConfig::default().with_emit_submitted(emit_submitted);
+    let mut engine_l2 = get_order_matching_engine_l2(
+        instrument_eth_usdt.clone(),
+        Rc::new(RefCell::new(msgbus)),
+        None,
+        None,
+        Some(config),
+    );
+
+    let mut limit_order = OrderTestBuilder::new(OrderType::Limit)
+        .instrument_id(instrument_eth_usdt.id())
+        .side(OrderSide::Buy)
+        .price(Price::from("1500.00"))
+        .quantity(Quantity::from("1.000"))
+        .build();
+    engine_l2.process_order(&mut limit_order, account_id);
+
+    let saved_messages = get_order_event_handler_messages(order_event_handler);
+    let event_types: Vec<OrderEventType> = saved_messages
+        .iter()
+        .map(OrderEventAny::event_type)
+        .collect();
+    assert_eq!(event_types, expected_event_types);
+    assert_eq!(limit_order.status(), OrderStatus::Accepted);
+}
+
+#[cfg(feature = "export")]
+#[rstest]
+fn test_export_fills_to_csv(
+    instrument_eth_usdt: InstrumentAny,
+    msgbus: MessageBus,
+    account_id: AccountId,
+) {
+    let config = OrderMatchingEngineConfig::default().with_record_trades(true);
+    let mut engine_l2 = get_order_matching_engine_l2(
+        instrument_eth_usdt.clone(),
+        Rc::new(RefCell::new(msgbus)),
+        None,
+        None,
+        Some(config),
+    );
+    for (i, price) in ["1500.00", "1501.00"].into_iter().enumerate() {
+        let delta = OrderBookDeltaTestBuilder::new(instrument_eth_usdt.id())
+            .book_action(BookAction::Add)
+            .book_order(BookOrder::new(
+                OrderSide::Sell,
+                Price::from(price),
+                Quantity::from("1.000"),
+                i as u64,
+            ))
+            .build();
+        engine_l2.process_order_book_delta(&delta);
+    }
+
+    // Sweeps both levels for two fills
+    let mut market_order = OrderTestBuilder::new(OrderType::Market)
+        .instrument_id(instrument_eth_usdt.id())
+        .side(OrderSide::Buy)
+        .quantity(Quantity::from("2.000"))
+        .build();
+    engine_l2.process_order(&mut market_order, account_id);
+
+    let temp_dir = tempfile::tempdir().unwrap();
+    let path = temp_dir.path().join("fills.csv");
+    engine_l2
+        .export_fills(&path, FillsExportFormat::Csv)
+        .unwrap();
+
+    let contents = std::fs::read_to_string(&path).unwrap();
+    let mut lines = contents.lines();
+    let header: Vec<&str> = lines.next().unwrap().split(',').collect();
+    let column = |name: &str| header.iter().position(|field| *field == name).unwrap();
+    let rows: Vec<Vec<&str>> = lines.map(|line| line.split(',').collect()).collect();
+    let fills = engine_l2.trades();
+    assert_eq!(fills.len(), 2);
+    assert_eq!(rows.len(), fills.len());
+    for (row, fill) in rows.iter().zip(fills) {
+        assert_eq!(
+            row[column("client_order_id")],
+            fill.client_order_id.to_string()
+        );
+        assert_eq!(row[column("trade_id")], fill.trade_id.to_string());
+        assert_eq!(row[column("order_side")], "BUY");
+        assert_eq!(
+            row[column("last_qty")].parse::<f64>().unwrap(),
+            fill.last_qty.as_f64()
+        );
+        assert_eq!(
+            row[column("last_px")].parse::<f64>().unwrap(),
+            fill.last_px.as_f64()
+        );
+        assert_eq!(
+            row[column("commission_currency")],
+            fill.commission.unwrap().currency.to_string()
+        );
+        assert_eq!(
+            row[column("ts_event")].parse::<u64>().unwrap(),
+            fill.ts_event.as_u64()
+        );
+    }
+}
+
+#[rstest]
+fn test_order_triggered_by_trigger_instrument(
+    instrument_eth_usdt: InstrumentAny,
+    mut msgbus: MessageBus,
+    order_event_handler: ShareableMessageHandler,
+    account_id: AccountId,
+) {
+    msgbus.register(
+        msgbus.switchboard.exec_engine_process,
+        order_event_handler.clone(),
+    );
+    let config = OrderMatchingEngineConfig::default().with_use_trigger_instruments(true);
+    let mut engine_l2 = get_order_matching_engine_l2(
+        instrument_eth_usdt.clone(),
+        Rc::new(RefCell::new(msgbus)),
+        None,
+        None,
+        Some(config),
+    );
+    let orderbook_delta_sell = OrderBookDeltaTestBuilder::new(instrument_eth_usdt.id())
+        .book_action(BookAction::Add)
+        .book_order(BookOrder::new(
+            OrderSide::Sell,
+            Price::from("1500.00"),
+            Quantity::from("1.000"),
+            1,
+        ))
+        .build();
+    engine_l2.process_order_book_delta(&orderbook_delta_sell);
+
+    // Buy ETH when BTC rises through 60000.00, which this engine's book would trigger at once
+    let trigger_instrument_id = InstrumentId::from("BTCUSDT-PERP.BINANCE");
+    let mut stop_order = OrderTestBuilder::new(OrderType::StopLimit)
+        .instrument_id(instrument_eth_usdt.id())
+        .side(OrderSide::Buy)
+        .trigger_price(Price::from("60000.00"))
+        .price(Price::from("1510.00"))
+        .quantity(Quantity::from("1.000"))
+        .trigger_instrument_id(trigger_instrument_id)
+        .build();
+    engine_l2.process_order(&mut stop_order, account_id);
+
+    let trigger_quote = |bid: &str, ask: &str| {
+        QuoteTick::new(
+            trigger_instrument_id,
+            Price::from(bid),
+            Price::from(ask),
+            Quantity::from("1.000"),
+            Quantity::from("1.000"),
+            UnixNanos::default(),
+            UnixNanos::default(),
+        )
+    };
+    engine_l2.process_trigger_quote_tick(&trigger_quote("59990.00", "59995.00"));
+    let saved_messages = get_order_event_handler_messages(order_event_handler.clone());
+    let event_types: Vec<OrderEventType> = saved_messages
+        .iter()
+        .map(OrderEventAny::event_type)
+        .collect();
+    assert_eq!(event_types, vec![OrderEventType::Accepted]);
+
+    engine_l2.process_trigger_quote_tick(&trigger_quote("60005.00", "60010.00"));
+    let saved_messages = get_order_event_handler_messages(order_event_handler);
+    let event_types: Vec<OrderEventType> = saved_messages
+        .iter()
+        .map(OrderEventAny::event_type)
+        .collect();
+    assert_eq!(
+        event_types,
+        vec![
+            OrderEventType::Accepted,
+            OrderEventType::Triggered,
+            OrderEventType::Filled,
+        ]
+    );
+    let OrderEventAny::Triggered(order_triggered) = &saved_messages[1] else {
+        panic!("Expected `OrderTriggered` event");
+    };
+    assert_eq!(order_triggered.market_price, Some(Price::from("60010.00")));
+    let OrderEventAny::Filled(order_filled) = &saved_messages[2] else {
+        panic!("Expected `OrderFilled` event");
+    };
+    assert_eq!(order_filled.last_px, Price::from("1500.00"));
+}
+
+#[rstest]
+fn test_trigger_instrument_prices_compared_at_its_price_increment(
+    instrument_eth_usdt: InstrumentAny,
+    instrument_es: InstrumentAny,
+    mut msgbus: MessageBus,
+    order_event_handler: ShareableMessageHandler,
+    account_id: AccountId,
+) {
+    msgbus.register(
+        msgbus.switchboard.exec_engine_process,
+        order_event_handler.clone(),
+    );
+    // ES-like trigger instrument quoted to two decimals on a tick of 0.25
+    let InstrumentAny::FuturesContract(mut futures_es) = instrument_es else {
+        panic!("Expected a futures contract");
+    };
+    futures_es.price_increment = Price::from("0.25");
+    let trigger_instrument_id = futures_es.id;
+    let cache = Rc::new(RefCell::new(Cache::default()));
+    cache
+        .borrow_mut()
+        .add_instrument(InstrumentAny::FuturesContract(futures_es))
+        .unwrap();
+    let config = OrderMatchingEngineConfig::default().with_use_trigger_instruments(true);
+    let mut engine_l2 = get_order_matching_engine_l2(
+        instrument_eth_usdt.clone(),
+        Rc::new(RefCell::new(msgbus)),
+        Some(cache),
+        None,
+        Some(config),
+    );
+
+    // Off-tick trigger price, which is 4500.00 at the tick of 0.25
+    let mut stop_order = OrderTestBuilder::new(OrderType::StopLimit)
+        .instrument_id(instrument_eth_usdt.id())
+        .side(OrderSide::Buy)
+        .trigger_price(Price::from("4500.10"))
+        .price(Price::from("1490.00"))
+        .quantity(Quantity::from("1.000"))
+        .trigger_instrument_id(trigger_instrument_id)
+        .build();
+    engine_l2.process_order(&mut stop_order, account_id);
+
+    engine_l2.process_trigger_quote_tick(&QuoteTick::new(
+        trigger_instrument_id,
+        Price::from("4499.75"),
+        Price::from("4500.00"),
+        Quantity::from("1"),
+        Quantity::from("1"),
+        UnixNanos::default(),
+        UnixNanos::default(),
+    ));
+
+    let saved_messages = get_order_event_handler_messages(order_event_handler);
+    let event_types: Vec<OrderEventType> = saved_messages
+        .iter()
+        .map(OrderEventAny::event_type)
+        .collect();
+    assert_eq!(
+        event_types,
+        vec![OrderEventType::Accepted, OrderEventType::Triggered]
+    );
+}
+
+#[rstest]
+fn test_matching_engine_pool_routes_by_instrument_with_config_overrides(
+    instrument_eth_usdt: InstrumentAny,
+    instrument_es: InstrumentAny,
+    mut msgbus: MessageBus,
+    order_event_handler: ShareableMessageHandler,
+    account_id: AccountId,
+) {
+    msgbus.register(
+        msgbus.switchboard.exec_engine_process,
+        order_event_handler.clone(),
+    );
+    let msgbus = Rc::new(RefCell::new(msgbus));
+    let cache = Rc::new(RefCell::new(Cache::default()));
+    let eth_id = instrument_eth_usdt.id();
+    let es_id = instrument_es.id();
+
+    // Only the ES engine rejects takers
+    let mut pool = MatchingEnginePool::new(OrderMatchingEngineConfig::default());
+    pool.set_config_override(
+        es_id,
+        OrderMatchingEngineConfig::default().with_reject_takers(true),
+    );
+    for instrument in [instrument_eth_usdt, instrument_es] {
+        pool.add_engine(
+            instrument,
+            1,
+            FillModel::default(),
+            FeeModelAny::default(),
+            BookType::L1_MBP,
+            OmsType::Netting,
+            AccountType::Cash,
+            &ATOMIC_TIME,
+            msgbus.clone(),
+            cache.clone(),
+        );
+    }
+    assert!(!pool.config(&eth_id).reject_takers);
+    assert!(pool.config(&es_id).reject_takers);
+
+    for (instrument_id, bid, ask) in [
+        (eth_id, "1499.00", "1500.00"),
+        (es_id, "4500.00", "4500.25"),
+    ] {
+        pool.process_quote_tick(&QuoteTick::new(
+            instrument_id,
+            Price::from(bid),
+            Price::from(ask),
+            Quantity::from("10.000"),
+            Quantity::from("10.000"),
+            UnixNanos::from(1),
+            UnixNanos::from(1),
+        ))
+        .unwrap();
+    }
+
+    let mut eth_order = OrderTestBuilder::new(OrderType::Market)
+        .instrument_id(eth_id)
+        .client_order_id(ClientOrderId::from("O-19700101-000000-001-001-1"))
+        .side(OrderSide::Buy)
+        .quantity(Quantity::from("1.000"))
+        .build();
+    let mut es_order = OrderTestBuilder::new(OrderType::Market)
+        .instrument_id(es_id)
+        .client_order_id(ClientOrderId::from("O-19700101-000000-001-001-2"))
+        .side(OrderSide::Buy)
+        .quantity(Quantity::from("1"))
+        .build();
+    pool.process_order(&mut eth_order, account_id).unwrap();
+    pool.process_order(&mut es_order, account_id).unwrap();
+
+    let saved_messages = get_order_event_handler_messages(order_event_handler);
+    let events: Vec<(InstrumentId, OrderEventType)> = saved_messages
+        .iter()
+        .map(|event| (event.instrument_id(), event.event_type()))
+        .collect();
+    assert_eq!(
+        events,
+        vec![
+            (eth_id, OrderEventType::Filled),
+            (es_id, OrderEventType::Rejected),
+        ]
+    );
+
+    let eth_engine = pool.engine(&eth_id).unwrap();
+    assert_eq!(eth_engine.submitted_count(), 1);
+    assert_eq!(eth_engine.filled_count(), 1);
+    let es_engine = pool.engine(&es_id).unwrap();
+    assert_eq!(es_engine.submitted_count(), 1);
+    assert_eq!(es_engine.rejected_count(), 1);
+
+    assert_eq!(pool.submitted_count(), 2);
+    assert_eq!(pool.filled_count(), 1);
+    assert_eq!(pool.rejected_count(), 1);
+    assert_eq!(pool.fill_ratio(), 0.5);
+    assert_eq!(pool.rejection_ratio(), 0.5);
+
+    // Routing to an instrument without an engine is an error
+    let mut unknown_order = OrderTestBuilder::new(OrderType::Market)
+        .instrument_id(InstrumentId::from("AUD/USD.SIM"))
+        .client_order_id(ClientOrderId::from("O-19700101-000000-001-001-3"))
+        .side(OrderSide::Buy)
+        .quantity(Quantity::from("1"))
+        .build();
+    assert!(pool.process_order(&mut unknown_order, account_id).is_err());
 }
@@ -18,7 +18,7 @@ use std::fmt::Display;
 use derive_builder::Builder;
 use nautilus_core::{UUID4, UnixNanos};
 use nautilus_model::{
-    enums::OrderSide,
+    enums::{OrderSide, OrderType},
     identifiers::{ClientId, InstrumentId, StrategyId, TraderId},
 };
 use serde::{Deserialize, Serialize};
@@ -32,6 +32,7 @@ pub struct CancelAllOrders {
     pub strategy_id: StrategyId,
     pub instrument_id: InstrumentId,
     pub order_side: OrderSide,
+    pub order_type: Option<OrderType>,
     pub command_id: UUID4,
     pub ts_init: UnixNanos,
 }
@@ -45,6 +46,7 @@ impl CancelAllOrders {
         strategy_id: StrategyId,
         instrument_id: InstrumentId,
         order_side: OrderSide,
+        order_type: Option<OrderType>,
         command_id: UUID4,
         ts_init: UnixNanos,
     ) -> anyhow::Result<Self> {
@@ -54,6 +56,7 @@ impl CancelAllOrders {
             strategy_id,
             instrument_id,
             order_side,
+            order_type,
             command_id,
             ts_init,
         })
@@ -64,8 +67,8 @@ impl Display for CancelAllOrders {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         write!(
             f,
-            "CancelAllOrders(instrument_id={}, order_side={})",
-            self.instrument_id, self.order_side,
+            "CancelAllOrders(instrument_id={}, order_side={}, order_type={:?})",
+            self.instrument_id, self.order_side, self.order_type,
         )
     }
 }
@@ -13,13 +13,15 @@
 //  limitations under the License.
 // -------------------------------------------------------------------------------------------------
 
+use std::collections::HashMap;
+
 use nautilus_model::{
-    enums::LiquiditySide,
+    enums::{AssetClass, InstrumentClass, LiquiditySide, OrderSide},
     instruments::InstrumentAny,
     orders::OrderAny,
     types::{Money, Price, Quantity},
 };
-use rust_decimal::prelude::ToPrimitive;
+use rust_decimal::{RoundingStrategy, prelude::ToPrimitive};
 
 pub trait FeeModel {
     fn get_commission(
@@ -35,6 +37,8 @@ pub trait FeeModel {
 pub enum FeeModelAny {
     Fixed(FixedFeeModel),
     MakerTaker(MakerTakerFeeModel),
+    PerSide(PerSideFeeModel),
+    Router(FeeModelRouter),
 }
 
 impl FeeModel for FeeModelAny {
@@ -50,6 +54,8 @@ impl FeeModel for FeeModelAny {
             Self::MakerTaker(model) => {
                 model.get_commission(order, fill_quantity, fill_px, instrument)
             }
+            Self::PerSide(model) => model.get_commission(order, fill_quantity, fill_px, instrument),
+            Self::Router(model) => model.get_commission(order, fill_quantity, fill_px, instrument),
         }
     }
 }
@@ -110,24 +116,134 @@ impl FeeModel for MakerTakerFeeModel {
         instrument: &InstrumentAny,
     ) -> anyhow::Result<Money> {
         let notional = instrument.calculate_notional_value(fill_quantity, fill_px, Some(false));
-        let commission = match order.liquidity_side() {
-            Some(LiquiditySide::Maker) => notional * instrument.maker_fee().to_f64().unwrap(),
-            Some(LiquiditySide::Taker) => notional * instrument.taker_fee().to_f64().unwrap(),
+        let fee_rate = match order.liquidity_side() {
+            Some(LiquiditySide::Maker) => instrument.maker_fee(),
+            Some(LiquiditySide::Taker) => instrument.taker_fee(),
             Some(LiquiditySide::NoLiquiditySide) | None => anyhow::bail!("Liquidity side not set."),
         };
-        if instrument.is_inverse() {
-            Ok(Money::new(commission, instrument.base_currency().unwrap()))
+        let currency = if instrument.is_inverse() {
+            instrument.base_currency().unwrap()
         } else {
-            Ok(Money::new(commission, instrument.quote_currency()))
+            instrument.quote_currency()
+        };
+        // Round to the commission currency precision to avoid carrying excess decimals
+        let commission = (notional.as_decimal() * fee_rate).round_dp_with_strategy(
+            u32::from(currency.precision),
+            RoundingStrategy::MidpointAwayFromZero,
+        );
+        Ok(Money::new(commission.to_f64().unwrap(), currency))
+    }
+}
+
+/// A fee model which applies distinct fee models to buy and sell orders.
+///
+/// Models venues with asymmetric fees, such as a stamp duty charged on buys only.
+#[derive(Debug, Clone)]
+pub struct PerSideFeeModel {
+    buy: Box<FeeModelAny>,
+    sell: Box<FeeModelAny>,
+}
+
+impl PerSideFeeModel {
+    /// Creates a new [`PerSideFeeModel`] instance.
+    #[must_use]
+    pub fn new(buy: FeeModelAny, sell: FeeModelAny) -> Self {
+        Self {
+            buy: Box::new(buy),
+            sell: Box::new(sell),
+        }
+    }
+}
+
+impl FeeModel for PerSideFeeModel {
+    fn get_commission(
+        &self,
+        order: &OrderAny,
+        fill_quantity: Quantity,
+        fill_px: Price,
+        instrument: &InstrumentAny,
+    ) -> anyhow::Result<Money> {
+        let model = match order.order_side() {
+            OrderSide::Buy => &self.buy,
+            OrderSide::Sell => &self.sell,
+            OrderSide::NoOrderSide => anyhow::bail!("Order side not set."),
+        };
+        model.get_commission(order, fill_quantity, fill_px, instrument)
+    }
+}
+
+/// Selects the fee model for a fill by the class of its instrument, e.g. to charge crypto,
+/// equity and futures fills under different fee schedules.
+///
+/// A model routed by instrument class takes precedence over one routed by asset class, with
+/// the default model used for instruments matching neither.
+#[derive(Debug, Clone)]
+pub struct FeeModelRouter {
+    default: Box<FeeModelAny>,
+    by_instrument_class: HashMap<InstrumentClass, FeeModelAny>,
+    by_asset_class: HashMap<AssetClass, FeeModelAny>,
+}
+
+impl FeeModelRouter {
+    /// Creates a new [`FeeModelRouter`] instance.
+    #[must_use]
+    pub fn new(default: FeeModelAny) -> Self {
+        Self {
+            default: Box::new(default),
+            by_instrument_class: HashMap::new(),
+            by_asset_class: HashMap::new(),
         }
     }
+
+    /// Routes fills of instruments of the given `instrument_class` to `model`.
+    #[must_use]
+    pub fn with_instrument_class(
+        mut self,
+        instrument_class: InstrumentClass,
+        model: FeeModelAny,
+    ) -> Self {
+        self.by_instrument_class.insert(instrument_class, model);
+        self
+    }
+
+    /// Routes fills of instruments of the given `asset_class` to `model`.
+    #[must_use]
+    pub fn with_asset_class(mut self, asset_class: AssetClass, model: FeeModelAny) -> Self {
+        self.by_asset_class.insert(asset_class, model);
+        self
+    }
+
+    /// Returns the fee model for fills of the `instrument`.
+    #[must_use]
+    pub fn model_for(&self, instrument: &InstrumentAny) -> &FeeModelAny {
+        self.by_instrument_class
+            .get(&instrument.instrument_class())
+            .or_else(|| self.by_asset_class.get(&instrument.asset_class()))
+            .unwrap_or(&self.default)
+    }
+}
+
+impl FeeModel for FeeModelRouter {
+    fn get_commission(
+        &self,
+        order: &OrderAny,
+        fill_quantity: Quantity,
+        fill_px: Price,
+        instrument: &InstrumentAny,
+    ) -> anyhow::Result<Money> {
+        self.model_for(instrument)
+            .get_commission(order, fill_quantity, fill_px, instrument)
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use nautilus_model::{
-        enums::{LiquiditySide, OrderSide, OrderType},
-        instruments::{InstrumentAny, stubs::audusd_sim},
+        enums::{AssetClass, InstrumentClass, LiquiditySide, OrderSide, OrderType},
+        instruments::{
+            InstrumentAny,
+            stubs::{audusd_sim, crypto_perpetual_ethusdt, equity_aapl, futures_contract_es},
+        },
         orders::{
             builder::OrderTestBuilder,
             stubs::{TestOrderEventStubs, TestOrderStubs},
@@ -137,7 +253,9 @@ mod tests {
     use rstest::rstest;
     use rust_decimal::prelude::ToPrimitive;
 
-    use super::{FeeModel, FixedFeeModel, MakerTakerFeeModel};
+    use super::{
+        FeeModel, FeeModelAny, FeeModelRouter, FixedFeeModel, MakerTakerFeeModel, PerSideFeeModel,
+    };
 
     #[rstest]
     fn test_fixed_model_single_fill() {
@@ -200,6 +318,7 @@ mod tests {
             None,
             None,
             None,
+            None,
         );
         accepted_order.apply(fill).unwrap();
         let commission_next_fill = fee_model
@@ -268,4 +387,101 @@ mod tests {
             .unwrap();
         assert_eq!(commission.as_f64(), expected_commission_amount);
     }
+
+    #[rstest]
+    fn test_maker_taker_fee_model_commission_rounded_to_currency_precision() {
+        let fee_model = MakerTakerFeeModel;
+        let eth_usdt = InstrumentAny::CryptoPerpetual(crypto_perpetual_ethusdt());
+        let market_order = OrderTestBuilder::new(OrderType::Market)
+            .instrument_id(eth_usdt.id())
+            .side(OrderSide::Buy)
+            .quantity(Quantity::from("0.001"))
+            .build();
+        let order_filled =
+            TestOrderStubs::make_filled_order(&market_order, &eth_usdt, LiquiditySide::Taker);
+        // Notional of 1.23457 USDT at a taker fee of 4 bps is 0.000493828 USDT
+        let commission = fee_model
+            .get_commission(
+                &order_filled,
+                Quantity::from("0.001"),
+                Price::from("1234.57"),
+                &eth_usdt,
+            )
+            .unwrap();
+        assert_eq!(commission, Money::from("0.00049383 USDT"));
+        assert_eq!(commission.currency.precision, 8);
+    }
+
+    #[rstest]
+    fn test_per_side_fee_model_charges_asymmetric_commissions() {
+        // Stamp duty style model, with buys charged more than sells
+        let fee_model = PerSideFeeModel::new(
+            FeeModelAny::Fixed(FixedFeeModel::new(Money::from("5 USD"), None).unwrap()),
+            FeeModelAny::Fixed(FixedFeeModel::new(Money::from("1 USD"), None).unwrap()),
+        );
+        let aud_usd = InstrumentAny::CurrencyPair(audusd_sim());
+        let commission_for_side = |side: OrderSide| {
+            let market_order = OrderTestBuilder::new(OrderType::Market)
+                .instrument_id(aud_usd.id())
+                .side(side)
+                .quantity(Quantity::from(100_000))
+                .build();
+            let accepted_order = TestOrderStubs::make_accepted_order(&market_order);
+            fee_model
+                .get_commission(
+                    &accepted_order,
+                    Quantity::from(100_000),
+                    Price::from("1.0"),
+                    &aud_usd,
+                )
+                .unwrap()
+        };
+
+        assert_eq!(commission_for_side(OrderSide::Buy), Money::from("5 USD"));
+        assert_eq!(commission_for_side(OrderSide::Sell), Money::from("1 USD"));
+    }
+
+    #[rstest]
+    fn test_fee_model_router_selects_model_by_instrument_class() {
+        let fixed = |commission: &str| {
+            FeeModelAny::Fixed(FixedFeeModel::new(Money::from(commission), None).unwrap())
+        };
+        let fee_model = FeeModelRouter::new(fixed("9 USD"))
+            .with_asset_class(AssetClass::Cryptocurrency, fixed("2 USDT"))
+            .with_asset_class(AssetClass::Equity, fixed("1 USD"))
+            .with_instrument_class(InstrumentClass::Future, fixed("3 USD"));
+        let commission_for = |instrument: &InstrumentAny, quantity: &str, price: &str| {
+            let market_order = OrderTestBuilder::new(OrderType::Market)
+                .instrument_id(instrument.id())
+                .side(OrderSide::Buy)
+                .quantity(Quantity::from(quantity))
+                .build();
+            let accepted_order = TestOrderStubs::make_accepted_order(&market_order);
+            fee_model
+                .get_commission(
+                    &accepted_order,
+                    Quantity::from(quantity),
+                    Price::from(price),
+                    instrument,
+                )
+                .unwrap()
+        };
+
+        let eth_usdt = InstrumentAny::CryptoPerpetual(crypto_perpetual_ethusdt());
+        let aapl = InstrumentAny::Equity(equity_aapl());
+        let es = InstrumentAny::FuturesContract(futures_contract_es(None, None));
+        let aud_usd = InstrumentAny::CurrencyPair(audusd_sim());
+
+        assert_eq!(
+            commission_for(&eth_usdt, "1.000", "1500.00"),
+            Money::from("2 USDT")
+        );
+        assert_eq!(commission_for(&aapl, "100", "150.00"), Money::from("1 USD"));
+        assert_eq!(commission_for(&es, "1", "4500.00"), Money::from("3 USD"));
+        // Neither class is routed, so the default model applies
+        assert_eq!(
+            commission_for(&aud_usd, "100000", "1.00000"),
+            Money::from("9 USD")
+        );
+    }
 }
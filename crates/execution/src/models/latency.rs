@@ -15,10 +15,113 @@
 
 use std::fmt::Display;
 
-pub struct LatencyModel;
+use rand::{Rng, SeedableRng, rngs::StdRng};
+
+#[derive(Debug, Clone)]
+pub struct LatencyModel {
+    /// The latency (nanoseconds) applied to fills of regular orders.
+    fill_latency_nanos: u64,
+    /// The latency (nanoseconds) applied to fills of triggered stop orders.
+    stop_fill_latency_nanos: u64,
+    /// The latency (nanoseconds) before a submitted order arrives at the venue.
+    insert_latency_nanos: u64,
+    /// The maximum random jitter (nanoseconds) added to each latency.
+    jitter_nanos: u64,
+    /// Random number generator
+    rng: StdRng,
+}
+
+impl LatencyModel {
+    /// Creates a new [`LatencyModel`] instance.
+    #[must_use]
+    pub fn new(
+        fill_latency_nanos: u64,
+        stop_fill_latency_nanos: u64,
+        jitter_nanos: u64,
+        random_seed: Option<u64>,
+    ) -> Self {
+        let rng = match random_seed {
+            Some(seed) => StdRng::seed_from_u64(seed),
+            None => StdRng::from_os_rng(),
+        };
+        Self {
+            fill_latency_nanos,
+            stop_fill_latency_nanos,
+            insert_latency_nanos: 0,
+            jitter_nanos,
+            rng,
+        }
+    }
+
+    /// Sets the latency (nanoseconds) before a submitted order arrives at the venue.
+    #[must_use]
+    pub const fn with_insert_latency(mut self, insert_latency_nanos: u64) -> Self {
+        self.insert_latency_nanos = insert_latency_nanos;
+        self
+    }
+
+    /// Returns the latency (nanoseconds) for a fill of a regular order.
+    pub fn fill_latency(&mut self) -> u64 {
+        self.with_jitter(self.fill_latency_nanos)
+    }
+
+    /// Returns the latency (nanoseconds) for a fill of a triggered stop order.
+    pub fn stop_fill_latency(&mut self) -> u64 {
+        self.with_jitter(self.stop_fill_latency_nanos)
+    }
+
+    /// Returns the latency (nanoseconds) before a submitted order arrives at the venue.
+    pub fn insert_latency(&mut self) -> u64 {
+        match self.insert_latency_nanos {
+            0 => 0,
+            insert_latency_nanos => self.with_jitter(insert_latency_nanos),
+        }
+    }
+
+    fn with_jitter(&mut self, latency_nanos: u64) -> u64 {
+        match self.jitter_nanos {
+            0 => latency_nanos,
+            jitter_nanos => latency_nanos + self.rng.random_range(0..=jitter_nanos),
+        }
+    }
+}
 
 impl Display for LatencyModel {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        write!(f, "LatencyModel()")
+        write!(
+            f,
+            "LatencyModel(fill_latency_nanos: {}, stop_fill_latency_nanos: {}, insert_latency_nanos: {}, jitter_nanos: {})",
+            self.fill_latency_nanos,
+            self.stop_fill_latency_nanos,
+            self.insert_latency_nanos,
+            self.jitter_nanos
+        )
+    }
+}
+
+impl Default for LatencyModel {
+    /// Creates a new default [`LatencyModel`] instance with no latency.
+    fn default() -> Self {
+        Self::new(0, 0, 0, None)
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use rstest::rstest;
+
+    use super::*;
+
+    #[rstest]
+    fn test_latency_model_jitter_within_bounds() {
+        let mut latency_model = LatencyModel::new(1_000, 5_000, 100, Some(42));
+
+        for _ in 0..100 {
+            assert!((1_000..=1_100).contains(&latency_model.fill_latency()));
+            assert!((5_000..=5_100).contains(&latency_model.stop_fill_latency()));
+        }
     }
 }
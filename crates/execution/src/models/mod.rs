@@ -16,3 +16,5 @@
 pub mod fee;
 pub mod fill;
 pub mod latency;
+pub mod queue;
+pub mod tick;
@@ -0,0 +1,94 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2025 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+use std::fmt::Display;
+
+use nautilus_core::correctness::check_non_negative_f64;
+
+/// Models how the volume resting ahead of an order at the same price level
+/// depletes over time due to cancellations.
+pub trait QueueModel {
+    /// Returns the queue-ahead volume remaining after `elapsed_ns` nanoseconds.
+    fn decay(&self, queue_ahead: f64, elapsed_ns: u64) -> f64;
+}
+
+#[derive(Clone, Debug)]
+pub enum QueueModelAny {
+    Decay(DecayQueueModel),
+}
+
+impl QueueModel for QueueModelAny {
+    fn decay(&self, queue_ahead: f64, elapsed_ns: u64) -> f64 {
+        match self {
+            Self::Decay(model) => model.decay(queue_ahead, elapsed_ns),
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct DecayQueueModel {
+    /// The volume cancelled from the queue ahead per second.
+    decay_rate: f64,
+}
+
+impl DecayQueueModel {
+    /// Creates a new [`DecayQueueModel`] instance.
+    pub fn new(decay_rate: f64) -> anyhow::Result<Self> {
+        check_non_negative_f64(decay_rate, "decay_rate")?;
+        Ok(Self { decay_rate })
+    }
+}
+
+impl QueueModel for DecayQueueModel {
+    fn decay(&self, queue_ahead: f64, elapsed_ns: u64) -> f64 {
+        let elapsed_secs = elapsed_ns as f64 / 1_000_000_000.0;
+        (queue_ahead - self.decay_rate * elapsed_secs).max(0.0)
+    }
+}
+
+impl Display for DecayQueueModel {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "DecayQueueModel(decay_rate: {})", self.decay_rate)
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use rstest::rstest;
+
+    use super::*;
+
+    #[rstest]
+    fn test_decay_queue_model_negative_decay_rate_error() {
+        assert!(DecayQueueModel::new(-1.0).is_err());
+    }
+
+    #[rstest]
+    #[case(2.0, 0, 2.0)]
+    #[case(2.0, 500_000_000, 1.5)]
+    #[case(2.0, 2_000_000_000, 0.0)]
+    #[case(2.0, 5_000_000_000, 0.0)]
+    fn test_decay_queue_model_decay(
+        #[case] queue_ahead: f64,
+        #[case] elapsed_ns: u64,
+        #[case] expected: f64,
+    ) {
+        let queue_model = DecayQueueModel::new(1.0).unwrap();
+        assert_eq!(queue_model.decay(queue_ahead, elapsed_ns), expected);
+    }
+}
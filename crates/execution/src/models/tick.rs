@@ -0,0 +1,171 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2025 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+use std::fmt::Display;
+
+use nautilus_core::correctness::{check_predicate_true, check_slice_not_empty};
+use nautilus_model::types::Price;
+
+/// Models the minimum price increment of a venue, which may vary by price band.
+pub trait TickScheme {
+    /// Returns the tick size which applies at the given `price`.
+    fn tick_size(&self, price: Price) -> Price;
+
+    /// Returns whether `price` is a multiple of the tick size for its price band.
+    fn is_valid_price(&self, price: Price) -> bool {
+        price.raw % self.tick_size(price).raw == 0
+    }
+
+    /// Returns `price` rounded to the nearest tick for its price band.
+    fn round_price(&self, price: Price) -> Price {
+        let tick_raw = self.tick_size(price).raw;
+        let raw = (price.raw + tick_raw / 2).div_euclid(tick_raw) * tick_raw;
+        Price::from_raw(raw, price.precision)
+    }
+}
+
+#[derive(Clone, Debug)]
+pub enum TickSchemeAny {
+    Tiered(TieredTickScheme),
+}
+
+impl TickScheme for TickSchemeAny {
+    fn tick_size(&self, price: Price) -> Price {
+        match self {
+            Self::Tiered(scheme) => scheme.tick_size(price),
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct TieredTickScheme {
+    /// The price bands as (lower bound, tick size), sorted by ascending lower bound.
+    tiers: Vec<(Price, Price)>,
+}
+
+impl TieredTickScheme {
+    /// Creates a new [`TieredTickScheme`] instance.
+    ///
+    /// Each tier is a (lower bound, tick size) pair, the tick size of the first tier
+    /// also applies to prices below its lower bound.
+    pub fn new(tiers: Vec<(Price, Price)>) -> anyhow::Result<Self> {
+        check_slice_not_empty(&tiers, "tiers")?;
+        check_predicate_true(
+            tiers.iter().all(|(_, tick_size)| tick_size.is_positive()),
+            "tick sizes must be positive",
+        )?;
+        check_predicate_true(
+            tiers.windows(2).all(|pair| pair[0].0 < pair[1].0),
+            "tier lower bounds must be strictly ascending",
+        )?;
+        Ok(Self { tiers })
+    }
+}
+
+impl TickScheme for TieredTickScheme {
+    fn tick_size(&self, price: Price) -> Price {
+        self.tiers
+            .iter()
+            .rev()
+            .find(|(lower_bound, _)| price >= *lower_bound)
+            .unwrap_or(&self.tiers[0])
+            .1
+    }
+}
+
+impl Display for TieredTickScheme {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "TieredTickScheme(tiers: {:?})", self.tiers)
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use rstest::{fixture, rstest};
+
+    use super::*;
+
+    #[fixture]
+    fn tiered_scheme() -> TieredTickScheme {
+        TieredTickScheme::new(vec![
+            (Price::from("0.0000"), Price::from("0.0001")),
+            (Price::from("1.0000"), Price::from("0.0100")),
+            (Price::from("10.0000"), Price::from("0.1000")),
+        ])
+        .unwrap()
+    }
+
+    #[rstest]
+    fn test_tiered_tick_scheme_unsorted_tiers_error() {
+        let tiers = vec![
+            (Price::from("1.00"), Price::from("0.01")),
+            (Price::from("0.00"), Price::from("0.01")),
+        ];
+        assert!(TieredTickScheme::new(tiers).is_err());
+    }
+
+    #[rstest]
+    fn test_tiered_tick_scheme_empty_tiers_error() {
+        assert!(TieredTickScheme::new(vec![]).is_err());
+    }
+
+    #[rstest]
+    #[case("0.5000", "0.0001")]
+    #[case("1.0000", "0.0100")]
+    #[case("9.9900", "0.0100")]
+    #[case("25.0000", "0.1000")]
+    fn test_tiered_tick_scheme_tick_size(
+        tiered_scheme: TieredTickScheme,
+        #[case] price: &str,
+        #[case] expected: &str,
+    ) {
+        assert_eq!(
+            tiered_scheme.tick_size(Price::from(price)),
+            Price::from(expected)
+        );
+    }
+
+    #[rstest]
+    #[case("0.5001", true)]
+    #[case("5.0001", false)]
+    #[case("5.0100", true)]
+    #[case("12.0100", false)]
+    #[case("12.1000", true)]
+    fn test_tiered_tick_scheme_is_valid_price(
+        tiered_scheme: TieredTickScheme,
+        #[case] price: &str,
+        #[case] expected: bool,
+    ) {
+        assert_eq!(tiered_scheme.is_valid_price(Price::from(price)), expected);
+    }
+
+    #[rstest]
+    #[case("5.0149", "5.0100")]
+    #[case("5.0150", "5.0200")]
+    #[case("12.0400", "12.0000")]
+    fn test_tiered_tick_scheme_round_price(
+        tiered_scheme: TieredTickScheme,
+        #[case] price: &str,
+        #[case] expected: &str,
+    ) {
+        assert_eq!(
+            tiered_scheme.round_price(Price::from(price)),
+            Price::from(expected)
+        );
+    }
+}
@@ -746,6 +746,7 @@ impl OrderEmulator {
             false,
             order.venue_order_id(),
             order.account_id(),
+            None,
         );
 
         self.manager.send_exec_event(OrderEventAny::Canceled(event));
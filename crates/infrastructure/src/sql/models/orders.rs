@@ -384,6 +384,7 @@ impl<'r> FromRow<'r, PgRow> for OrderFilledModel {
             false,
             position_id,
             commission,
+            None,
         );
         Ok(OrderFilledModel(order_event))
     }
@@ -469,6 +469,7 @@ mod serial_tests {
             None,
             None,
             Some(AccountId::new("SIM-001")),
+            None,
         );
         market_order.apply(filled).unwrap();
 
@@ -765,6 +766,7 @@ mod serial_tests {
             None,
             None,
             Some(AccountId::new("SIM-001")),
+            None,
         );
         let position = Position::new(&instrument, filled.into());
         let snapshot = PositionSnapshot::from(&position, None);
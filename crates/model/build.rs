@@ -82,6 +82,20 @@ fn main() {
         // Run the replace operation in memory
         let mut data = data.replace("cdef enum", "cpdef enum");
 
+        // Cython has no C `bool` type, so declare `bool` fields and params as `bint`
+        data = data
+            .lines()
+            .map(|line| {
+                let trimmed = line.trim_start();
+                if trimmed.starts_with("bool ") {
+                    let indent = &line[..line.len() - trimmed.len()];
+                    format!("{indent}bint {}\n", &trimmed[5..])
+                } else {
+                    format!("{line}\n")
+                }
+            })
+            .collect();
+
         #[cfg(feature = "high-precision")]
         {
             let lines: Vec<&str> = data.lines().collect();
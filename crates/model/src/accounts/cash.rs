@@ -486,6 +486,7 @@ mod tests {
             None,
             None,
             Some(AccountId::from("SIM-001")),
+            None,
         );
         let position = Position::new(&audusd_sim, fill.clone().into());
         let pnls = cash_account_million_usd
@@ -516,6 +517,7 @@ mod tests {
             None,
             None,
             Some(AccountId::from("SIM-001")),
+            None,
         );
         let position = Position::new(&btcusdt, fill1.clone().into());
         let result1 = cash_account_multi
@@ -541,6 +543,7 @@ mod tests {
             None,
             None,
             Some(AccountId::from("SIM-001")),
+            None,
         );
         let result2 = cash_account_multi
             .calculate_pnls(
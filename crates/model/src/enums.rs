@@ -734,6 +734,48 @@ impl FromU16 for MarketStatusAction {
     }
 }
 
+/// The reason a request to modify an order was rejected.
+#[repr(C)]
+#[derive(
+    Copy,
+    Clone,
+    Debug,
+    Display,
+    Hash,
+    PartialEq,
+    Eq,
+    PartialOrd,
+    Ord,
+    AsRefStr,
+    FromRepr,
+    EnumIter,
+    EnumString,
+)]
+#[strum(ascii_case_insensitive)]
+#[strum(serialize_all = "SCREAMING_SNAKE_CASE")]
+#[cfg_attr(
+    feature = "python",
+    pyo3::pyclass(eq, eq_int, module = "nautilus_trader.core.nautilus_pyo3.model.enums")
+)]
+pub enum ModifyRejectReason {
+    /// The order to modify was not found.
+    OrderNotFound = 1,
+    /// The order to modify is already closed.
+    OrderClosed = 2,
+    /// The modified post-only order would have been a liquidity taker.
+    PostOnlyWouldBeTaker = 3,
+    /// The modified quantity is below the quantity already filled.
+    QuantityBelowFilled = 4,
+    /// The modified trigger price is already in the market.
+    TriggerPriceInMarket = 5,
+    /// The modify command instrument does not match the order instrument.
+    InstrumentMismatch = 6,
+    /// The order has exceeded the venue rate limit on modifications.
+    RateExceeded = 7,
+    /// The modified limit price is incompatible with the trigger price.
+    InvalidPriceTrigger = 8,
+}
+
 /// The order management system (OMS) type for a trading venue or trading strategy.
 #[repr(C)]
 #[derive(
@@ -1145,6 +1187,38 @@ impl RecordFlag {
     }
 }
 
+/// The self-trade prevention (STP) mode applied when orders from the same strategy would match.
+#[repr(C)]
+#[derive(
+    Copy,
+    Clone,
+    Debug,
+    Display,
+    Hash,
+    PartialEq,
+    Eq,
+    PartialOrd,
+    Ord,
+    AsRefStr,
+    FromRepr,
+    EnumIter,
+    EnumString,
+)]
+#[strum(ascii_case_insensitive)]
+#[strum(serialize_all = "SCREAMING_SNAKE_CASE")]
+#[cfg_attr(
+    feature = "python",
+    pyo3::pyclass(eq, eq_int, module = "nautilus_trader.core.nautilus_pyo3.model.enums")
+)]
+pub enum SelfTradePrevention {
+    /// The incoming (taker) order is canceled, the resting orders remain.
+    CancelNewest = 1,
+    /// The resting (maker) orders are canceled, the incoming order proceeds.
+    CancelOldest = 2,
+    /// Both the incoming order and the resting orders are canceled.
+    CancelBoth = 3,
+}
+
 /// The 'Time in Force' instruction for an order.
 #[repr(C)]
 #[derive(
@@ -1318,6 +1392,7 @@ enum_strum_serde!(InstrumentCloseType);
 enum_strum_serde!(LiquiditySide);
 enum_strum_serde!(MarketStatus);
 enum_strum_serde!(MarketStatusAction);
+enum_strum_serde!(ModifyRejectReason);
 enum_strum_serde!(OmsType);
 enum_strum_serde!(OptionKind);
 enum_strum_serde!(OrderSide);
@@ -1326,6 +1401,7 @@ enum_strum_serde!(OrderType);
 enum_strum_serde!(PositionSide);
 enum_strum_serde!(PriceType);
 enum_strum_serde!(RecordFlag);
+enum_strum_serde!(SelfTradePrevention);
 enum_strum_serde!(TimeInForce);
 enum_strum_serde!(TradingState);
 enum_strum_serde!(TrailingOffsetType);
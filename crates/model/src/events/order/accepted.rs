@@ -16,7 +16,7 @@
 use std::fmt::{Debug, Display};
 
 use derive_builder::Builder;
-use nautilus_core::{UUID4, UnixNanos, serialization::from_bool_as_u8};
+use nautilus_core::{UUID4, UnixNanos, serialization::from_u8_as_bool};
 use rust_decimal::Decimal;
 use serde::{Deserialize, Serialize};
 use ustr::Ustr;
@@ -65,8 +65,8 @@ pub struct OrderAccepted {
     /// UNIX timestamp (nanoseconds) when the event was initialized.
     pub ts_init: UnixNanos,
     /// If the event was generated during reconciliation.
-    #[serde(deserialize_with = "from_bool_as_u8")]
-    pub reconciliation: u8, // TODO: Change to bool once Cython removed
+    #[serde(deserialize_with = "from_u8_as_bool")]
+    pub reconciliation: bool,
 }
 
 impl OrderAccepted {
@@ -94,7 +94,7 @@ impl OrderAccepted {
             event_id,
             ts_event,
             ts_init,
-            reconciliation: u8::from(reconciliation),
+            reconciliation,
         }
     }
 }
@@ -203,7 +203,7 @@ impl OrderEvent for OrderAccepted {
     }
 
     fn reconciliation(&self) -> bool {
-        false
+        self.reconciliation
     }
 
     fn price(&self) -> Option<Price> {
@@ -321,4 +321,30 @@ mod tests {
             "OrderAccepted(instrument_id=BTCUSDT.COINBASE, client_order_id=O-19700101-000000-001-001-1, venue_order_id=001, account_id=SIM-001, ts_event=0)"
         );
     }
+
+    #[rstest]
+    fn test_order_accepted_serializes_reconciliation_as_bool(order_accepted: OrderAccepted) {
+        let json = serde_json::to_string(&order_accepted).unwrap();
+        assert!(json.contains("\"reconciliation\":false"));
+
+        let deserialized: OrderAccepted = serde_json::from_str(&json).unwrap();
+        assert_eq!(deserialized, order_accepted);
+    }
+
+    #[rstest]
+    #[case(0, false)]
+    #[case(1, true)]
+    fn test_order_accepted_deserializes_legacy_u8_reconciliation(
+        order_accepted: OrderAccepted,
+        #[case] legacy: u8,
+        #[case] expected: bool,
+    ) {
+        let json = serde_json::to_string(&order_accepted).unwrap().replace(
+            "\"reconciliation\":false",
+            &format!("\"reconciliation\":{legacy}"),
+        );
+
+        let deserialized: OrderAccepted = serde_json::from_str(&json).unwrap();
+        assert_eq!(deserialized.reconciliation(), expected);
+    }
 }
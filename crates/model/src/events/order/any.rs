@@ -270,7 +270,7 @@ impl OrderEventAny {
             Self::Submitted(_) => None,
             Self::Accepted(_) => None,
             Self::Rejected(event) => Some(event.reason),
-            Self::Canceled(_) => None,
+            Self::Canceled(event) => event.reason,
             Self::Expired(_) => None,
             Self::Triggered(_) => None,
             Self::PendingUpdate(_) => None,
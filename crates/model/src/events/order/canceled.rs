@@ -16,7 +16,7 @@
 use std::fmt::{Debug, Display};
 
 use derive_builder::Builder;
-use nautilus_core::{UUID4, UnixNanos, serialization::from_bool_as_u8};
+use nautilus_core::{UUID4, UnixNanos, serialization::from_u8_as_bool};
 use rust_decimal::Decimal;
 use serde::{Deserialize, Serialize};
 use ustr::Ustr;
@@ -59,12 +59,15 @@ pub struct OrderCanceled {
     /// UNIX timestamp (nanoseconds) when the event was initialized.
     pub ts_init: UnixNanos,
     /// If the event was generated during reconciliation.
-    #[serde(deserialize_with = "from_bool_as_u8")]
-    pub reconciliation: u8, // TODO: Change to bool once Cython removed
+    #[serde(deserialize_with = "from_u8_as_bool")]
+    pub reconciliation: bool,
     /// The venue order ID associated with the event.
     pub venue_order_id: Option<VenueOrderId>,
     /// The account ID associated with the event.
     pub account_id: Option<AccountId>,
+    /// The reason for the cancellation (e.g. the linked order which caused it).
+    #[serde(default)]
+    pub reason: Option<Ustr>,
 }
 
 impl OrderCanceled {
@@ -81,6 +84,7 @@ impl OrderCanceled {
         reconciliation: bool,
         venue_order_id: Option<VenueOrderId>,
         account_id: Option<AccountId>,
+        reason: Option<Ustr>,
     ) -> Self {
         Self {
             trader_id,
@@ -90,9 +94,10 @@ impl OrderCanceled {
             event_id,
             ts_event,
             ts_init,
-            reconciliation: u8::from(reconciliation),
+            reconciliation,
             venue_order_id,
             account_id,
+            reason,
         }
     }
 }
@@ -181,7 +186,7 @@ impl OrderEvent for OrderCanceled {
     }
 
     fn reason(&self) -> Option<Ustr> {
-        None
+        self.reason
     }
 
     fn quantity(&self) -> Option<Quantity> {
@@ -209,7 +214,7 @@ impl OrderEvent for OrderCanceled {
     }
 
     fn reconciliation(&self) -> bool {
-        false
+        self.reconciliation
     }
 
     fn price(&self) -> Option<Price> {
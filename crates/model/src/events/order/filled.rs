@@ -80,6 +80,8 @@ pub struct OrderFilled {
     pub position_id: Option<PositionId>,
     /// The commission generated from this execution.
     pub commission: Option<Money>,
+    /// The slippage of the fill price from the expected price (positive when adverse).
+    pub slippage: Option<Price>,
 }
 
 impl OrderFilled {
@@ -105,6 +107,7 @@ impl OrderFilled {
         reconciliation: bool,
         position_id: Option<PositionId>,
         commission: Option<Money>,
+        slippage: Option<Price>,
     ) -> Self {
         Self {
             trader_id,
@@ -126,6 +129,7 @@ impl OrderFilled {
             reconciliation,
             position_id,
             commission,
+            slippage,
         }
     }
 
@@ -143,6 +147,15 @@ impl OrderFilled {
     pub fn is_sell(&self) -> bool {
         self.order_side == OrderSide::Sell
     }
+
+    /// Returns the key identifying this fill for deduplication.
+    ///
+    /// Fills may be redelivered (e.g. during reconciliation); the same trade for the
+    /// same order is considered a duplicate regardless of the event ID.
+    #[must_use]
+    pub fn dedup_key(&self) -> (TradeId, ClientOrderId) {
+        (self.trade_id, self.client_order_id)
+    }
 }
 
 impl Default for OrderFilled {
@@ -163,6 +176,7 @@ impl Default for OrderFilled {
             last_px: Price::from("1.00000"),
             currency: Currency::USD(),
             commission: None,
+            slippage: None,
             liquidity_side: LiquiditySide::Taker,
             event_id: Default::default(),
             ts_event: Default::default(),
@@ -332,7 +346,7 @@ impl OrderEvent for OrderFilled {
     }
 
     fn reconciliation(&self) -> bool {
-        false
+        self.reconciliation
     }
 
     fn price(&self) -> Option<Price> {
@@ -437,6 +451,7 @@ impl OrderEvent for OrderFilled {
 ////////////////////////////////////////////////////////////////////////////////
 #[cfg(test)]
 mod tests {
+    use nautilus_core::UUID4;
     use rstest::rstest;
 
     use crate::events::{OrderFilled, order::stubs::*};
@@ -458,4 +473,17 @@ mod tests {
         assert!(order_filled.is_buy());
         assert!(!order_filled.is_sell());
     }
+
+    #[rstest]
+    fn test_order_filled_dedup_key_ignores_event_id(order_filled: OrderFilled) {
+        let mut redelivered = order_filled;
+        redelivered.event_id = UUID4::new();
+        redelivered.reconciliation = true;
+
+        assert_eq!(
+            order_filled.dedup_key(),
+            (order_filled.trade_id, order_filled.client_order_id)
+        );
+        assert_eq!(order_filled.dedup_key(), redelivered.dedup_key());
+    }
 }
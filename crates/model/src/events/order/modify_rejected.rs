@@ -16,15 +16,15 @@
 use std::fmt::{Debug, Display};
 
 use derive_builder::Builder;
-use nautilus_core::{UUID4, UnixNanos, serialization::from_bool_as_u8};
+use nautilus_core::{UUID4, UnixNanos, serialization::from_u8_as_bool};
 use rust_decimal::Decimal;
 use serde::{Deserialize, Serialize};
 use ustr::Ustr;
 
 use crate::{
     enums::{
-        ContingencyType, LiquiditySide, OrderSide, OrderType, TimeInForce, TrailingOffsetType,
-        TriggerType,
+        ContingencyType, LiquiditySide, ModifyRejectReason, OrderSide, OrderType, TimeInForce,
+        TrailingOffsetType, TriggerType,
     },
     events::OrderEvent,
     identifiers::{
@@ -62,12 +62,14 @@ pub struct OrderModifyRejected {
     /// UNIX timestamp (nanoseconds) when the event was initialized.
     pub ts_init: UnixNanos,
     /// If the event was generated during reconciliation.
-    #[serde(deserialize_with = "from_bool_as_u8")]
-    pub reconciliation: u8, // TODO: Change to bool once Cython removed
+    #[serde(deserialize_with = "from_u8_as_bool")]
+    pub reconciliation: bool,
     /// The venue order ID associated with the event.
     pub venue_order_id: Option<VenueOrderId>,
     /// The account ID associated with the event.
     pub account_id: Option<AccountId>,
+    /// The reason code for programmatic handling of the rejection.
+    pub reason_code: Option<ModifyRejectReason>,
 }
 
 impl OrderModifyRejected {
@@ -85,6 +87,7 @@ impl OrderModifyRejected {
         reconciliation: bool,
         venue_order_id: Option<VenueOrderId>,
         account_id: Option<AccountId>,
+        reason_code: Option<ModifyRejectReason>,
     ) -> Self {
         Self {
             trader_id,
@@ -95,9 +98,10 @@ impl OrderModifyRejected {
             event_id,
             ts_event,
             ts_init,
-            reconciliation: u8::from(reconciliation),
+            reconciliation,
             venue_order_id,
             account_id,
+            reason_code,
         }
     }
 }
@@ -216,7 +220,7 @@ impl OrderEvent for OrderModifyRejected {
     }
 
     fn reconciliation(&self) -> bool {
-        false
+        self.reconciliation
     }
 
     fn price(&self) -> Option<Price> {
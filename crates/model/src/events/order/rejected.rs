@@ -16,7 +16,7 @@
 use std::fmt::{Debug, Display};
 
 use derive_builder::Builder;
-use nautilus_core::{UUID4, UnixNanos, serialization::from_bool_as_u8};
+use nautilus_core::{UUID4, UnixNanos, serialization::from_u8_as_bool};
 use rust_decimal::Decimal;
 use serde::{Deserialize, Serialize};
 use ustr::Ustr;
@@ -63,8 +63,10 @@ pub struct OrderRejected {
     /// UNIX timestamp (nanoseconds) when the event was initialized.
     pub ts_init: UnixNanos,
     /// If the event was generated during reconciliation.
-    #[serde(deserialize_with = "from_bool_as_u8")]
-    pub reconciliation: u8, // TODO: Change to bool once Cython removed
+    #[serde(deserialize_with = "from_u8_as_bool")]
+    pub reconciliation: bool,
+    /// The venue order ID associated with the event (if assigned before the rejection).
+    pub venue_order_id: Option<VenueOrderId>,
 }
 
 impl OrderRejected {
@@ -81,6 +83,7 @@ impl OrderRejected {
         ts_event: UnixNanos,
         ts_init: UnixNanos,
         reconciliation: bool,
+        venue_order_id: Option<VenueOrderId>,
     ) -> Self {
         Self {
             trader_id,
@@ -92,7 +95,8 @@ impl OrderRejected {
             event_id,
             ts_event,
             ts_init,
-            reconciliation: u8::from(reconciliation),
+            reconciliation,
+            venue_order_id,
         }
     }
 }
@@ -201,7 +205,7 @@ impl OrderEvent for OrderRejected {
     }
 
     fn reconciliation(&self) -> bool {
-        false
+        self.reconciliation
     }
 
     fn price(&self) -> Option<Price> {
@@ -277,7 +281,7 @@ impl OrderEvent for OrderRejected {
     }
 
     fn venue_order_id(&self) -> Option<VenueOrderId> {
-        None
+        self.venue_order_id
     }
 
     fn account_id(&self) -> Option<AccountId> {
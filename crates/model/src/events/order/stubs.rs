@@ -20,7 +20,10 @@ use rstest::fixture;
 use ustr::Ustr;
 
 use crate::{
-    enums::{ContingencyType, LiquiditySide, OrderSide, OrderType, TimeInForce, TriggerType},
+    enums::{
+        ContingencyType, LiquiditySide, ModifyRejectReason, OrderSide, OrderType, TimeInForce,
+        TriggerType,
+    },
     events::{
         OrderAccepted, OrderCancelRejected, OrderDenied, OrderEmulated, OrderExpired, OrderFilled,
         OrderInitialized, OrderModifyRejected, OrderPendingCancel, OrderPendingUpdate,
@@ -65,6 +68,7 @@ pub fn order_filled(
         false,
         None,
         Some(Money::from("12.2 USDT")),
+        None,
     )
 }
 
@@ -108,6 +112,7 @@ pub fn order_rejected_insufficient_margin(
         UnixNanos::default(),
         UnixNanos::default(),
         false,
+        None,
     )
 }
 
@@ -200,6 +205,8 @@ pub fn order_triggered(
         false,
         Some(venue_order_id),
         Some(account_id),
+        None,
+        None,
     )
 }
 
@@ -339,6 +346,7 @@ pub fn order_modify_rejected(
         false,
         Some(venue_order_id),
         Some(account_id),
+        Some(ModifyRejectReason::OrderNotFound),
     )
 }
 
@@ -16,7 +16,7 @@
 use std::fmt::{Debug, Display};
 
 use derive_builder::Builder;
-use nautilus_core::{UUID4, UnixNanos, serialization::from_bool_as_u8};
+use nautilus_core::{UUID4, UnixNanos, serialization::from_u8_as_bool};
 use rust_decimal::Decimal;
 use serde::{Deserialize, Serialize};
 use ustr::Ustr;
@@ -61,12 +61,18 @@ pub struct OrderTriggered {
     /// UNIX timestamp (nanoseconds) when the event was initialized.
     pub ts_init: UnixNanos,
     /// If the event was generated during reconciliation.
-    #[serde(deserialize_with = "from_bool_as_u8")]
-    pub reconciliation: u8, // TODO: Change to bool once Cython removed
+    #[serde(deserialize_with = "from_u8_as_bool")]
+    pub reconciliation: bool,
     /// The venue order ID associated with the event.
     pub venue_order_id: Option<VenueOrderId>,
     /// The account ID associated with the event.
     pub account_id: Option<AccountId>,
+    /// The trigger price of the order which triggered.
+    #[serde(default)]
+    pub trigger_price: Option<Price>,
+    /// The market price which caused the order to trigger.
+    #[serde(default)]
+    pub market_price: Option<Price>,
 }
 
 impl OrderTriggered {
@@ -83,6 +89,8 @@ impl OrderTriggered {
         reconciliation: bool,
         venue_order_id: Option<VenueOrderId>,
         account_id: Option<AccountId>,
+        trigger_price: Option<Price>,
+        market_price: Option<Price>,
     ) -> Self {
         Self {
             trader_id,
@@ -92,9 +100,11 @@ impl OrderTriggered {
             event_id,
             ts_event,
             ts_init,
-            reconciliation: u8::from(reconciliation),
+            reconciliation,
             venue_order_id,
             account_id,
+            trigger_price,
+            market_price,
         }
     }
 }
@@ -211,7 +221,7 @@ impl OrderEvent for OrderTriggered {
     }
 
     fn reconciliation(&self) -> bool {
-        false
+        self.reconciliation
     }
 
     fn price(&self) -> Option<Price> {
@@ -227,7 +237,7 @@ impl OrderEvent for OrderTriggered {
     }
 
     fn trigger_price(&self) -> Option<Price> {
-        None
+        self.trigger_price
     }
 
     fn trigger_type(&self) -> Option<TriggerType> {
@@ -130,7 +130,7 @@ pub extern "C" fn order_accepted_new(
     event_id: UUID4,
     ts_event: UnixNanos,
     ts_init: UnixNanos,
-    reconciliation: u8,
+    reconciliation: bool,
 ) -> OrderAccepted {
     OrderAccepted {
         trader_id,
@@ -149,6 +149,8 @@ pub extern "C" fn order_accepted_new(
 /// # Safety
 ///
 /// - Assumes `reason_ptr` is a valid C string pointer.
+// The `venue_order_id` is exposed to C as a `VenueOrderId_t` with a null pointer for `None`
+#[allow(improper_ctypes_definitions)]
 #[unsafe(no_mangle)]
 pub unsafe extern "C" fn order_rejected_new(
     trader_id: TraderId,
@@ -160,7 +162,7 @@ pub unsafe extern "C" fn order_rejected_new(
     event_id: UUID4,
     ts_event: UnixNanos,
     ts_init: UnixNanos,
-    reconciliation: u8,
+    reconciliation: bool,
 ) -> OrderRejected {
     OrderRejected {
         trader_id,
@@ -173,5 +175,6 @@ pub unsafe extern "C" fn order_rejected_new(
         ts_event,
         ts_init,
         reconciliation,
+        venue_order_id: None,
     }
 }
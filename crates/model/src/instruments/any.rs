@@ -25,7 +25,7 @@ use super::{
     option_contract::OptionContract, option_spread::OptionSpread,
 };
 use crate::{
-    enums::InstrumentClass,
+    enums::{AssetClass, InstrumentClass},
     identifiers::{InstrumentId, Symbol, Venue},
     types::{Currency, Money, Price, Quantity},
 };
@@ -78,6 +78,22 @@ impl InstrumentAny {
         }
     }
 
+    #[must_use]
+    pub fn asset_class(&self) -> AssetClass {
+        match self {
+            Self::Betting(inst) => inst.asset_class(),
+            Self::BinaryOption(inst) => inst.asset_class(),
+            Self::CryptoFuture(inst) => inst.asset_class(),
+            Self::CryptoPerpetual(inst) => inst.asset_class(),
+            Self::CurrencyPair(inst) => inst.asset_class(),
+            Self::Equity(inst) => inst.asset_class(),
+            Self::FuturesContract(inst) => inst.asset_class(),
+            Self::FuturesSpread(inst) => inst.asset_class(),
+            Self::OptionContract(inst) => inst.asset_class(),
+            Self::OptionSpread(inst) => inst.asset_class(),
+        }
+    }
+
     #[must_use]
     pub fn id(&self) -> InstrumentId {
         match self {
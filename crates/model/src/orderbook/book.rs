@@ -367,6 +367,17 @@ impl OrderBook {
         }
     }
 
+    /// Simulates fills for an order, writing (price, quantity) tuples into `fills`.
+    ///
+    /// Any existing contents of `fills` are cleared first, allowing the caller to reuse
+    /// the buffer across calls without allocating.
+    pub fn simulate_fills_into(&self, order: &BookOrder, fills: &mut Vec<(Price, Quantity)>) {
+        match order.side.as_specified() {
+            OrderSideSpecified::Buy => self.asks.simulate_fills_into(order, fills),
+            OrderSideSpecified::Sell => self.bids.simulate_fills_into(order, fills),
+        }
+    }
+
     /// Return a formatted string representation of the order book.
     #[must_use]
     pub fn pprint(&self, num_levels: usize) -> String {
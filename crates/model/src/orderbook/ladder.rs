@@ -205,8 +205,18 @@ impl BookLadder {
     /// Returns a list of (price, size) tuples representing the simulated fills.
     #[must_use]
     pub fn simulate_fills(&self, order: &BookOrder) -> Vec<(Price, Quantity)> {
-        let is_reversed = self.side == OrderSideSpecified::Buy;
         let mut fills = Vec::new();
+        self.simulate_fills_into(order, &mut fills);
+        fills
+    }
+
+    /// Simulates fills for an order against this ladder's liquidity, writing the
+    /// (price, size) tuples into `fills` so the caller can reuse its allocation.
+    ///
+    /// Any existing contents of `fills` are cleared first.
+    pub fn simulate_fills_into(&self, order: &BookOrder, fills: &mut Vec<(Price, Quantity)>) {
+        fills.clear();
+        let is_reversed = self.side == OrderSideSpecified::Buy;
         let mut cumulative_denominator = Quantity::zero(order.size.precision);
         let target = order.size;
 
@@ -225,7 +235,7 @@ impl BookLadder {
                     if remainder.is_positive() {
                         fills.push((book_order.price, remainder));
                     }
-                    return fills;
+                    return;
                 }
 
                 // Add this fill and continue
@@ -233,8 +243,6 @@ impl BookLadder {
                 cumulative_denominator += current;
             }
         }
-
-        fills
     }
 }
 
@@ -813,6 +821,47 @@ mod tests {
         assert_eq!(size3, Quantity::from(200));
     }
 
+    #[rstest]
+    fn test_simulate_fills_into_matches_simulate_fills_across_sweeps() {
+        let mut ladder = BookLadder::new(OrderSideSpecified::Sell);
+        ladder.add_bulk(vec![
+            BookOrder::new(
+                OrderSide::Sell,
+                Price::from("100.00"),
+                Quantity::from(100),
+                1,
+            ),
+            BookOrder::new(
+                OrderSide::Sell,
+                Price::from("100.00"),
+                Quantity::from(50),
+                2,
+            ),
+            BookOrder::new(
+                OrderSide::Sell,
+                Price::from("101.00"),
+                Quantity::from(200),
+                3,
+            ),
+            BookOrder::new(
+                OrderSide::Sell,
+                Price::from("102.00"),
+                Quantity::from(400),
+                4,
+            ),
+        ]);
+
+        // Reuse a single buffer which still holds the previous sweep's fills
+        let mut fills = vec![(Price::from("1.00"), Quantity::from(1))];
+        for size in [1, 100, 150, 151, 350, 500, 750, 1_000] {
+            let order = BookOrder::new(OrderSide::Buy, Price::max(2), Quantity::from(size), 0);
+
+            ladder.simulate_fills_into(&order, &mut fills);
+
+            assert_eq!(fills, ladder.simulate_fills(&order));
+        }
+    }
+
     #[rstest]
     fn test_simulate_order_fills_sell_with_size_at_limit_of_precision() {
         let mut ladder = BookLadder::new(OrderSideSpecified::Buy);
@@ -314,6 +314,21 @@ impl OrderAny {
         }
     }
 
+    #[must_use]
+    pub fn trade_ids(&self) -> Vec<&TradeId> {
+        match self {
+            Self::Limit(order) => order.trade_ids(),
+            Self::LimitIfTouched(order) => order.trade_ids(),
+            Self::Market(order) => order.trade_ids(),
+            Self::MarketIfTouched(order) => order.trade_ids(),
+            Self::MarketToLimit(order) => order.trade_ids(),
+            Self::StopLimit(order) => order.trade_ids(),
+            Self::StopMarket(order) => order.trade_ids(),
+            Self::TrailingStopLimit(order) => order.trade_ids(),
+            Self::TrailingStopMarket(order) => order.trade_ids(),
+        }
+    }
+
     #[must_use]
     pub fn init_id(&self) -> UUID4 {
         match self {
@@ -1129,6 +1144,7 @@ impl From<OrderAny> for PassiveOrderAny {
             OrderAny::Limit(_) => PassiveOrderAny::Limit(order.into()),
             OrderAny::LimitIfTouched(_) => PassiveOrderAny::Stop(order.into()),
             OrderAny::MarketIfTouched(_) => PassiveOrderAny::Stop(order.into()),
+            OrderAny::MarketToLimit(_) => PassiveOrderAny::Limit(order.into()),
             OrderAny::StopLimit(_) => PassiveOrderAny::Stop(order.into()),
             OrderAny::StopMarket(_) => PassiveOrderAny::Stop(order.into()),
             OrderAny::TrailingStopLimit(_) => PassiveOrderAny::Stop(order.into()),
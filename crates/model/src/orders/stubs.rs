@@ -16,11 +16,14 @@
 use std::str::FromStr;
 
 use nautilus_core::{UUID4, UnixNanos};
+use ustr::Ustr;
 
 use super::any::OrderAny;
 use crate::{
     enums::LiquiditySide,
-    events::{OrderAccepted, OrderEventAny, OrderFilled, OrderSubmitted},
+    events::{
+        OrderAccepted, OrderCanceled, OrderEventAny, OrderFilled, OrderRejected, OrderSubmitted,
+    },
     identifiers::{AccountId, PositionId, TradeId, VenueOrderId},
     instruments::InstrumentAny,
     types::{Money, Price, Quantity},
@@ -64,6 +67,40 @@ impl TestOrderEventStubs {
         OrderEventAny::Accepted(event)
     }
 
+    pub fn order_rejected(order: &OrderAny, account_id: AccountId, reason: &str) -> OrderEventAny {
+        let event = OrderRejected::new(
+            order.trader_id(),
+            order.strategy_id(),
+            order.instrument_id(),
+            order.client_order_id(),
+            account_id,
+            Ustr::from(reason),
+            UUID4::new(),
+            UnixNanos::default(),
+            UnixNanos::default(),
+            false,
+            order.venue_order_id(),
+        );
+        OrderEventAny::Rejected(event)
+    }
+
+    pub fn order_canceled(order: &OrderAny, account_id: AccountId) -> OrderEventAny {
+        let event = OrderCanceled::new(
+            order.trader_id(),
+            order.strategy_id(),
+            order.instrument_id(),
+            order.client_order_id(),
+            UUID4::new(),
+            UnixNanos::default(),
+            UnixNanos::default(),
+            false,
+            order.venue_order_id(),
+            Some(account_id),
+            None,
+        );
+        OrderEventAny::Canceled(event)
+    }
+
     #[allow(clippy::too_many_arguments)]
     pub fn order_filled(
         order: &OrderAny,
@@ -76,6 +113,7 @@ impl TestOrderEventStubs {
         commission: Option<Money>,
         ts_filled_ns: Option<UnixNanos>,
         account_id: Option<AccountId>,
+        reconciliation: Option<bool>,
     ) -> OrderEventAny {
         let venue_order_id = order.venue_order_id().unwrap_or_default();
         let account_id = account_id
@@ -109,9 +147,10 @@ impl TestOrderEventStubs {
             event,
             ts_filled_ns.unwrap_or_default(),
             UnixNanos::default(),
-            false,
+            reconciliation.unwrap_or(false),
             Some(position_id),
             Some(commission),
+            None,
         );
         OrderEventAny::Filled(event)
     }
@@ -120,6 +159,26 @@ impl TestOrderEventStubs {
 pub struct TestOrderStubs;
 
 impl TestOrderStubs {
+    /// Applies the given `events` to a clone of `order` in sequence.
+    ///
+    /// # Panics
+    ///
+    /// Panics if any event is an invalid state transition for the order.
+    pub fn apply_events(order: &OrderAny, events: Vec<OrderEventAny>) -> OrderAny {
+        let mut new_order = order.clone();
+        for event in events {
+            let event_type = event.event_type();
+            if let Err(e) = new_order.apply(event) {
+                panic!(
+                    "Invalid event {event_type:?} for order {} in status {}: {e}",
+                    new_order.client_order_id(),
+                    new_order.status(),
+                );
+            }
+        }
+        new_order
+    }
+
     pub fn make_accepted_order(order: &OrderAny) -> OrderAny {
         let mut new_order = order.clone();
         let submitted_event =
@@ -134,6 +193,25 @@ impl TestOrderStubs {
         new_order
     }
 
+    pub fn make_rejected_order(order: &OrderAny, reason: &str) -> OrderAny {
+        let mut new_order = order.clone();
+        let submitted_event =
+            TestOrderEventStubs::order_submitted(&new_order, AccountId::from("SIM-001"));
+        let rejected_event =
+            TestOrderEventStubs::order_rejected(&new_order, AccountId::from("SIM-001"), reason);
+        new_order.apply(submitted_event).unwrap();
+        new_order.apply(rejected_event).unwrap();
+        new_order
+    }
+
+    pub fn make_canceled_order(order: &OrderAny) -> OrderAny {
+        let mut accepted_order = TestOrderStubs::make_accepted_order(order);
+        let canceled_event =
+            TestOrderEventStubs::order_canceled(&accepted_order, AccountId::from("SIM-001"));
+        accepted_order.apply(canceled_event).unwrap();
+        accepted_order
+    }
+
     pub fn make_filled_order(
         order: &OrderAny,
         instrument: &InstrumentAny,
@@ -151,8 +229,248 @@ impl TestOrderStubs {
             None,
             None,
             None,
+            None,
         );
         accepted_order.apply(fill).unwrap();
         accepted_order
     }
+
+    pub fn make_partially_filled_order(
+        order: &OrderAny,
+        instrument: &InstrumentAny,
+        fill_qty: Quantity,
+    ) -> OrderAny {
+        let mut accepted_order = TestOrderStubs::make_accepted_order(order);
+        let fill = match TestOrderEventStubs::order_filled(
+            &accepted_order,
+            instrument,
+            None,
+            None,
+            None,
+            Some(fill_qty),
+            None,
+            None,
+            None,
+            None,
+            None,
+        ) {
+            OrderEventAny::Filled(fill) => fill,
+            _ => unreachable!(),
+        };
+        accepted_order
+            .apply(OrderEventAny::PartiallyFilled(fill))
+            .unwrap();
+        accepted_order
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use rstest::rstest;
+
+    use super::*;
+    use crate::{
+        enums::{OrderSide, OrderStatus, OrderType},
+        events::OrderEvent,
+        identifiers::InstrumentId,
+        instruments::stubs::audusd_sim,
+        orders::builder::OrderTestBuilder,
+    };
+
+    #[rstest]
+    fn test_make_partially_filled_order() {
+        let audusd_sim = InstrumentAny::CurrencyPair(audusd_sim());
+        let order = OrderTestBuilder::new(OrderType::Limit)
+            .instrument_id(audusd_sim.id())
+            .side(OrderSide::Buy)
+            .price(Price::from("1.00000"))
+            .quantity(Quantity::from(100_000))
+            .build();
+
+        let partially_filled_order = TestOrderStubs::make_partially_filled_order(
+            &order,
+            &audusd_sim,
+            Quantity::from(40_000),
+        );
+
+        assert_eq!(
+            partially_filled_order.status(),
+            OrderStatus::PartiallyFilled
+        );
+        assert_eq!(partially_filled_order.filled_qty(), Quantity::from(40_000));
+        assert_eq!(partially_filled_order.leaves_qty(), Quantity::from(60_000));
+    }
+
+    #[rstest]
+    #[case(None, false)]
+    #[case(Some(false), false)]
+    #[case(Some(true), true)]
+    fn test_order_filled_reconciliation(
+        #[case] reconciliation: Option<bool>,
+        #[case] expected: bool,
+    ) {
+        let audusd_sim = InstrumentAny::CurrencyPair(audusd_sim());
+        let order = OrderTestBuilder::new(OrderType::Market)
+            .instrument_id(audusd_sim.id())
+            .side(OrderSide::Buy)
+            .quantity(Quantity::from(100_000))
+            .build();
+
+        let event = TestOrderEventStubs::order_filled(
+            &order,
+            &audusd_sim,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            reconciliation,
+        );
+
+        let OrderEventAny::Filled(fill) = event else {
+            panic!("Expected `OrderFilled` event, was {event:?}");
+        };
+        assert_eq!(fill.reconciliation(), expected);
+    }
+
+    #[rstest]
+    fn test_make_rejected_order() {
+        let order = OrderTestBuilder::new(OrderType::Market)
+            .instrument_id(InstrumentId::from("AUD/USD.SIM"))
+            .side(OrderSide::Buy)
+            .quantity(Quantity::from(100_000))
+            .build();
+
+        let rejected_order = TestOrderStubs::make_rejected_order(&order, "INSUFFICIENT_MARGIN");
+
+        assert_eq!(rejected_order.status(), OrderStatus::Rejected);
+        assert!(rejected_order.is_closed());
+        match rejected_order.last_event() {
+            OrderEventAny::Rejected(rejected) => {
+                assert_eq!(rejected.reason, Ustr::from("INSUFFICIENT_MARGIN"));
+            }
+            _ => panic!("Expected OrderRejected as last event"),
+        }
+    }
+
+    #[rstest]
+    fn test_make_canceled_order() {
+        let order = OrderTestBuilder::new(OrderType::Limit)
+            .instrument_id(InstrumentId::from("AUD/USD.SIM"))
+            .side(OrderSide::Sell)
+            .price(Price::from("1.00000"))
+            .quantity(Quantity::from(100_000))
+            .build();
+
+        let canceled_order = TestOrderStubs::make_canceled_order(&order);
+
+        assert_eq!(canceled_order.status(), OrderStatus::Canceled);
+        assert!(canceled_order.is_closed());
+        assert_eq!(
+            canceled_order.venue_order_id(),
+            Some(VenueOrderId::from("V-001"))
+        );
+    }
+
+    #[rstest]
+    fn test_apply_events() {
+        let audusd_sim = InstrumentAny::CurrencyPair(audusd_sim());
+        let order = OrderTestBuilder::new(OrderType::Limit)
+            .instrument_id(audusd_sim.id())
+            .side(OrderSide::Buy)
+            .price(Price::from("1.00000"))
+            .quantity(Quantity::from(100_000))
+            .build();
+        let account_id = AccountId::from("SIM-001");
+        let venue_order_id = VenueOrderId::from("V-001");
+        let mut accepted_order = order.clone();
+        accepted_order
+            .apply(TestOrderEventStubs::order_submitted(&order, account_id))
+            .unwrap();
+        accepted_order
+            .apply(TestOrderEventStubs::order_accepted(
+                &order,
+                account_id,
+                venue_order_id,
+            ))
+            .unwrap();
+        let partial_fill = match TestOrderEventStubs::order_filled(
+            &accepted_order,
+            &audusd_sim,
+            Some(TradeId::new("E-1")),
+            None,
+            None,
+            Some(Quantity::from(40_000)),
+            None,
+            None,
+            None,
+            None,
+            None,
+        ) {
+            OrderEventAny::Filled(fill) => OrderEventAny::PartiallyFilled(fill),
+            _ => unreachable!(),
+        };
+        let fill = TestOrderEventStubs::order_filled(
+            &accepted_order,
+            &audusd_sim,
+            Some(TradeId::new("E-2")),
+            None,
+            None,
+            Some(Quantity::from(60_000)),
+            None,
+            None,
+            None,
+            None,
+            None,
+        );
+
+        let filled_order = TestOrderStubs::apply_events(
+            &order,
+            vec![
+                TestOrderEventStubs::order_submitted(&order, account_id),
+                TestOrderEventStubs::order_accepted(&order, account_id, venue_order_id),
+                partial_fill,
+                fill,
+            ],
+        );
+
+        assert_eq!(filled_order.status(), OrderStatus::Filled);
+        assert_eq!(filled_order.filled_qty(), Quantity::from(100_000));
+        assert_eq!(filled_order.leaves_qty(), Quantity::from(0));
+        assert_eq!(filled_order.events().len(), 5);
+        assert!(filled_order.is_closed());
+    }
+
+    #[rstest]
+    #[should_panic(expected = "Invalid event")]
+    fn test_apply_events_with_invalid_transition() {
+        let audusd_sim = InstrumentAny::CurrencyPair(audusd_sim());
+        let order = OrderTestBuilder::new(OrderType::Market)
+            .instrument_id(audusd_sim.id())
+            .side(OrderSide::Buy)
+            .quantity(Quantity::from(100_000))
+            .build();
+
+        // An order cannot be filled before it has been submitted
+        let fill = TestOrderEventStubs::order_filled(
+            &order,
+            &audusd_sim,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+        );
+        let _ = TestOrderStubs::apply_events(&order, vec![fill]);
+    }
 }
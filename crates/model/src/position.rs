@@ -576,6 +576,7 @@ mod tests {
             None,
             None,
             None,
+            None,
         );
         let fill2 = TestOrderEventStubs::order_filled(
             &order2,
@@ -588,6 +589,7 @@ mod tests {
             None,
             None,
             None,
+            None,
         );
         let mut position = Position::new(&audusd_sim, fill1.into());
         position.apply(&fill2.into());
@@ -612,6 +614,7 @@ mod tests {
             None,
             None,
             None,
+            None,
         );
         let last_price = Price::from_str("1.0005").unwrap();
         let position = Position::new(&audusd_sim, fill.into());
@@ -666,6 +669,7 @@ mod tests {
             None,
             None,
             None,
+            None,
         );
         let last_price = Price::from_str("1.00050").unwrap();
         let position = Position::new(&audusd_sim, fill.into());
@@ -721,6 +725,7 @@ mod tests {
             None,
             None,
             None,
+            None,
         );
         let last_price = Price::from_str("1.00048").unwrap();
         let position = Position::new(&audusd_sim, fill.into());
@@ -765,6 +770,7 @@ mod tests {
             None,
             None,
             None,
+            None,
         );
         let fill2 = TestOrderEventStubs::order_filled(
             &order,
@@ -777,6 +783,7 @@ mod tests {
             None,
             None,
             None,
+            None,
         );
         let last_price = Price::from_str("1.0005").unwrap();
         let mut position = Position::new(&audusd_sim, fill1.into());
@@ -822,6 +829,7 @@ mod tests {
             None,
             Some(UnixNanos::from(1_000_000_000)),
             None,
+            None,
         );
         let mut position = Position::new(&audusd_sim, fill.into());
 
@@ -845,6 +853,7 @@ mod tests {
             false,
             Some(PositionId::new("T1")),
             Some(Money::from("0.0 USD")),
+            None,
         );
         position.apply(&fill2);
         let last = Price::from_str("1.0005").unwrap();
@@ -898,6 +907,7 @@ mod tests {
             None,
             None,
             None,
+            None,
         );
         let mut position = Position::new(&audusd_sim, fill1.into());
         // create closing from order from different venue but same strategy
@@ -912,6 +922,7 @@ mod tests {
             None,
             None,
             None,
+            None,
         );
         let fill3 = TestOrderEventStubs::order_filled(
             &order2,
@@ -924,6 +935,7 @@ mod tests {
             None,
             None,
             None,
+            None,
         );
         let last = Price::from("1.0005");
         position.apply(&fill2.into());
@@ -977,6 +989,7 @@ mod tests {
             None,
             None,
             None,
+            None,
         );
         let mut position = Position::new(&audusd_sim, fill1.into());
         let fill2 = TestOrderEventStubs::order_filled(
@@ -990,6 +1003,7 @@ mod tests {
             None,
             None,
             None,
+            None,
         );
         let last = Price::from("1.0005");
         position.apply(&fill2.into());
@@ -1048,6 +1062,7 @@ mod tests {
             None,
             None,
             None,
+            None,
         );
         let fill2 = TestOrderEventStubs::order_filled(
             &order2,
@@ -1060,6 +1075,7 @@ mod tests {
             None,
             None,
             None,
+            None,
         );
         let fill3 = TestOrderEventStubs::order_filled(
             &order3,
@@ -1072,6 +1088,7 @@ mod tests {
             None,
             None,
             None,
+            None,
         );
         let mut position = Position::new(&audusd_sim, fill1.into());
         let last = Price::from("1.0005");
@@ -1128,6 +1145,7 @@ mod tests {
             Some(commission1),
             None,
             None,
+            None,
         );
         let mut position = Position::new(&ethusdt, fill1.into());
         let quantity2 = Quantity::from(17);
@@ -1149,6 +1167,7 @@ mod tests {
             Some(commission2),
             None,
             None,
+            None,
         );
         position.apply(&fill2.into());
         assert_eq!(position.quantity, Quantity::from(29));
@@ -1173,6 +1192,7 @@ mod tests {
             Some(commission3),
             None,
             None,
+            None,
         );
         position.apply(&fill3.into());
         assert_eq!(position.quantity, Quantity::from(20));
@@ -1197,6 +1217,7 @@ mod tests {
             Some(commission4),
             None,
             None,
+            None,
         );
         position.apply(&fill4.into());
         assert_eq!(position.quantity, Quantity::from("16"));
@@ -1221,6 +1242,7 @@ mod tests {
             Some(commission5),
             None,
             None,
+            None,
         );
         position.apply(&fill5.into());
         assert_eq!(position.quantity, Quantity::from("19"));
@@ -1254,6 +1276,7 @@ mod tests {
             Some(commission1),
             Some(UnixNanos::from(1_000_000_000)),
             None,
+            None,
         );
         let mut position = Position::new(&audusd_sim, fill1.into());
 
@@ -1277,6 +1300,7 @@ mod tests {
             false,
             Some(PositionId::from("P-123456")),
             Some(Money::from("0 USD")),
+            None,
         );
 
         position.apply(&fill2);
@@ -1301,6 +1325,7 @@ mod tests {
             false,
             Some(PositionId::from("P-123456")),
             Some(Money::from("0 USD")),
+            None,
         );
 
         position.apply(&fill3);
@@ -1358,6 +1383,7 @@ mod tests {
             Some(commission1),
             None,
             None,
+            None,
         );
         let mut position = Position::new(&btcusdt, fill1.into());
         let order2 = OrderTestBuilder::new(OrderType::Market)
@@ -1378,6 +1404,7 @@ mod tests {
             Some(commission2),
             None,
             None,
+            None,
         );
         position.apply(&fill2.into());
         assert_eq!(position.quantity, Quantity::from(29));
@@ -1405,6 +1432,7 @@ mod tests {
             Some(commission3),
             None,
             None,
+            None,
         );
         position.apply(&fill3.into());
         assert_eq!(position.quantity, Quantity::from(20));
@@ -1432,6 +1460,7 @@ mod tests {
             Some(commission4),
             None,
             None,
+            None,
         );
         position.apply(&fill4.into());
         assert_eq!(position.quantity, Quantity::from(23));
@@ -1459,6 +1488,7 @@ mod tests {
             Some(commission5),
             None,
             None,
+            None,
         );
         position.apply(&fill5.into());
         assert_eq!(position.quantity, Quantity::from(19));
@@ -1494,6 +1524,7 @@ mod tests {
             None,
             None,
             None,
+            None,
         );
         let position = Position::new(&btcusdt, fill.into());
         let result = position.calculate_pnl(10500.0, 10500.0, Quantity::from("100000.0"));
@@ -1521,6 +1552,7 @@ mod tests {
             Some(commission),
             None,
             None,
+            None,
         );
         let position = Position::new(&btcusdt, fill.into());
         let pnl = position.calculate_pnl(10500.0, 10510.0, Quantity::from("12.0"));
@@ -1558,6 +1590,7 @@ mod tests {
             Some(commission),
             None,
             None,
+            None,
         );
         let position = Position::new(&btcusdt, fill.into());
         let pnl = position.calculate_pnl(10500.0, 10480.5, Quantity::from("10.0"));
@@ -1595,6 +1628,7 @@ mod tests {
             Some(commission),
             None,
             None,
+            None,
         );
         let position = Position::new(&btcusdt, fill.into());
         let pnl = position.calculate_pnl(10500.0, 10390.0, Quantity::from("10.15"));
@@ -1632,6 +1666,7 @@ mod tests {
             Some(commission),
             None,
             None,
+            None,
         );
         let position = Position::new(&btcusdt, fill.into());
         let pnl = position.calculate_pnl(10500.0, 10670.5, Quantity::from("10.0"));
@@ -1674,6 +1709,7 @@ mod tests {
             Some(commission),
             None,
             None,
+            None,
         );
         let position = Position::new(&xbtusd_bitmex, fill.into());
         let pnl = position.calculate_pnl(10000.0, 11000.0, Quantity::from("100000.0"));
@@ -1715,6 +1751,7 @@ mod tests {
             Some(commission),
             None,
             None,
+            None,
         );
         let position = Position::new(&ethusdt_bitmex, fill.into());
 
@@ -1755,6 +1792,7 @@ mod tests {
             Some(commission1),
             None,
             None,
+            None,
         );
         let commission2 =
             calculate_commission(&btcusdt, order2.quantity(), Price::from("10500.0"), None)
@@ -1770,6 +1808,7 @@ mod tests {
             Some(commission2),
             None,
             None,
+            None,
         );
         let mut position = Position::new(&btcusdt, fill1.into());
         position.apply(&fill2.into());
@@ -1807,6 +1846,7 @@ mod tests {
             Some(commission),
             None,
             None,
+            None,
         );
         let position = Position::new(&btcusdt, fill.into());
         let pnl = position.unrealized_pnl(Price::from("10407.15"));
@@ -1847,6 +1887,7 @@ mod tests {
             Some(commission),
             None,
             None,
+            None,
         );
 
         let position = Position::new(&xbtusd_bitmex, fill.into());
@@ -1882,6 +1923,7 @@ mod tests {
             Some(commission),
             None,
             None,
+            None,
         );
         let position = Position::new(&xbtusd_bitmex, fill.into());
         let pnl = position.unrealized_pnl(Price::from("12506.65"));
@@ -1920,6 +1962,7 @@ mod tests {
             Some(commission),
             None,
             None,
+            None,
         );
         let position = Position::new(&audusd_sim, fill.into());
         assert_eq!(position.signed_qty, expected);
@@ -18,6 +18,7 @@ use nautilus_core::{
     python::{IntoPyObjectNautilusExt, serialization::from_dict_pyo3},
 };
 use pyo3::{basic::CompareOp, prelude::*, types::PyDict};
+use ustr::Ustr;
 
 use crate::{
     events::OrderCanceled,
@@ -28,7 +29,7 @@ use crate::{
 impl OrderCanceled {
     #[allow(clippy::too_many_arguments)]
     #[new]
-    #[pyo3(signature = (trader_id, strategy_id, instrument_id, client_order_id, event_id, ts_event, ts_init, reconciliation, venue_order_id=None, account_id=None))]
+    #[pyo3(signature = (trader_id, strategy_id, instrument_id, client_order_id, event_id, ts_event, ts_init, reconciliation, venue_order_id=None, account_id=None, reason=None))]
     fn py_new(
         trader_id: TraderId,
         strategy_id: StrategyId,
@@ -40,6 +41,7 @@ impl OrderCanceled {
         reconciliation: bool,
         venue_order_id: Option<VenueOrderId>,
         account_id: Option<AccountId>,
+        reason: Option<String>,
     ) -> Self {
         Self::new(
             trader_id,
@@ -52,6 +54,7 @@ impl OrderCanceled {
             reconciliation,
             venue_order_id,
             account_id,
+            reason.map(|reason| Ustr::from(&reason)),
         )
     }
 
@@ -97,6 +100,10 @@ impl OrderCanceled {
             Some(account_id) => dict.set_item("account_id", account_id.to_string())?,
             None => dict.set_item("account_id", py.None())?,
         }
+        match self.reason {
+            Some(reason) => dict.set_item("reason", reason.to_string())?,
+            None => dict.set_item("reason", py.None())?,
+        }
         Ok(dict.into())
     }
 }
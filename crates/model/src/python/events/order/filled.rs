@@ -33,7 +33,7 @@ use crate::{
 impl OrderFilled {
     #[allow(clippy::too_many_arguments)]
     #[new]
-    #[pyo3(signature = (trader_id, strategy_id, instrument_id, client_order_id, venue_order_id, account_id, trade_id, order_side, order_type, last_qty, last_px, currency, liquidity_side, event_id, ts_event, ts_init, reconciliation, position_id=None, commission=None))]
+    #[pyo3(signature = (trader_id, strategy_id, instrument_id, client_order_id, venue_order_id, account_id, trade_id, order_side, order_type, last_qty, last_px, currency, liquidity_side, event_id, ts_event, ts_init, reconciliation, position_id=None, commission=None, slippage=None))]
     fn py_new(
         trader_id: TraderId,
         strategy_id: StrategyId,
@@ -54,6 +54,7 @@ impl OrderFilled {
         reconciliation: bool,
         position_id: Option<PositionId>,
         commission: Option<Money>,
+        slippage: Option<Price>,
     ) -> Self {
         Self::new(
             trader_id,
@@ -75,6 +76,7 @@ impl OrderFilled {
             reconciliation,
             position_id,
             commission,
+            slippage,
         )
     }
 
@@ -214,6 +216,12 @@ impl OrderFilled {
         self.commission
     }
 
+    #[getter]
+    #[pyo3(name = "slippage")]
+    fn py_slippage(&self) -> Option<Price> {
+        self.slippage
+    }
+
     #[getter]
     #[pyo3(name = "order_type")]
     fn py_order_type(&self) -> OrderType {
@@ -256,6 +264,10 @@ impl OrderFilled {
             Some(commission) => dict.set_item("commission", commission.to_string())?,
             None => dict.set_item("commission", py.None())?,
         }
+        match self.slippage {
+            Some(slippage) => dict.set_item("slippage", slippage.to_string())?,
+            None => dict.set_item("slippage", py.None())?,
+        }
         Ok(dict.into())
     }
 }
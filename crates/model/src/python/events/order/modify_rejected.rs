@@ -23,6 +23,7 @@ use pyo3::{basic::CompareOp, prelude::*, types::PyDict};
 use ustr::Ustr;
 
 use crate::{
+    enums::ModifyRejectReason,
     events::OrderModifyRejected,
     identifiers::{AccountId, ClientOrderId, InstrumentId, StrategyId, TraderId, VenueOrderId},
 };
@@ -31,7 +32,7 @@ use crate::{
 impl OrderModifyRejected {
     #[allow(clippy::too_many_arguments)]
     #[new]
-    #[pyo3(signature = (trader_id, strategy_id, instrument_id, client_order_id, reason, event_id, ts_event, ts_init, reconciliation, venue_order_id=None, account_id=None))]
+    #[pyo3(signature = (trader_id, strategy_id, instrument_id, client_order_id, reason, event_id, ts_event, ts_init, reconciliation, venue_order_id=None, account_id=None, reason_code=None))]
     fn py_new(
         trader_id: TraderId,
         strategy_id: StrategyId,
@@ -44,6 +45,7 @@ impl OrderModifyRejected {
         reconciliation: bool,
         venue_order_id: Option<VenueOrderId>,
         account_id: Option<AccountId>,
+        reason_code: Option<ModifyRejectReason>,
     ) -> PyResult<Self> {
         let reason = Ustr::from_str(reason).map_err(to_pyvalue_err)?;
         Ok(Self::new(
@@ -58,6 +60,7 @@ impl OrderModifyRejected {
             reconciliation,
             venue_order_id,
             account_id,
+            reason_code,
         ))
     }
 
@@ -24,12 +24,13 @@ use ustr::Ustr;
 
 use crate::{
     events::OrderRejected,
-    identifiers::{AccountId, ClientOrderId, InstrumentId, StrategyId, TraderId},
+    identifiers::{AccountId, ClientOrderId, InstrumentId, StrategyId, TraderId, VenueOrderId},
 };
 #[pymethods]
 impl OrderRejected {
     #[allow(clippy::too_many_arguments)]
     #[new]
+    #[pyo3(signature = (trader_id, strategy_id, instrument_id, client_order_id, account_id, reason, event_id, ts_event, ts_init, reconciliation, venue_order_id=None))]
     fn py_new(
         trader_id: TraderId,
         strategy_id: StrategyId,
@@ -41,6 +42,7 @@ impl OrderRejected {
         ts_event: u64,
         ts_init: u64,
         reconciliation: bool,
+        venue_order_id: Option<VenueOrderId>,
     ) -> PyResult<Self> {
         let reason = Ustr::from_str(reason).map_err(to_pyvalue_err)?;
         Ok(Self::new(
@@ -54,6 +56,7 @@ impl OrderRejected {
             ts_event.into(),
             ts_init.into(),
             reconciliation,
+            venue_order_id,
         ))
     }
 
@@ -93,6 +96,10 @@ impl OrderRejected {
         dict.set_item("ts_event", self.ts_event.as_u64())?;
         dict.set_item("ts_init", self.ts_init.as_u64())?;
         dict.set_item("reconciliation", self.reconciliation)?;
+        match self.venue_order_id {
+            Some(venue_order_id) => dict.set_item("venue_order_id", venue_order_id.to_string())?,
+            None => dict.set_item("venue_order_id", py.None())?,
+        }
         Ok(dict.into())
     }
 }
@@ -22,13 +22,14 @@ use pyo3::{basic::CompareOp, prelude::*, types::PyDict};
 use crate::{
     events::OrderTriggered,
     identifiers::{AccountId, ClientOrderId, InstrumentId, StrategyId, TraderId, VenueOrderId},
+    types::Price,
 };
 
 #[pymethods]
 impl OrderTriggered {
     #[allow(clippy::too_many_arguments)]
     #[new]
-    #[pyo3(signature = (trader_id, strategy_id, instrument_id, client_order_id, event_id, ts_event, ts_init, reconciliation, venue_order_id=None, account_id=None))]
+    #[pyo3(signature = (trader_id, strategy_id, instrument_id, client_order_id, event_id, ts_event, ts_init, reconciliation, venue_order_id=None, account_id=None, trigger_price=None, market_price=None))]
     fn py_new(
         trader_id: TraderId,
         strategy_id: StrategyId,
@@ -40,6 +41,8 @@ impl OrderTriggered {
         reconciliation: bool,
         venue_order_id: Option<VenueOrderId>,
         account_id: Option<AccountId>,
+        trigger_price: Option<Price>,
+        market_price: Option<Price>,
     ) -> Self {
         Self::new(
             trader_id,
@@ -52,6 +55,8 @@ impl OrderTriggered {
             reconciliation,
             venue_order_id,
             account_id,
+            trigger_price,
+            market_price,
         )
     }
 
@@ -97,6 +102,14 @@ impl OrderTriggered {
             Some(account_id) => dict.set_item("account_id", account_id.to_string())?,
             None => dict.set_item("account_id", "None")?,
         }
+        match self.trigger_price {
+            Some(trigger_price) => dict.set_item("trigger_price", trigger_price.to_string())?,
+            None => dict.set_item("trigger_price", py.None())?,
+        }
+        match self.market_price {
+            Some(market_price) => dict.set_item("market_price", market_price.to_string())?,
+            None => dict.set_item("market_price", py.None())?,
+        }
         Ok(dict.into())
     }
 }
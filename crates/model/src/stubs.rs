@@ -78,6 +78,7 @@ pub fn stub_position_long(audusd_sim: CurrencyPair) -> Position {
         None,
         None,
         None,
+        None,
     );
     Position::new(&audusd_sim, filled.into())
 }
@@ -101,6 +102,7 @@ pub fn stub_position_short(audusd_sim: CurrencyPair) -> Position {
         None,
         None,
         None,
+        None,
     );
     Position::new(&audusd_sim, filled.into())
 }
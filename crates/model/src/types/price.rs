@@ -614,6 +614,18 @@ mod tests {
         assert!(approx_eq!(f64, price.as_f64(), -123.45, epsilon = 1e-9));
     }
 
+    #[rstest]
+    #[case("1.5e3", "1500")]
+    #[case("1.25E1", "12.5")]
+    #[case("25e-4", "0.0025")]
+    #[case("-1.5e2", "-150")]
+    fn test_string_parsing_scientific_notation(#[case] input: &str, #[case] expected: &str) {
+        let price = Price::from(input);
+        let expected = Price::from(expected);
+        assert_eq!(price, expected);
+        assert_eq!(price.precision, expected.precision);
+    }
+
     #[rstest]
     fn test_string_parsing_errors() {
         assert!(Price::from_str("invalid").is_err());
@@ -686,6 +686,17 @@ mod tests {
         assert_eq!(qty.as_decimal(), Decimal::from_str(input).unwrap());
     }
 
+    #[rstest]
+    #[case("1.5e3", "1500")]
+    #[case("25e-4", "0.0025")]
+    #[case("1e-8", "0.00000001")]
+    fn test_from_str_scientific_notation(#[case] input: &str, #[case] expected: &str) {
+        let qty = Quantity::from(input);
+        let expected = Quantity::from(expected);
+        assert_eq!(qty, expected);
+        assert_eq!(qty.precision, expected.precision);
+    }
+
     #[rstest]
     #[should_panic]
     fn test_from_str_invalid_input() {
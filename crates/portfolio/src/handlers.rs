@@ -95,7 +95,7 @@ impl MessageHandler for UpdatePositionHandler {
     }
 
     fn handle(&self, msg: &dyn Any) {
-        (self.callback)(msg.downcast_ref::<&PositionEvent>().unwrap());
+        (self.callback)(msg.downcast_ref::<PositionEvent>().unwrap());
     }
     fn handle_response(&self, _resp: DataResponse) {}
     fn handle_data(&self, _data: Data) {}
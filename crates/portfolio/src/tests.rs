@@ -1042,6 +1042,7 @@ mod tests {
             false,
             Some(PositionId::new("SSD")),
             Some(Money::from("12.2 USD")),
+            None,
         );
 
         // Update the last quote
@@ -1164,6 +1165,7 @@ mod tests {
             false,
             Some(PositionId::new("SSD")),
             Some(Money::from("12.2 USD")),
+            None,
         );
 
         let position = Position::new(&instrument_ethusdt, fill);
@@ -1250,6 +1252,7 @@ mod tests {
             false,
             Some(PositionId::new("SSD")),
             Some(Money::from("12.2 USD")),
+            None,
         );
 
         let last_ethusd = get_quote_tick(&instrument_ethusdt, 376.05, 377.10, 16.0, 25.0);
@@ -1343,6 +1346,7 @@ mod tests {
             false,
             Some(PositionId::new("SSD")),
             Some(Money::from("12.2 USD")),
+            None,
         );
         let fill2 = OrderFilled::new(
             order2.trader_id(),
@@ -1364,6 +1368,7 @@ mod tests {
             false,
             Some(PositionId::new("SSD")),
             Some(Money::from("12.2 USD")),
+            None,
         );
 
         portfolio.cache.borrow_mut().update_order(&order1).unwrap();
@@ -1514,6 +1519,7 @@ mod tests {
             false,
             Some(PositionId::new("SSD")),
             Some(Money::from("12.2 USD")),
+            None,
         );
 
         let mut position1 = Position::new(&instrument_audusd, fill1);
@@ -1551,6 +1557,7 @@ mod tests {
             false,
             Some(PositionId::new("SSD")),
             Some(Money::from("1.2 USD")),
+            None,
         );
 
         position1.apply(&fill2);
@@ -1668,6 +1675,7 @@ mod tests {
             false,
             Some(PositionId::new("SSD")),
             Some(Money::from("12.2 USD")),
+            None,
         );
 
         let mut position1 = Position::new(&instrument_audusd, fill1);
@@ -1705,6 +1713,7 @@ mod tests {
             false,
             Some(PositionId::new("SSD")),
             Some(Money::from("1.2 USD")),
+            None,
         );
 
         position1.apply(&fill2);
@@ -1798,6 +1807,7 @@ mod tests {
             false,
             Some(PositionId::new("P-1")),
             None,
+            None,
         );
         let fill2 = OrderFilled::new(
             order2.trader_id(),
@@ -1819,6 +1829,7 @@ mod tests {
             false,
             Some(PositionId::new("P-2")),
             None,
+            None,
         );
         let fill3 = OrderFilled::new(
             order3.trader_id(),
@@ -1840,6 +1851,7 @@ mod tests {
             false,
             Some(PositionId::new("P-3")),
             None,
+            None,
         );
         let fill4 = OrderFilled::new(
             order4.trader_id(),
@@ -1861,6 +1873,7 @@ mod tests {
             false,
             Some(PositionId::new("P-4")),
             None,
+            None,
         );
 
         let position1 = Position::new(&instrument_audusd, fill1);
@@ -250,6 +250,7 @@ impl RiskEngine {
             false,
             order.venue_order_id(),
             None,
+            None,
         ))
     }
 
@@ -969,6 +970,7 @@ impl RiskEngine {
             false,
             order.venue_order_id(),
             order.account_id(),
+            None,
         ));
 
         self.msgbus
@@ -1417,6 +1419,7 @@ mod tests {
             false,
             None,
             Some(commission),
+            None,
         )
     }
 
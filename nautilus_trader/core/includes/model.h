@@ -2,6 +2,7 @@
 
 /* Warning, this file is autogenerated by cbindgen. Don't modify this manually. */
 
+#include <stdbool.h>
 #include <stdint.h>
 #include <Python.h>
 
@@ -1556,7 +1557,7 @@ typedef struct OrderAccepted_t {
     /**
      * If the event was generated during reconciliation.
      */
-    uint8_t reconciliation;
+    bool reconciliation;
 } OrderAccepted_t;
 
 /**
@@ -1602,7 +1603,11 @@ typedef struct OrderRejected_t {
     /**
      * If the event was generated during reconciliation.
      */
-    uint8_t reconciliation;
+    bool reconciliation;
+    /**
+     * The venue order ID associated with the event (if assigned before the rejection).
+     */
+    VenueOrderId_t venue_order_id;
 } OrderRejected_t;
 
 /**
@@ -2360,7 +2365,7 @@ struct OrderAccepted_t order_accepted_new(struct TraderId_t trader_id,
                                           UUID4_t event_id,
                                           uint64_t ts_event,
                                           uint64_t ts_init,
-                                          uint8_t reconciliation);
+                                          bool reconciliation);
 
 /**
  * # Safety
@@ -2376,7 +2381,7 @@ struct OrderRejected_t order_rejected_new(struct TraderId_t trader_id,
                                           UUID4_t event_id,
                                           uint64_t ts_event,
                                           uint64_t ts_init,
-                                          uint8_t reconciliation);
+                                          bool reconciliation);
 
 /**
  * Returns a Nautilus identifier from a C string pointer.
@@ -857,7 +857,7 @@ cdef extern from "../includes/model.h":
         # UNIX timestamp (nanoseconds) when the event was initialized.
         uint64_t ts_init;
         # If the event was generated during reconciliation.
-        uint8_t reconciliation;
+        bint reconciliation;
 
     # Represents an event where an order has been rejected by the trading venue.
     cdef struct OrderRejected_t:
@@ -880,7 +880,9 @@ cdef extern from "../includes/model.h":
         # UNIX timestamp (nanoseconds) when the event was initialized.
         uint64_t ts_init;
         # If the event was generated during reconciliation.
-        uint8_t reconciliation;
+        bint reconciliation;
+        # The venue order ID associated with the event (if assigned before the rejection).
+        VenueOrderId_t venue_order_id;
 
     # Represents a system client ID.
     cdef struct ClientId_t:
@@ -1491,7 +1493,7 @@ cdef extern from "../includes/model.h":
                                        UUID4_t event_id,
                                        uint64_t ts_event,
                                        uint64_t ts_init,
-                                       uint8_t reconciliation);
+                                       bint reconciliation);
 
     # # Safety
     #
@@ -1505,7 +1507,7 @@ cdef extern from "../includes/model.h":
                                        UUID4_t event_id,
                                        uint64_t ts_event,
                                        uint64_t ts_init,
-                                       uint8_t reconciliation);
+                                       bint reconciliation);
 
     # Returns a Nautilus identifier from a C string pointer.
     #